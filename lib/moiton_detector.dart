@@ -6,6 +6,7 @@ import 'dart:async';
 
 import 'package:vault/router_provider.dart';
 import 'package:vault/providers.dart';
+import 'package:vault/src/rust/api/file.dart' as file_api;
 
 class MotionDetector extends ConsumerStatefulWidget {
   final Widget child;
@@ -110,6 +111,8 @@ class _MotionDetectorState extends ConsumerState<MotionDetector> {
   // }
   void _triggerLogout() {
     _stopListening();
+    // Drop the key on the Rust side too, not just the unlocked UI
+    file_api.lockVault().catchError((e) => debugPrint("Vault error: WARN: $e"));
     // Wrap in microtask to avoid "DiagnosticsProperty" / build-phase errors
     Future.microtask(() {
       if (mounted) {
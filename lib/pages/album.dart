@@ -8,6 +8,8 @@ import 'package:flutter/material.dart' show MaterialRectArcTween;
 import 'package:flutter/services.dart';
 import 'package:flutter_blurhash/flutter_blurhash.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart'
+    show PlatformInt64Util;
 import 'package:go_router/go_router.dart';
 import 'package:image_picker/image_picker.dart';
 import 'package:modal_bottom_sheet/modal_bottom_sheet.dart'; // Added package
@@ -15,6 +17,7 @@ import 'package:path_provider/path_provider.dart';
 import 'package:progressive_blur/progressive_blur.dart';
 import 'package:smooth_gradient/smooth_gradient.dart';
 import 'package:vault/providers.dart';
+import 'package:vault/src/rust/utils/storage.dart';
 import 'package:vault/utils/file_api_wrapper.dart' as fileapi;
 
 class AlbumPage extends ConsumerStatefulWidget {
@@ -145,10 +148,19 @@ class _AlbumPageState extends ConsumerState<AlbumPage>
       try {
         final bytes = await image.readAsBytes();
         final Uint8List uint8list = Uint8List.fromList(bytes);
+        // Read before the picker's copy is deleted below
+        final modified = await image.lastModified();
+        final source = MediaSource(
+          originalName: image.name,
+          mimeType: image.mimeType,
+          modifiedAt: PlatformInt64Util.from(
+              modified.millisecondsSinceEpoch ~/ 1000),
+        );
         if (Platform.isAndroid) {
           await File(image.path).delete();
         }
-        await saveImageWrapper(uint8list, '$directory/${widget.name}');
+        await saveImageWrapper(uint8list, '$directory/${widget.name}',
+            source: source);
       } catch (e) {
         debugPrint("Error processing image: $e");
       }
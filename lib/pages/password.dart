@@ -52,7 +52,7 @@ class _PasswordState extends ConsumerState<Password>
     } catch (e) {
       if (mounted) {
         setState(() {
-          errorMessage = "An error occurred: ${e.toString()}";
+          errorMessage = fileapi.vaultErrorMessage(e);
         });
       }
     } finally {
@@ -1,8 +1,14 @@
 import 'dart:io';
 import 'package:flutter/cupertino.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart'
+    show RustStreamSink;
 import 'package:vault/providers.dart';
+import 'package:vault/src/rust/utils/backup.dart';
+import 'package:vault/src/rust/utils/error.dart';
+import 'package:vault/src/rust/utils/progress.dart';
 import 'package:vault/widget/menu_item.dart';
+import 'package:vault/widget/progress_dialog.dart';
 import 'package:path_provider/path_provider.dart';
 import 'package:file_picker/file_picker.dart';
 import 'package:vault/utils/file_api_wrapper.dart' as fileapi;
@@ -18,6 +24,17 @@ class _PrivacySettingsState extends ConsumerState<PrivacySettings>
     with fileapi.FileApiWrapper {
   static const double _menuSpacing = 16.0;
   static const double _borderRadius = 20.0;
+  static const Map<int, String> _autoLockOptions = {
+    1: "After 1 minute",
+    5: "After 5 minutes",
+    15: "After 15 minutes",
+    0: "Never",
+  };
+  static const Map<DuplicatePolicy, String> _duplicateOptions = {
+    DuplicatePolicy.skip: "Skip Duplicates",
+    DuplicatePolicy.keepBoth: "Keep Both",
+    DuplicatePolicy.overwrite: "Replace Existing",
+  };
 
   void _showCupertinoNotify(String message, {String title = "Security"}) {
     showCupertinoDialog(
@@ -38,6 +55,85 @@ class _PrivacySettingsState extends ConsumerState<PrivacySettings>
     );
   }
 
+  /// Runs [operation] behind a progress dialog with a Cancel button, closing
+  /// the dialog once the operation finishes or fails.
+  Future<T> _runWithProgress<T>(
+      String title,
+      Future<T> Function(
+              CancelToken cancel, RustStreamSink<ProgressEvent> progress)
+          operation) async {
+    final cancel = await createCancelTokenWrapper();
+    final progress = RustStreamSink<ProgressEvent>();
+    final future = operation(cancel, progress);
+    if (!mounted) return future;
+
+    showCupertinoDialog(
+      context: context,
+      barrierDismissible: false,
+      builder: (_) => ProgressDialog(
+        title: title,
+        progress: progress.stream,
+        onCancel: () => cancelOperationWrapper(cancel),
+      ),
+    );
+
+    try {
+      return await future;
+    } finally {
+      if (mounted) Navigator.of(context, rootNavigator: true).pop();
+    }
+  }
+
+  Future<void> pickAutoLock() async {
+    final minutes = await showCupertinoModalPopup<int>(
+      context: context,
+      builder: (context) => CupertinoActionSheet(
+        title: const Text("Auto-Lock"),
+        message: const Text(
+            "The vault forgets its key after this long without activity."),
+        actions: [
+          for (final option in _autoLockOptions.entries)
+            CupertinoActionSheetAction(
+              onPressed: () => Navigator.pop(context, option.key),
+              child: Text(option.value),
+            ),
+        ],
+        cancelButton: CupertinoActionSheetAction(
+          isDefaultAction: true,
+          onPressed: () => Navigator.pop(context),
+          child: const Text("Cancel"),
+        ),
+      ),
+    );
+
+    if (minutes == null) return;
+    await ref.read(settingsModelProvider).setAutoLockMinutes(minutes);
+  }
+
+  Future<DuplicatePolicy?> pickDuplicatePolicy() {
+    return showCupertinoModalPopup<DuplicatePolicy>(
+      context: context,
+      builder: (context) => CupertinoActionSheet(
+        title: const Text("Duplicates"),
+        message: const Text(
+            "Choose what to do with photos that are already in the vault."),
+        actions: [
+          for (final option in _duplicateOptions.entries)
+            CupertinoActionSheetAction(
+              isDestructiveAction: option.key == DuplicatePolicy.overwrite,
+              onPressed: () => Navigator.pop(context, option.key),
+              child: Text(option.value),
+            ),
+        ],
+        cancelButton: CupertinoActionSheetAction(
+          isDefaultAction: true,
+          onPressed: () => Navigator.pop(context),
+          child: const Text("Cancel"),
+        ),
+      ),
+    );
+  }
+
   Future<void> backupAll() async {
     final result = await showCupertinoDialog<bool>(
       context: context,
@@ -74,7 +170,20 @@ class _PrivacySettingsState extends ConsumerState<PrivacySettings>
       downloadPath = '${appDownloadDir?.path}/vault_backup.zip';
     }
 
-    await zipBackupWrapper(rootDirectory, downloadPath, result);
+    try {
+      await _runWithProgress(
+          "Creating Backup",
+          (cancel, progress) => zipBackupWrapper(
+              rootDirectory, downloadPath, result,
+              cancel: cancel, progress: progress));
+    } on VaultError_Cancelled {
+      return;
+    } catch (e) {
+      if (mounted) {
+        _showCupertinoNotify(fileapi.vaultErrorMessage(e), title: "Error");
+      }
+      return;
+    }
 
     if (mounted) {
       _showCupertinoNotify(
@@ -101,33 +210,53 @@ class _PrivacySettingsState extends ConsumerState<PrivacySettings>
           if (password == null) return;
         }
 
-        showCupertinoDialog(
-          context: currentContext,
-          barrierDismissible: false,
-          builder: (_) =>
-              const Center(child: CupertinoActivityIndicator(radius: 15)),
-        );
+        final duplicates = await pickDuplicatePolicy();
+        if (duplicates == null) return;
 
         Directory appDocDir = await getApplicationDocumentsDirectory();
         String rootDirectory = '${appDocDir.path}/Collections';
 
-        await restoreBackupWrapper(rootDirectory, zipPath, password);
+        final summary = await _runWithProgress(
+            "Restoring Backup",
+            (cancel, progress) => restoreBackupWrapper(
+                rootDirectory, zipPath, password,
+                cancel: cancel, duplicates: duplicates, progress: progress));
 
-        if (currentContext.mounted) Navigator.of(currentContext).pop();
         if (currentContext.mounted) {
-          _showCupertinoNotify(
-              'Your vault contents have been restored successfully.',
-              title: "Restore Success");
+          _showCupertinoNotify(_describeRestore(summary, duplicates),
+              title: summary.rejected.isEmpty
+                  ? "Restore Success"
+                  : "Restore Finished");
         }
       }
+    } on VaultError_Cancelled {
+      return;
     } catch (e) {
-      if (currentContext.mounted) Navigator.of(currentContext).pop();
       if (currentContext.mounted) {
-        _showCupertinoNotify(
-            'Restore failed: ${e.toString().replaceFirst("Exception: ", "")}',
-            title: "Error");
+        _showCupertinoNotify(fileapi.vaultErrorMessage(e), title: "Error");
+      }
+    }
+  }
+
+  String _describeRestore(RestoreSummary summary, DuplicatePolicy duplicates) {
+    final lines = [
+      "Restored ${summary.restored} item${summary.restored == 1 ? '' : 's'}.",
+    ];
+    if (summary.conflicts.isNotEmpty) {
+      final outcome = switch (duplicates) {
+        DuplicatePolicy.skip => "skipped",
+        DuplicatePolicy.keepBoth => "kept next to the existing copies",
+        DuplicatePolicy.overwrite => "replaced the existing copies",
+      };
+      lines.add("${summary.conflicts.length} duplicates were $outcome.");
+    }
+    if (summary.rejected.isNotEmpty) {
+      lines.add("\n${summary.rejected.length} entries were not restored:");
+      for (final entry in summary.rejected) {
+        lines.add("${entry.name}: ${entry.reason}");
       }
     }
+    return lines.join("\n");
   }
 
   Future<String?> showPasswordDialog(
@@ -208,7 +337,7 @@ class _PrivacySettingsState extends ConsumerState<PrivacySettings>
                           } catch (e) {
                             setState(() {
                               isChecking = false;
-                              error = "Verification error";
+                              error = fileapi.vaultErrorMessage(e);
                             });
                           }
                         },
@@ -227,6 +356,7 @@ class _PrivacySettingsState extends ConsumerState<PrivacySettings>
     final theme = CupertinoTheme.of(context);
     final bgColor =
         CupertinoColors.systemGroupedBackground.resolveFrom(context);
+    final autoLockMinutes = ref.watch(settingsModelProvider).autoLockMinutes;
 
     return CupertinoPageScaffold(
       backgroundColor: bgColor,
@@ -325,6 +455,15 @@ class _PrivacySettingsState extends ConsumerState<PrivacySettings>
                           onChanged: (value) => ref
                               .read(settingsModelProvider)
                               .toggleSecureContent(),
+                          divider: true,
+                        ),
+                        MenuItem(
+                          icon: CupertinoIcons.timer,
+                          iconColor: CupertinoColors.systemOrange,
+                          title: "Auto-Lock",
+                          subtitle: _autoLockOptions[autoLockMinutes] ??
+                              "After $autoLockMinutes minutes",
+                          onTap: pickAutoLock,
                           divider: false,
                         ),
                       ],
@@ -50,7 +50,8 @@ class _SetupPageState extends ConsumerState<SetupPage>
       if (mounted) {
         ref.read(isAuthenticatedProvider.notifier).state = false;
         setState(() {
-          errorMessage = "Failed to save password: ${e.toString()}";
+          errorMessage =
+              "Failed to save password: ${fileapi.vaultErrorMessage(e)}";
         });
       }
     } finally {
@@ -6,6 +6,7 @@ import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:isolate_pool_2/isolate_pool_2.dart';
 import 'package:secure_content/secure_content.dart';
 import 'package:shared_preferences/shared_preferences.dart';
+import 'package:vault/src/rust/api/file.dart' as file_api;
 
 class SettingsModel extends ChangeNotifier {
   final secureContentPlugin = SecureContent();
@@ -15,12 +16,14 @@ class SettingsModel extends ChangeNotifier {
   bool _theftProtection = false;
   bool _advancedTextures = false;
   bool _secureContent = true;
+  int _autoLockMinutes = 5;
 
   // Getter for private variables
   bool get darkmode => _darkMode;
   bool get theftProtection => _theftProtection;
   bool get advancedTextures => _advancedTextures;
   bool get secureContent => _secureContent;
+  int get autoLockMinutes => _autoLockMinutes;
 
   // Constructor that triggers loading the settings
   SettingsModel() {
@@ -35,6 +38,8 @@ class SettingsModel extends ChangeNotifier {
     _theftProtection = prefs.getBool('theftProtection') ?? true;
     _advancedTextures = prefs.getBool('advancedTextures') ?? false;
     _secureContent = prefs.getBool('secureContent') ?? true;
+    _autoLockMinutes = prefs.getInt('autoLockMinutes') ?? 5;
+    await _applyAutoLock();
 
     notifyListeners();
   }
@@ -71,6 +76,25 @@ class SettingsModel extends ChangeNotifier {
     await prefs.setBool('secureContent', _secureContent);
     notifyListeners();
   }
+
+  // 0 turns the auto-lock off
+  Future<void> setAutoLockMinutes(int minutes) async {
+    final prefs = await SharedPreferences.getInstance();
+    _autoLockMinutes = minutes;
+    await prefs.setInt('autoLockMinutes', _autoLockMinutes);
+    await _applyAutoLock();
+    notifyListeners();
+  }
+
+  // The Rust side owns the idle timer and locks the key itself
+  Future<void> _applyAutoLock() async {
+    try {
+      await file_api.setAutoLockTimeout(
+          seconds: BigInt.from(_autoLockMinutes * 60));
+    } catch (e) {
+      debugPrint("Vault error: WARN: $e");
+    }
+  }
 }
 
 final settingsModelProvider = ChangeNotifierProvider<SettingsModel>((ref) {
@@ -15,6 +15,7 @@ import 'package:vault/pages/settings/appearance_settings.dart';
 import 'package:vault/pages/settings/privacy_settings.dart';
 import 'package:vault/pages/settings/settings.dart';
 import 'package:vault/pages/setup.dart';
+import 'package:vault/src/rust/utils/session.dart';
 import 'package:vault/utils/file_api_wrapper.dart' as fileapi;
 
 // A simple wrapper class for file operations
@@ -34,6 +35,12 @@ final passwordExistsProvider = FutureProvider<bool>((ref) async {
 // Provider to hold the current authentication state of the user
 final isAuthenticatedProvider = StateProvider<bool>((ref) => false);
 
+// Events pushed from Rust, e.g. when the vault locks itself after inactivity
+final vaultEventsProvider = StreamProvider<VaultEvent>((ref) {
+  const fileService = FileService();
+  return fileService.vaultEventsWrapper();
+});
+
 /// Helper class to make GoRouter listen to Riverpod's StateProvider changes.
 class GoRouterRefreshStream extends ChangeNotifier {
   GoRouterRefreshStream(Stream<dynamic> stream) {
@@ -55,6 +62,13 @@ final routerProvider = Provider<GoRouter>((ref) {
   final refreshListenable =
       GoRouterRefreshStream(ref.watch(isAuthenticatedProvider.notifier).stream);
 
+  // Rust already dropped the key on a lock event, go back to the login screen
+  ref.listen<AsyncValue<VaultEvent>>(vaultEventsProvider, (_, event) {
+    if (event.valueOrNull is VaultEvent_Locked) {
+      ref.read(isAuthenticatedProvider.notifier).state = false;
+    }
+  });
+
   return GoRouter(
     refreshListenable: refreshListenable,
     initialLocation: '/', // Start at the root
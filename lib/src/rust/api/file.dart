@@ -4,15 +4,76 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../utils/backup.dart';
 import '../utils/error.dart';
+import '../utils/kdf.dart';
+import '../utils/keyslot.dart';
+import '../utils/manifest.dart';
+import '../utils/progress.dart';
+import '../utils/session.dart';
+import '../utils/storage.dart';
+import 'media.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `session_root`, `session_vault`, `start_session`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SESSION_VAULT`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `deref`, `initialize`
+
 Future<bool> setPassword({required String password, required String dir}) =>
     RustLib.instance.api.crateApiFileSetPassword(password: password, dir: dir);
 
 Future<void> savePassword({required String password, required String dir}) =>
     RustLib.instance.api.crateApiFileSavePassword(password: password, dir: dir);
 
+Future<void> savePasswordWithKdf(
+        {required String password,
+        required String dir,
+        required KdfConfig kdf}) =>
+    RustLib.instance.api.crateApiFileSavePasswordWithKdf(
+        password: password, dir: dir, kdf: kdf);
+
+Future<KdfConfig> benchmarkKdf({required int targetMillis}) =>
+    RustLib.instance.api.crateApiFileBenchmarkKdf(targetMillis: targetMillis);
+
+Future<void> changePassword(
+        {required String oldPassword,
+        required String newPassword,
+        required String dir}) =>
+    RustLib.instance.api.crateApiFileChangePassword(
+        oldPassword: oldPassword, newPassword: newPassword, dir: dir);
+
+Future<int> addKeySlot(
+        {required String password,
+        required String dir,
+        required KeySlotKind kind,
+        required String secret,
+        required String label}) =>
+    RustLib.instance.api.crateApiFileAddKeySlot(
+        password: password, dir: dir, kind: kind, secret: secret, label: label);
+
+Future<String> addRecoveryKey(
+        {required String password, required String dir}) =>
+    RustLib.instance.api
+        .crateApiFileAddRecoveryKey(password: password, dir: dir);
+
+Future<List<KeySlotInfo>> listKeySlots({required String dir}) =>
+    RustLib.instance.api.crateApiFileListKeySlots(dir: dir);
+
+Future<void> revokeKeySlot(
+        {required String password, required String dir, required int slotId}) =>
+    RustLib.instance.api.crateApiFileRevokeKeySlot(
+        password: password, dir: dir, slotId: slotId);
+
+Future<void> lockVault() => RustLib.instance.api.crateApiFileLockVault();
+
+Future<bool> isUnlocked() => RustLib.instance.api.crateApiFileIsUnlocked();
+
+Future<void> setAutoLockTimeout({required BigInt seconds}) =>
+    RustLib.instance.api.crateApiFileSetAutoLockTimeout(seconds: seconds);
+
+Stream<VaultEvent> vaultEvents() =>
+    RustLib.instance.api.crateApiFileVaultEvents();
+
 Future<bool> checkPasswordExist({required String dir}) =>
     RustLib.instance.api.crateApiFileCheckPasswordExist(dir: dir);
 
@@ -40,11 +101,61 @@ Future<Uint8List> getFileThumb({required String path}) =>
 Future<Uint8List> getFile({required String path}) =>
     RustLib.instance.api.crateApiFileGetFile(path: path);
 
-Future<void> saveMedia({required List<int> imageData, required String dir}) =>
-    RustLib.instance.api.crateApiFileSaveMedia(imageData: imageData, dir: dir);
+Future<Uint8List> readRange(
+        {required String path, required BigInt offset, required BigInt len}) =>
+    RustLib.instance.api
+        .crateApiFileReadRange(path: path, offset: offset, len: len);
+
+Stream<Uint8List> readFileStream({required String path}) =>
+    RustLib.instance.api.crateApiFileReadFileStream(path: path);
+
+Future<String> mediaUrl({required String path}) =>
+    RustLib.instance.api.crateApiFileMediaUrl(path: path);
 
-Future<void> saveFile({required List<int> imageData, required String dir}) =>
-    RustLib.instance.api.crateApiFileSaveFile(imageData: imageData, dir: dir);
+Future<void> stopMediaServer() =>
+    RustLib.instance.api.crateApiFileStopMediaServer();
+
+Future<void> saveMedia(
+        {required List<int> imageData,
+        required String dir,
+        MediaSource? source}) =>
+    RustLib.instance.api
+        .crateApiFileSaveMedia(imageData: imageData, dir: dir, source: source);
+
+Future<void> saveFile(
+        {required List<int> imageData,
+        required String dir,
+        MediaSource? source}) =>
+    RustLib.instance.api
+        .crateApiFileSaveFile(imageData: imageData, dir: dir, source: source);
+
+Future<void> importPath(
+        {required String srcPath,
+        required String dir,
+        MediaSource? source,
+        required bool secureDelete}) =>
+    RustLib.instance.api.crateApiFileImportPath(
+        srcPath: srcPath, dir: dir, source: source, secureDelete: secureDelete);
+
+Future<void> importPaths(
+        {required List<String> srcPaths,
+        required String dir,
+        required bool secureDelete,
+        RustStreamSink<ProgressEvent>? progress,
+        required CancelToken cancel}) =>
+    RustLib.instance.api.crateApiFileImportPaths(
+        srcPaths: srcPaths,
+        dir: dir,
+        secureDelete: secureDelete,
+        progress: progress,
+        cancel: cancel);
+
+Future<MediaWriter> openMediaWriter(
+        {required String dir, MediaSource? source}) =>
+    RustLib.instance.api.crateApiFileOpenMediaWriter(dir: dir, source: source);
+
+Future<MediaInfo> getMediaInfo({required String path}) =>
+    RustLib.instance.api.crateApiFileGetMediaInfo(path: path);
 
 Future<void> deleteFile({required String path}) =>
     RustLib.instance.api.crateApiFileDeleteFile(path: path);
@@ -53,17 +164,59 @@ Future<void> moveFile({required String sourceFile, required String destDir}) =>
     RustLib.instance.api
         .crateApiFileMoveFile(sourceFile: sourceFile, destDir: destDir);
 
+Future<CancelToken> createCancelToken() =>
+    RustLib.instance.api.crateApiFileCreateCancelToken();
+
+Future<void> cancelOperation({required CancelToken token}) =>
+    RustLib.instance.api.crateApiFileCancelOperation(token: token);
+
 Future<void> zipBackup(
         {required String rootDir,
         required String savePath,
-        required bool encryption}) =>
+        required bool encryption,
+        RustStreamSink<ProgressEvent>? progress,
+        required CancelToken cancel}) =>
     RustLib.instance.api.crateApiFileZipBackup(
-        rootDir: rootDir, savePath: savePath, encryption: encryption);
+        rootDir: rootDir,
+        savePath: savePath,
+        encryption: encryption,
+        progress: progress,
+        cancel: cancel);
 
-Future<void> restoreBackup(
-        {required String rootDir, required String zipPath, String? password}) =>
+Future<RestoreSummary> restoreBackup(
+        {required String rootDir,
+        required String zipPath,
+        String? password,
+        required DuplicatePolicy duplicates,
+        RustStreamSink<ProgressEvent>? progress,
+        required CancelToken cancel}) =>
     RustLib.instance.api.crateApiFileRestoreBackup(
-        rootDir: rootDir, zipPath: zipPath, password: password);
+        rootDir: rootDir,
+        zipPath: zipPath,
+        password: password,
+        duplicates: duplicates,
+        progress: progress,
+        cancel: cancel);
+
+Future<RestoreSummary> restoreBackupSelection(
+        {required String rootDir,
+        required String zipPath,
+        String? password,
+        required DuplicatePolicy duplicates,
+        required BackupSelection selection,
+        RustStreamSink<ProgressEvent>? progress,
+        required CancelToken cancel}) =>
+    RustLib.instance.api.crateApiFileRestoreBackupSelection(
+        rootDir: rootDir,
+        zipPath: zipPath,
+        password: password,
+        duplicates: duplicates,
+        selection: selection,
+        progress: progress,
+        cancel: cancel);
+
+Future<BackupContents> listBackup({required String zipPath}) =>
+    RustLib.instance.api.crateApiFileListBackup(zipPath: zipPath);
 
 Future<bool> checkZipPassword(
         {required String zipPath, required String password}) =>
@@ -72,3 +225,11 @@ Future<bool> checkZipPassword(
 
 Future<bool> checkZipEncrypted({required String zipPath}) =>
     RustLib.instance.api.crateApiFileCheckZipEncrypted(zipPath: zipPath);
+
+Future<BackupManifest> inspectBackup({required String zipPath}) =>
+    RustLib.instance.api.crateApiFileInspectBackup(zipPath: zipPath);
+
+Future<BackupReport> verifyBackup(
+        {required String zipPath, String? password}) =>
+    RustLib.instance.api
+        .crateApiFileVerifyBackup(zipPath: zipPath, password: password);
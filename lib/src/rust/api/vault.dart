@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../utils/backup.dart';
+import '../utils/error.dart';
+import '../utils/progress.dart';
+import '../utils/storage.dart';
+import 'media.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `album_of`, `cached_images`, `clear_cache`, `key`, `new`, `resolve`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Vault>>
+abstract class Vault implements RustOpaqueInterface {
+  Future<List<String>> albums();
+
+  static Future<Vault> create(
+          {required String rootDir, required String password}) =>
+      RustLib.instance.api
+          .crateApiVaultVaultCreate(rootDir: rootDir, password: password);
+
+  Future<void> createAlbum({required String name});
+
+  Future<void> deleteAlbum({required String album});
+
+  Future<void> deleteFile({required String path});
+
+  Future<Map<String, (String, double)>?> getAlbumThumb({required String album});
+
+  Future<Uint8List> getFile({required String path});
+
+  Future<Uint8List> getFileThumb({required String path});
+
+  Future<Map<String, (String, double)>> getImages({required String album});
+
+  Future<MediaInfo> getMediaInfo({required String path});
+
+  Future<void> importPath(
+      {required String srcPath,
+      required String album,
+      MediaSource? source,
+      required bool secureDelete});
+
+  Future<void> importPaths(
+      {required List<String> srcPaths,
+      required String album,
+      required bool secureDelete,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<bool> isUnlocked();
+
+  Future<void> lock();
+
+  Future<String> mediaUrl({required String path});
+
+  Future<void> moveFile({required String path, required String destAlbum});
+
+  static Future<Vault> open(
+          {required String rootDir, required String password}) =>
+      RustLib.instance.api
+          .crateApiVaultVaultOpen(rootDir: rootDir, password: password);
+
+  Future<MediaWriter> openWriter({required String album, MediaSource? source});
+
+  Future<Uint8List> readRange(
+      {required String path, required BigInt offset, required BigInt len});
+
+  Stream<Uint8List> readStream({required String path});
+
+  Future<RestoreSummary> restoreBackup(
+      {required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<RestoreSummary> restoreBackupSelection(
+      {required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      required BackupSelection selection,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<String> root();
+
+  Future<void> saveFile(
+      {required List<int> data, required String album, MediaSource? source});
+
+  Future<void> saveMedia(
+      {required List<int> data, required String album, MediaSource? source});
+
+  Future<void> zipBackup(
+      {required String savePath,
+      required bool encryption,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+}
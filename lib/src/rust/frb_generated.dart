@@ -4,13 +4,22 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/file.dart';
+import 'api/media.dart';
+import 'api/vault.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils/backup.dart';
 import 'utils/error.dart';
+import 'utils/kdf.dart';
+import 'utils/keyslot.dart';
+import 'utils/manifest.dart';
+import 'utils/progress.dart';
+import 'utils/session.dart';
+import 'utils/storage.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -67,7 +76,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.10.0';
 
   @override
-  int get rustContentHash => -1266995892;
+  int get rustContentHash => -191200866;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -78,6 +87,139 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<void> crateApiMediaMediaWriterAbort({required MediaWriter that});
+
+  Future<void> crateApiMediaMediaWriterFinish({required MediaWriter that});
+
+  Future<void> crateApiMediaMediaWriterWrite(
+      {required MediaWriter that, required List<int> data});
+
+  Future<List<String>> crateApiVaultVaultAlbums({required Vault that});
+
+  Future<Vault> crateApiVaultVaultCreate(
+      {required String rootDir, required String password});
+
+  Future<void> crateApiVaultVaultCreateAlbum(
+      {required Vault that, required String name});
+
+  Future<void> crateApiVaultVaultDeleteAlbum(
+      {required Vault that, required String album});
+
+  Future<void> crateApiVaultVaultDeleteFile(
+      {required Vault that, required String path});
+
+  Future<Map<String, (String, double)>?> crateApiVaultVaultGetAlbumThumb(
+      {required Vault that, required String album});
+
+  Future<Uint8List> crateApiVaultVaultGetFile(
+      {required Vault that, required String path});
+
+  Future<Uint8List> crateApiVaultVaultGetFileThumb(
+      {required Vault that, required String path});
+
+  Future<Map<String, (String, double)>> crateApiVaultVaultGetImages(
+      {required Vault that, required String album});
+
+  Future<MediaInfo> crateApiVaultVaultGetMediaInfo(
+      {required Vault that, required String path});
+
+  Future<void> crateApiVaultVaultImportPath(
+      {required Vault that,
+      required String srcPath,
+      required String album,
+      MediaSource? source,
+      required bool secureDelete});
+
+  Future<void> crateApiVaultVaultImportPaths(
+      {required Vault that,
+      required List<String> srcPaths,
+      required String album,
+      required bool secureDelete,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<bool> crateApiVaultVaultIsUnlocked({required Vault that});
+
+  Future<void> crateApiVaultVaultLock({required Vault that});
+
+  Future<String> crateApiVaultVaultMediaUrl(
+      {required Vault that, required String path});
+
+  Future<void> crateApiVaultVaultMoveFile(
+      {required Vault that, required String path, required String destAlbum});
+
+  Future<Vault> crateApiVaultVaultOpen(
+      {required String rootDir, required String password});
+
+  Future<MediaWriter> crateApiVaultVaultOpenWriter(
+      {required Vault that, required String album, MediaSource? source});
+
+  Future<Uint8List> crateApiVaultVaultReadRange(
+      {required Vault that,
+      required String path,
+      required BigInt offset,
+      required BigInt len});
+
+  Stream<Uint8List> crateApiVaultVaultReadStream(
+      {required Vault that, required String path});
+
+  Future<RestoreSummary> crateApiVaultVaultRestoreBackup(
+      {required Vault that,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<RestoreSummary> crateApiVaultVaultRestoreBackupSelection(
+      {required Vault that,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      required BackupSelection selection,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<String> crateApiVaultVaultRoot({required Vault that});
+
+  Future<void> crateApiVaultVaultSaveFile(
+      {required Vault that,
+      required List<int> data,
+      required String album,
+      MediaSource? source});
+
+  Future<void> crateApiVaultVaultSaveMedia(
+      {required Vault that,
+      required List<int> data,
+      required String album,
+      MediaSource? source});
+
+  Future<void> crateApiVaultVaultZipBackup(
+      {required Vault that,
+      required String savePath,
+      required bool encryption,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<int> crateApiFileAddKeySlot(
+      {required String password,
+      required String dir,
+      required KeySlotKind kind,
+      required String secret,
+      required String label});
+
+  Future<String> crateApiFileAddRecoveryKey(
+      {required String password, required String dir});
+
+  Future<KdfConfig> crateApiFileBenchmarkKdf({required int targetMillis});
+
+  Future<void> crateApiFileCancelOperation({required CancelToken token});
+
+  Future<void> crateApiFileChangePassword(
+      {required String oldPassword,
+      required String newPassword,
+      required String dir});
+
   Future<bool> crateApiFileCheckPasswordExist({required String dir});
 
   Future<bool> crateApiFileCheckZipEncrypted({required String zipPath});
@@ -85,6 +227,8 @@ abstract class RustLibApi extends BaseApi {
   Future<bool> crateApiFileCheckZipPassword(
       {required String zipPath, required String password});
 
+  Future<CancelToken> crateApiFileCreateCancelToken();
+
   Future<void> crateApiFileCreateDir(
       {required String dir, required String albumName});
 
@@ -104,30 +248,118 @@ abstract class RustLibApi extends BaseApi {
   Future<Map<String, (String, double)>> crateApiFileGetImages(
       {required String dir});
 
+  Future<MediaInfo> crateApiFileGetMediaInfo({required String path});
+
+  Future<void> crateApiFileImportPath(
+      {required String srcPath,
+      required String dir,
+      MediaSource? source,
+      required bool secureDelete});
+
+  Future<void> crateApiFileImportPaths(
+      {required List<String> srcPaths,
+      required String dir,
+      required bool secureDelete,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<BackupManifest> crateApiFileInspectBackup({required String zipPath});
+
+  Future<bool> crateApiFileIsUnlocked();
+
   Future<bool> crateApiFileIsVideo({required List<int> imageData});
 
+  Future<BackupContents> crateApiFileListBackup({required String zipPath});
+
+  Future<List<KeySlotInfo>> crateApiFileListKeySlots({required String dir});
+
+  Future<void> crateApiFileLockVault();
+
+  Future<String> crateApiFileMediaUrl({required String path});
+
   Future<void> crateApiFileMoveFile(
       {required String sourceFile, required String destDir});
 
-  Future<void> crateApiFileRestoreBackup(
-      {required String rootDir, required String zipPath, String? password});
+  Future<MediaWriter> crateApiFileOpenMediaWriter(
+      {required String dir, MediaSource? source});
+
+  Stream<Uint8List> crateApiFileReadFileStream({required String path});
+
+  Future<Uint8List> crateApiFileReadRange(
+      {required String path, required BigInt offset, required BigInt len});
+
+  Future<RestoreSummary> crateApiFileRestoreBackup(
+      {required String rootDir,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<RestoreSummary> crateApiFileRestoreBackupSelection(
+      {required String rootDir,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      required BackupSelection selection,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  Future<void> crateApiFileRevokeKeySlot(
+      {required String password, required String dir, required int slotId});
 
   Future<void> crateApiFileSaveFile(
-      {required List<int> imageData, required String dir});
+      {required List<int> imageData, required String dir, MediaSource? source});
 
   Future<void> crateApiFileSaveMedia(
-      {required List<int> imageData, required String dir});
+      {required List<int> imageData, required String dir, MediaSource? source});
 
   Future<void> crateApiFileSavePassword(
       {required String password, required String dir});
 
+  Future<void> crateApiFileSavePasswordWithKdf(
+      {required String password, required String dir, required KdfConfig kdf});
+
+  Future<void> crateApiFileSetAutoLockTimeout({required BigInt seconds});
+
   Future<bool> crateApiFileSetPassword(
       {required String password, required String dir});
 
+  Future<void> crateApiFileStopMediaServer();
+
+  Stream<VaultEvent> crateApiFileVaultEvents();
+
+  Future<BackupReport> crateApiFileVerifyBackup(
+      {required String zipPath, String? password});
+
   Future<void> crateApiFileZipBackup(
       {required String rootDir,
       required String savePath,
-      required bool encryption});
+      required bool encryption,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel});
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_CancelToken;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_CancelToken;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancelTokenPtr;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_MediaWriter;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_MediaWriter;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MediaWriterPtr;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Vault;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Vault;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VaultPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -139,138 +371,146 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<bool> crateApiFileCheckPasswordExist({required String dir}) {
+  Future<void> crateApiMediaMediaWriterAbort({required MediaWriter that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 1, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileCheckPasswordExistConstMeta,
-      argValues: [dir],
+      constMeta: kCrateApiMediaMediaWriterAbortConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileCheckPasswordExistConstMeta =>
+  TaskConstMeta get kCrateApiMediaMediaWriterAbortConstMeta =>
       const TaskConstMeta(
-        debugName: "check_password_exist",
-        argNames: ["dir"],
+        debugName: "MediaWriter_abort",
+        argNames: ["that"],
       );
 
   @override
-  Future<bool> crateApiFileCheckZipEncrypted({required String zipPath}) {
+  Future<void> crateApiMediaMediaWriterFinish({required MediaWriter that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(zipPath, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileCheckZipEncryptedConstMeta,
-      argValues: [zipPath],
+      constMeta: kCrateApiMediaMediaWriterFinishConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileCheckZipEncryptedConstMeta =>
+  TaskConstMeta get kCrateApiMediaMediaWriterFinishConstMeta =>
       const TaskConstMeta(
-        debugName: "check_zip_encrypted",
-        argNames: ["zipPath"],
+        debugName: "MediaWriter_finish",
+        argNames: ["that"],
       );
 
   @override
-  Future<bool> crateApiFileCheckZipPassword(
-      {required String zipPath, required String password}) {
+  Future<void> crateApiMediaMediaWriterWrite(
+      {required MediaWriter that, required List<int> data}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(zipPath, serializer);
-        sse_encode_String(password, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+            that, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileCheckZipPasswordConstMeta,
-      argValues: [zipPath, password],
+      constMeta: kCrateApiMediaMediaWriterWriteConstMeta,
+      argValues: [that, data],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileCheckZipPasswordConstMeta =>
+  TaskConstMeta get kCrateApiMediaMediaWriterWriteConstMeta =>
       const TaskConstMeta(
-        debugName: "check_zip_password",
-        argNames: ["zipPath", "password"],
+        debugName: "MediaWriter_write",
+        argNames: ["that", "data"],
       );
 
   @override
-  Future<void> crateApiFileCreateDir(
-      {required String dir, required String albumName}) {
+  Future<List<String>> crateApiVaultVaultAlbums({required Vault that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dir, serializer);
-        sse_encode_String(albumName, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 4, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_list_String,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileCreateDirConstMeta,
-      argValues: [dir, albumName],
+      constMeta: kCrateApiVaultVaultAlbumsConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileCreateDirConstMeta => const TaskConstMeta(
-        debugName: "create_dir",
-        argNames: ["dir", "albumName"],
+  TaskConstMeta get kCrateApiVaultVaultAlbumsConstMeta => const TaskConstMeta(
+        debugName: "Vault_albums",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiFileDeleteDir({required String dir}) {
+  Future<Vault> crateApiVaultVaultCreate(
+      {required String rootDir, required String password}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dir, serializer);
+        sse_encode_String(rootDir, serializer);
+        sse_encode_String(password, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 5, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileDeleteDirConstMeta,
-      argValues: [dir],
+      constMeta: kCrateApiVaultVaultCreateConstMeta,
+      argValues: [rootDir, password],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileDeleteDirConstMeta => const TaskConstMeta(
-        debugName: "delete_dir",
-        argNames: ["dir"],
+  TaskConstMeta get kCrateApiVaultVaultCreateConstMeta => const TaskConstMeta(
+        debugName: "Vault_create",
+        argNames: ["rootDir", "password"],
       );
 
   @override
-  Future<void> crateApiFileDeleteFile({required String path}) {
+  Future<void> crateApiVaultVaultCreateAlbum(
+      {required Vault that, required String name}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(name, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 6, port: port_);
       },
@@ -278,95 +518,110 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileDeleteFileConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiVaultVaultCreateAlbumConstMeta,
+      argValues: [that, name],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileDeleteFileConstMeta => const TaskConstMeta(
-        debugName: "delete_file",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiVaultVaultCreateAlbumConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_create_album",
+        argNames: ["that", "name"],
       );
 
   @override
-  Future<Map<String, (String, double)>?> crateApiFileGetAlbumThumb(
-      {required String dir}) {
+  Future<void> crateApiVaultVaultDeleteAlbum(
+      {required Vault that, required String album}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(album, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 7, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_Map_String_record_string_f_32_None,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileGetAlbumThumbConstMeta,
-      argValues: [dir],
+      constMeta: kCrateApiVaultVaultDeleteAlbumConstMeta,
+      argValues: [that, album],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileGetAlbumThumbConstMeta => const TaskConstMeta(
-        debugName: "get_album_thumb",
-        argNames: ["dir"],
+  TaskConstMeta get kCrateApiVaultVaultDeleteAlbumConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_delete_album",
+        argNames: ["that", "album"],
       );
 
   @override
-  Future<List<String>> crateApiFileGetDirs({required String dir}) {
+  Future<void> crateApiVaultVaultDeleteFile(
+      {required Vault that, required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 8, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileGetDirsConstMeta,
-      argValues: [dir],
+      constMeta: kCrateApiVaultVaultDeleteFileConstMeta,
+      argValues: [that, path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileGetDirsConstMeta => const TaskConstMeta(
-        debugName: "get_dirs",
-        argNames: ["dir"],
+  TaskConstMeta get kCrateApiVaultVaultDeleteFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_delete_file",
+        argNames: ["that", "path"],
       );
 
   @override
-  Future<Uint8List> crateApiFileGetFile({required String path}) {
+  Future<Map<String, (String, double)>?> crateApiVaultVaultGetAlbumThumb(
+      {required Vault that, required String album}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(album, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeSuccessData: sse_decode_opt_Map_String_record_string_f_32_None,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileGetFileConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiVaultVaultGetAlbumThumbConstMeta,
+      argValues: [that, album],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileGetFileConstMeta => const TaskConstMeta(
-        debugName: "get_file",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiVaultVaultGetAlbumThumbConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_get_album_thumb",
+        argNames: ["that", "album"],
       );
 
   @override
-  Future<Uint8List> crateApiFileGetFileThumb({required String path}) {
+  Future<Uint8List> crateApiVaultVaultGetFile(
+      {required Vault that, required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
         sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 10, port: port_);
@@ -375,101 +630,117 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_list_prim_u_8_strict,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileGetFileThumbConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiVaultVaultGetFileConstMeta,
+      argValues: [that, path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileGetFileThumbConstMeta => const TaskConstMeta(
-        debugName: "get_file_thumb",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiVaultVaultGetFileConstMeta => const TaskConstMeta(
+        debugName: "Vault_get_file",
+        argNames: ["that", "path"],
       );
 
   @override
-  Future<Map<String, (String, double)>> crateApiFileGetImages(
-      {required String dir}) {
+  Future<Uint8List> crateApiVaultVaultGetFileThumb(
+      {required Vault that, required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 11, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_Map_String_record_string_f_32_None,
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileGetImagesConstMeta,
-      argValues: [dir],
+      constMeta: kCrateApiVaultVaultGetFileThumbConstMeta,
+      argValues: [that, path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileGetImagesConstMeta => const TaskConstMeta(
-        debugName: "get_images",
-        argNames: ["dir"],
+  TaskConstMeta get kCrateApiVaultVaultGetFileThumbConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_get_file_thumb",
+        argNames: ["that", "path"],
       );
 
   @override
-  Future<bool> crateApiFileIsVideo({required List<int> imageData}) {
+  Future<Map<String, (String, double)>> crateApiVaultVaultGetImages(
+      {required Vault that, required String album}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(imageData, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(album, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 12, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_Map_String_record_string_f_32_None,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileIsVideoConstMeta,
-      argValues: [imageData],
+      constMeta: kCrateApiVaultVaultGetImagesConstMeta,
+      argValues: [that, album],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileIsVideoConstMeta => const TaskConstMeta(
-        debugName: "is_video",
-        argNames: ["imageData"],
+  TaskConstMeta get kCrateApiVaultVaultGetImagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_get_images",
+        argNames: ["that", "album"],
       );
 
   @override
-  Future<void> crateApiFileMoveFile(
-      {required String sourceFile, required String destDir}) {
+  Future<MediaInfo> crateApiVaultVaultGetMediaInfo(
+      {required Vault that, required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(sourceFile, serializer);
-        sse_encode_String(destDir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 13, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_media_info,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileMoveFileConstMeta,
-      argValues: [sourceFile, destDir],
+      constMeta: kCrateApiVaultVaultGetMediaInfoConstMeta,
+      argValues: [that, path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileMoveFileConstMeta => const TaskConstMeta(
-        debugName: "move_file",
-        argNames: ["sourceFile", "destDir"],
+  TaskConstMeta get kCrateApiVaultVaultGetMediaInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_get_media_info",
+        argNames: ["that", "path"],
       );
 
   @override
-  Future<void> crateApiFileRestoreBackup(
-      {required String rootDir, required String zipPath, String? password}) {
+  Future<void> crateApiVaultVaultImportPath(
+      {required Vault that,
+      required String srcPath,
+      required String album,
+      MediaSource? source,
+      required bool secureDelete}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(rootDir, serializer);
-        sse_encode_String(zipPath, serializer);
-        sse_encode_opt_String(password, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(srcPath, serializer);
+        sse_encode_String(album, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        sse_encode_bool(secureDelete, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 14, port: port_);
       },
@@ -477,25 +748,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileRestoreBackupConstMeta,
-      argValues: [rootDir, zipPath, password],
+      constMeta: kCrateApiVaultVaultImportPathConstMeta,
+      argValues: [that, srcPath, album, source, secureDelete],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileRestoreBackupConstMeta => const TaskConstMeta(
-        debugName: "restore_backup",
-        argNames: ["rootDir", "zipPath", "password"],
+  TaskConstMeta get kCrateApiVaultVaultImportPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_import_path",
+        argNames: ["that", "srcPath", "album", "source", "secureDelete"],
       );
 
   @override
-  Future<void> crateApiFileSaveFile(
-      {required List<int> imageData, required String dir}) {
+  Future<void> crateApiVaultVaultImportPaths(
+      {required Vault that,
+      required List<String> srcPaths,
+      required String album,
+      required bool secureDelete,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(imageData, serializer);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_list_String(srcPaths, serializer);
+        sse_encode_String(album, serializer);
+        sse_encode_bool(secureDelete, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 15, port: port_);
       },
@@ -503,51 +786,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileSaveFileConstMeta,
-      argValues: [imageData, dir],
+      constMeta: kCrateApiVaultVaultImportPathsConstMeta,
+      argValues: [that, srcPaths, album, secureDelete, progress, cancel],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileSaveFileConstMeta => const TaskConstMeta(
-        debugName: "save_file",
-        argNames: ["imageData", "dir"],
+  TaskConstMeta get kCrateApiVaultVaultImportPathsConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_import_paths",
+        argNames: [
+          "that",
+          "srcPaths",
+          "album",
+          "secureDelete",
+          "progress",
+          "cancel"
+        ],
       );
 
   @override
-  Future<void> crateApiFileSaveMedia(
-      {required List<int> imageData, required String dir}) {
+  Future<bool> crateApiVaultVaultIsUnlocked({required Vault that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(imageData, serializer);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 16, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_vault_error,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiFileSaveMediaConstMeta,
-      argValues: [imageData, dir],
+      constMeta: kCrateApiVaultVaultIsUnlockedConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileSaveMediaConstMeta => const TaskConstMeta(
-        debugName: "save_media",
-        argNames: ["imageData", "dir"],
+  TaskConstMeta get kCrateApiVaultVaultIsUnlockedConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_is_unlocked",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiFileSavePassword(
-      {required String password, required String dir}) {
+  Future<void> crateApiVaultVaultLock({required Vault that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(password, serializer);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 17, port: port_);
       },
@@ -555,54 +845,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileSavePasswordConstMeta,
-      argValues: [password, dir],
+      constMeta: kCrateApiVaultVaultLockConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileSavePasswordConstMeta => const TaskConstMeta(
-        debugName: "save_password",
-        argNames: ["password", "dir"],
+  TaskConstMeta get kCrateApiVaultVaultLockConstMeta => const TaskConstMeta(
+        debugName: "Vault_lock",
+        argNames: ["that"],
       );
 
   @override
-  Future<bool> crateApiFileSetPassword(
-      {required String password, required String dir}) {
+  Future<String> crateApiVaultVaultMediaUrl(
+      {required Vault that, required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(password, serializer);
-        sse_encode_String(dir, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 18, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileSetPasswordConstMeta,
-      argValues: [password, dir],
+      constMeta: kCrateApiVaultVaultMediaUrlConstMeta,
+      argValues: [that, path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileSetPasswordConstMeta => const TaskConstMeta(
-        debugName: "set_password",
-        argNames: ["password", "dir"],
+  TaskConstMeta get kCrateApiVaultVaultMediaUrlConstMeta => const TaskConstMeta(
+        debugName: "Vault_media_url",
+        argNames: ["that", "path"],
       );
 
   @override
-  Future<void> crateApiFileZipBackup(
-      {required String rootDir,
-      required String savePath,
-      required bool encryption}) {
+  Future<void> crateApiVaultVaultMoveFile(
+      {required Vault that, required String path, required String destAlbum}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(rootDir, serializer);
-        sse_encode_String(savePath, serializer);
-        sse_encode_bool(encryption, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(destAlbum, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 19, port: port_);
       },
@@ -610,312 +900,3444 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_vault_error,
       ),
-      constMeta: kCrateApiFileZipBackupConstMeta,
-      argValues: [rootDir, savePath, encryption],
+      constMeta: kCrateApiVaultVaultMoveFileConstMeta,
+      argValues: [that, path, destAlbum],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiFileZipBackupConstMeta => const TaskConstMeta(
-        debugName: "zip_backup",
-        argNames: ["rootDir", "savePath", "encryption"],
+  TaskConstMeta get kCrateApiVaultVaultMoveFileConstMeta => const TaskConstMeta(
+        debugName: "Vault_move_file",
+        argNames: ["that", "path", "destAlbum"],
       );
 
-  @protected
-  Map<String, (String, double)> dco_decode_Map_String_record_string_f_32_None(
+  @override
+  Future<Vault> crateApiVaultVaultOpen(
+      {required String rootDir, required String password}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootDir, serializer);
+        sse_encode_String(password, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 20, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultOpenConstMeta,
+      argValues: [rootDir, password],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultOpenConstMeta => const TaskConstMeta(
+        debugName: "Vault_open",
+        argNames: ["rootDir", "password"],
+      );
+
+  @override
+  Future<MediaWriter> crateApiVaultVaultOpenWriter(
+      {required Vault that, required String album, MediaSource? source}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(album, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 21, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultOpenWriterConstMeta,
+      argValues: [that, album, source],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultOpenWriterConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_open_writer",
+        argNames: ["that", "album", "source"],
+      );
+
+  @override
+  Future<Uint8List> crateApiVaultVaultReadRange(
+      {required Vault that,
+      required String path,
+      required BigInt offset,
+      required BigInt len}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_u_64(offset, serializer);
+        sse_encode_u_64(len, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 22, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultReadRangeConstMeta,
+      argValues: [that, path, offset, len],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultReadRangeConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_read_range",
+        argNames: ["that", "path", "offset", "len"],
+      );
+
+  @override
+  Stream<Uint8List> crateApiVaultVaultReadStream(
+      {required Vault that, required String path}) {
+    final sink = RustStreamSink<Uint8List>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 23, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultReadStreamConstMeta,
+      argValues: [that, path, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultReadStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_read_stream",
+        argNames: ["that", "path", "sink"],
+      );
+
+  @override
+  Future<RestoreSummary> crateApiVaultVaultRestoreBackup(
+      {required Vault that,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(zipPath, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_duplicate_policy(duplicates, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 24, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_restore_summary,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultRestoreBackupConstMeta,
+      argValues: [that, zipPath, password, duplicates, progress, cancel],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultRestoreBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_restore_backup",
+        argNames: [
+          "that",
+          "zipPath",
+          "password",
+          "duplicates",
+          "progress",
+          "cancel"
+        ],
+      );
+
+  @override
+  Future<RestoreSummary> crateApiVaultVaultRestoreBackupSelection(
+      {required Vault that,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      required BackupSelection selection,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(zipPath, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_duplicate_policy(duplicates, serializer);
+        sse_encode_box_autoadd_backup_selection(selection, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_restore_summary,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultRestoreBackupSelectionConstMeta,
+      argValues: [
+        that,
+        zipPath,
+        password,
+        duplicates,
+        selection,
+        progress,
+        cancel
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultRestoreBackupSelectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_restore_backup_selection",
+        argNames: [
+          "that",
+          "zipPath",
+          "password",
+          "duplicates",
+          "selection",
+          "progress",
+          "cancel"
+        ],
+      );
+
+  @override
+  Future<String> crateApiVaultVaultRoot({required Vault that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 26, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiVaultVaultRootConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultRootConstMeta => const TaskConstMeta(
+        debugName: "Vault_root",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateApiVaultVaultSaveFile(
+      {required Vault that,
+      required List<int> data,
+      required String album,
+      MediaSource? source}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_String(album, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultSaveFileConstMeta,
+      argValues: [that, data, album, source],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultSaveFileConstMeta => const TaskConstMeta(
+        debugName: "Vault_save_file",
+        argNames: ["that", "data", "album", "source"],
+      );
+
+  @override
+  Future<void> crateApiVaultVaultSaveMedia(
+      {required Vault that,
+      required List<int> data,
+      required String album,
+      MediaSource? source}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_String(album, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultSaveMediaConstMeta,
+      argValues: [that, data, album, source],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultSaveMediaConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_save_media",
+        argNames: ["that", "data", "album", "source"],
+      );
+
+  @override
+  Future<void> crateApiVaultVaultZipBackup(
+      {required Vault that,
+      required String savePath,
+      required bool encryption,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+            that, serializer);
+        sse_encode_String(savePath, serializer);
+        sse_encode_bool(encryption, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiVaultVaultZipBackupConstMeta,
+      argValues: [that, savePath, encryption, progress, cancel],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiVaultVaultZipBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "Vault_zip_backup",
+        argNames: ["that", "savePath", "encryption", "progress", "cancel"],
+      );
+
+  @override
+  Future<int> crateApiFileAddKeySlot(
+      {required String password,
+      required String dir,
+      required KeySlotKind kind,
+      required String secret,
+      required String label}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(password, serializer);
+        sse_encode_String(dir, serializer);
+        sse_encode_key_slot_kind(kind, serializer);
+        sse_encode_String(secret, serializer);
+        sse_encode_String(label, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 30, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileAddKeySlotConstMeta,
+      argValues: [password, dir, kind, secret, label],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileAddKeySlotConstMeta => const TaskConstMeta(
+        debugName: "add_key_slot",
+        argNames: ["password", "dir", "kind", "secret", "label"],
+      );
+
+  @override
+  Future<String> crateApiFileAddRecoveryKey(
+      {required String password, required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(password, serializer);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileAddRecoveryKeyConstMeta,
+      argValues: [password, dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileAddRecoveryKeyConstMeta => const TaskConstMeta(
+        debugName: "add_recovery_key",
+        argNames: ["password", "dir"],
+      );
+
+  @override
+  Future<KdfConfig> crateApiFileBenchmarkKdf({required int targetMillis}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(targetMillis, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_kdf_config,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileBenchmarkKdfConstMeta,
+      argValues: [targetMillis],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileBenchmarkKdfConstMeta => const TaskConstMeta(
+        debugName: "benchmark_kdf",
+        argNames: ["targetMillis"],
+      );
+
+  @override
+  Future<void> crateApiFileCancelOperation({required CancelToken token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 33, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiFileCancelOperationConstMeta,
+      argValues: [token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileCancelOperationConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_operation",
+        argNames: ["token"],
+      );
+
+  @override
+  Future<void> crateApiFileChangePassword(
+      {required String oldPassword,
+      required String newPassword,
+      required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(oldPassword, serializer);
+        sse_encode_String(newPassword, serializer);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 34, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileChangePasswordConstMeta,
+      argValues: [oldPassword, newPassword, dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileChangePasswordConstMeta => const TaskConstMeta(
+        debugName: "change_password",
+        argNames: ["oldPassword", "newPassword", "dir"],
+      );
+
+  @override
+  Future<bool> crateApiFileCheckPasswordExist({required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiFileCheckPasswordExistConstMeta,
+      argValues: [dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileCheckPasswordExistConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_password_exist",
+        argNames: ["dir"],
+      );
+
+  @override
+  Future<bool> crateApiFileCheckZipEncrypted({required String zipPath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(zipPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileCheckZipEncryptedConstMeta,
+      argValues: [zipPath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileCheckZipEncryptedConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_zip_encrypted",
+        argNames: ["zipPath"],
+      );
+
+  @override
+  Future<bool> crateApiFileCheckZipPassword(
+      {required String zipPath, required String password}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(zipPath, serializer);
+        sse_encode_String(password, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileCheckZipPasswordConstMeta,
+      argValues: [zipPath, password],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileCheckZipPasswordConstMeta =>
+      const TaskConstMeta(
+        debugName: "check_zip_password",
+        argNames: ["zipPath", "password"],
+      );
+
+  @override
+  Future<CancelToken> crateApiFileCreateCancelToken() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiFileCreateCancelTokenConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileCreateCancelTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_cancel_token",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiFileCreateDir(
+      {required String dir, required String albumName}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        sse_encode_String(albumName, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileCreateDirConstMeta,
+      argValues: [dir, albumName],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileCreateDirConstMeta => const TaskConstMeta(
+        debugName: "create_dir",
+        argNames: ["dir", "albumName"],
+      );
+
+  @override
+  Future<void> crateApiFileDeleteDir({required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileDeleteDirConstMeta,
+      argValues: [dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileDeleteDirConstMeta => const TaskConstMeta(
+        debugName: "delete_dir",
+        argNames: ["dir"],
+      );
+
+  @override
+  Future<void> crateApiFileDeleteFile({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileDeleteFileConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileDeleteFileConstMeta => const TaskConstMeta(
+        debugName: "delete_file",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<Map<String, (String, double)>?> crateApiFileGetAlbumThumb(
+      {required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_Map_String_record_string_f_32_None,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileGetAlbumThumbConstMeta,
+      argValues: [dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileGetAlbumThumbConstMeta => const TaskConstMeta(
+        debugName: "get_album_thumb",
+        argNames: ["dir"],
+      );
+
+  @override
+  Future<List<String>> crateApiFileGetDirs({required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileGetDirsConstMeta,
+      argValues: [dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileGetDirsConstMeta => const TaskConstMeta(
+        debugName: "get_dirs",
+        argNames: ["dir"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFileGetFile({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileGetFileConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileGetFileConstMeta => const TaskConstMeta(
+        debugName: "get_file",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFileGetFileThumb({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileGetFileThumbConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileGetFileThumbConstMeta => const TaskConstMeta(
+        debugName: "get_file_thumb",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<Map<String, (String, double)>> crateApiFileGetImages(
+      {required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_Map_String_record_string_f_32_None,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileGetImagesConstMeta,
+      argValues: [dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileGetImagesConstMeta => const TaskConstMeta(
+        debugName: "get_images",
+        argNames: ["dir"],
+      );
+
+  @override
+  Future<MediaInfo> crateApiFileGetMediaInfo({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_media_info,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileGetMediaInfoConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileGetMediaInfoConstMeta => const TaskConstMeta(
+        debugName: "get_media_info",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiFileImportPath(
+      {required String srcPath,
+      required String dir,
+      MediaSource? source,
+      required bool secureDelete}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(srcPath, serializer);
+        sse_encode_String(dir, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        sse_encode_bool(secureDelete, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileImportPathConstMeta,
+      argValues: [srcPath, dir, source, secureDelete],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileImportPathConstMeta => const TaskConstMeta(
+        debugName: "import_path",
+        argNames: ["srcPath", "dir", "source", "secureDelete"],
+      );
+
+  @override
+  Future<void> crateApiFileImportPaths(
+      {required List<String> srcPaths,
+      required String dir,
+      required bool secureDelete,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(srcPaths, serializer);
+        sse_encode_String(dir, serializer);
+        sse_encode_bool(secureDelete, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileImportPathsConstMeta,
+      argValues: [srcPaths, dir, secureDelete, progress, cancel],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileImportPathsConstMeta => const TaskConstMeta(
+        debugName: "import_paths",
+        argNames: ["srcPaths", "dir", "secureDelete", "progress", "cancel"],
+      );
+
+  @override
+  Future<BackupManifest> crateApiFileInspectBackup({required String zipPath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(zipPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_backup_manifest,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileInspectBackupConstMeta,
+      argValues: [zipPath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileInspectBackupConstMeta => const TaskConstMeta(
+        debugName: "inspect_backup",
+        argNames: ["zipPath"],
+      );
+
+  @override
+  Future<bool> crateApiFileIsUnlocked() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiFileIsUnlockedConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileIsUnlockedConstMeta => const TaskConstMeta(
+        debugName: "is_unlocked",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiFileIsVideo({required List<int> imageData}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(imageData, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileIsVideoConstMeta,
+      argValues: [imageData],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileIsVideoConstMeta => const TaskConstMeta(
+        debugName: "is_video",
+        argNames: ["imageData"],
+      );
+
+  @override
+  Future<BackupContents> crateApiFileListBackup({required String zipPath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(zipPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_backup_contents,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileListBackupConstMeta,
+      argValues: [zipPath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileListBackupConstMeta => const TaskConstMeta(
+        debugName: "list_backup",
+        argNames: ["zipPath"],
+      );
+
+  @override
+  Future<List<KeySlotInfo>> crateApiFileListKeySlots({required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_key_slot_info,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileListKeySlotsConstMeta,
+      argValues: [dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileListKeySlotsConstMeta => const TaskConstMeta(
+        debugName: "list_key_slots",
+        argNames: ["dir"],
+      );
+
+  @override
+  Future<void> crateApiFileLockVault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 55, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileLockVaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileLockVaultConstMeta => const TaskConstMeta(
+        debugName: "lock_vault",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiFileMediaUrl({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 56, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileMediaUrlConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileMediaUrlConstMeta => const TaskConstMeta(
+        debugName: "media_url",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiFileMoveFile(
+      {required String sourceFile, required String destDir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(sourceFile, serializer);
+        sse_encode_String(destDir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 57, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileMoveFileConstMeta,
+      argValues: [sourceFile, destDir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileMoveFileConstMeta => const TaskConstMeta(
+        debugName: "move_file",
+        argNames: ["sourceFile", "destDir"],
+      );
+
+  @override
+  Future<MediaWriter> crateApiFileOpenMediaWriter(
+      {required String dir, MediaSource? source}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dir, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 58, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileOpenMediaWriterConstMeta,
+      argValues: [dir, source],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileOpenMediaWriterConstMeta =>
+      const TaskConstMeta(
+        debugName: "open_media_writer",
+        argNames: ["dir", "source"],
+      );
+
+  @override
+  Stream<Uint8List> crateApiFileReadFileStream({required String path}) {
+    final sink = RustStreamSink<Uint8List>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 59, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileReadFileStreamConstMeta,
+      argValues: [path, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiFileReadFileStreamConstMeta => const TaskConstMeta(
+        debugName: "read_file_stream",
+        argNames: ["path", "sink"],
+      );
+
+  @override
+  Future<Uint8List> crateApiFileReadRange(
+      {required String path, required BigInt offset, required BigInt len}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_64(offset, serializer);
+        sse_encode_u_64(len, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 60, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_u_8_strict,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileReadRangeConstMeta,
+      argValues: [path, offset, len],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileReadRangeConstMeta => const TaskConstMeta(
+        debugName: "read_range",
+        argNames: ["path", "offset", "len"],
+      );
+
+  @override
+  Future<RestoreSummary> crateApiFileRestoreBackup(
+      {required String rootDir,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootDir, serializer);
+        sse_encode_String(zipPath, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_duplicate_policy(duplicates, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 61, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_restore_summary,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileRestoreBackupConstMeta,
+      argValues: [rootDir, zipPath, password, duplicates, progress, cancel],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileRestoreBackupConstMeta => const TaskConstMeta(
+        debugName: "restore_backup",
+        argNames: [
+          "rootDir",
+          "zipPath",
+          "password",
+          "duplicates",
+          "progress",
+          "cancel"
+        ],
+      );
+
+  @override
+  Future<RestoreSummary> crateApiFileRestoreBackupSelection(
+      {required String rootDir,
+      required String zipPath,
+      String? password,
+      required DuplicatePolicy duplicates,
+      required BackupSelection selection,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootDir, serializer);
+        sse_encode_String(zipPath, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_duplicate_policy(duplicates, serializer);
+        sse_encode_box_autoadd_backup_selection(selection, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 62, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_restore_summary,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileRestoreBackupSelectionConstMeta,
+      argValues: [
+        rootDir,
+        zipPath,
+        password,
+        duplicates,
+        selection,
+        progress,
+        cancel
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileRestoreBackupSelectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_backup_selection",
+        argNames: [
+          "rootDir",
+          "zipPath",
+          "password",
+          "duplicates",
+          "selection",
+          "progress",
+          "cancel"
+        ],
+      );
+
+  @override
+  Future<void> crateApiFileRevokeKeySlot(
+      {required String password, required String dir, required int slotId}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(password, serializer);
+        sse_encode_String(dir, serializer);
+        sse_encode_u_32(slotId, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 63, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileRevokeKeySlotConstMeta,
+      argValues: [password, dir, slotId],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileRevokeKeySlotConstMeta => const TaskConstMeta(
+        debugName: "revoke_key_slot",
+        argNames: ["password", "dir", "slotId"],
+      );
+
+  @override
+  Future<void> crateApiFileSaveFile(
+      {required List<int> imageData,
+      required String dir,
+      MediaSource? source}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(imageData, serializer);
+        sse_encode_String(dir, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 64, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileSaveFileConstMeta,
+      argValues: [imageData, dir, source],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileSaveFileConstMeta => const TaskConstMeta(
+        debugName: "save_file",
+        argNames: ["imageData", "dir", "source"],
+      );
+
+  @override
+  Future<void> crateApiFileSaveMedia(
+      {required List<int> imageData,
+      required String dir,
+      MediaSource? source}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(imageData, serializer);
+        sse_encode_String(dir, serializer);
+        sse_encode_opt_box_autoadd_media_source(source, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 65, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileSaveMediaConstMeta,
+      argValues: [imageData, dir, source],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileSaveMediaConstMeta => const TaskConstMeta(
+        debugName: "save_media",
+        argNames: ["imageData", "dir", "source"],
+      );
+
+  @override
+  Future<void> crateApiFileSavePassword(
+      {required String password, required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(password, serializer);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 66, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileSavePasswordConstMeta,
+      argValues: [password, dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileSavePasswordConstMeta => const TaskConstMeta(
+        debugName: "save_password",
+        argNames: ["password", "dir"],
+      );
+
+  @override
+  Future<void> crateApiFileSavePasswordWithKdf(
+      {required String password, required String dir, required KdfConfig kdf}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(password, serializer);
+        sse_encode_String(dir, serializer);
+        sse_encode_box_autoadd_kdf_config(kdf, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 67, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileSavePasswordWithKdfConstMeta,
+      argValues: [password, dir, kdf],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileSavePasswordWithKdfConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_password_with_kdf",
+        argNames: ["password", "dir", "kdf"],
+      );
+
+  @override
+  Future<void> crateApiFileSetAutoLockTimeout({required BigInt seconds}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(seconds, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 68, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileSetAutoLockTimeoutConstMeta,
+      argValues: [seconds],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileSetAutoLockTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_auto_lock_timeout",
+        argNames: ["seconds"],
+      );
+
+  @override
+  Future<bool> crateApiFileSetPassword(
+      {required String password, required String dir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(password, serializer);
+        sse_encode_String(dir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 69, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileSetPasswordConstMeta,
+      argValues: [password, dir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileSetPasswordConstMeta => const TaskConstMeta(
+        debugName: "set_password",
+        argNames: ["password", "dir"],
+      );
+
+  @override
+  Future<void> crateApiFileStopMediaServer() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 70, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileStopMediaServerConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileStopMediaServerConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_media_server",
+        argNames: [],
+      );
+
+  @override
+  Stream<VaultEvent> crateApiFileVaultEvents() {
+    final sink = RustStreamSink<VaultEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_vault_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 71, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileVaultEventsConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiFileVaultEventsConstMeta => const TaskConstMeta(
+        debugName: "vault_events",
+        argNames: ["sink"],
+      );
+
+  @override
+  Future<BackupReport> crateApiFileVerifyBackup(
+      {required String zipPath, String? password}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(zipPath, serializer);
+        sse_encode_opt_String(password, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 72, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_backup_report,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileVerifyBackupConstMeta,
+      argValues: [zipPath, password],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileVerifyBackupConstMeta => const TaskConstMeta(
+        debugName: "verify_backup",
+        argNames: ["zipPath", "password"],
+      );
+
+  @override
+  Future<void> crateApiFileZipBackup(
+      {required String rootDir,
+      required String savePath,
+      required bool encryption,
+      RustStreamSink<ProgressEvent>? progress,
+      required CancelToken cancel}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(rootDir, serializer);
+        sse_encode_String(savePath, serializer);
+        sse_encode_bool(encryption, serializer);
+        sse_encode_opt_StreamSink_progress_event_Sse(progress, serializer);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+            cancel, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 73, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_vault_error,
+      ),
+      constMeta: kCrateApiFileZipBackupConstMeta,
+      argValues: [rootDir, savePath, encryption, progress, cancel],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiFileZipBackupConstMeta => const TaskConstMeta(
+        debugName: "zip_backup",
+        argNames: ["rootDir", "savePath", "encryption", "progress", "cancel"],
+      );
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_CancelToken => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_CancelToken => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_MediaWriter => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_MediaWriter => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Vault =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Vault =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  CancelToken
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MediaWriter
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MediaWriterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Vault
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VaultImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CancelToken
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MediaWriter
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MediaWriterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Vault
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VaultImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Map<String, (String, double)> dco_decode_Map_String_record_string_f_32_None(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(dco_decode_list_record_string_record_string_f_32(raw)
+        .map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  CancelToken
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CancelTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  MediaWriter
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MediaWriterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Vault
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VaultImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ProgressEvent> dco_decode_StreamSink_progress_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<VaultEvent> dco_decode_StreamSink_vault_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  BackupAlbum dco_decode_backup_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BackupAlbum(
+      path: dco_decode_String(arr[0]),
+      items: dco_decode_u_32(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  BackupContents dco_decode_backup_contents(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BackupContents(
+      albums: dco_decode_list_backup_album(arr[0]),
+      items: dco_decode_list_backup_item(arr[1]),
+    );
+  }
+
+  @protected
+  BackupEncryption dco_decode_backup_encryption(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BackupEncryption.values[raw as int];
+  }
+
+  @protected
+  BackupItem dco_decode_backup_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BackupItem(
+      name: dco_decode_String(arr[0]),
+      album: dco_decode_String(arr[1]),
+      originalName: dco_decode_opt_String(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  BackupManifest dco_decode_backup_manifest(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return BackupManifest(
+      version: dco_decode_u_32(arr[0]),
+      createdAt: dco_decode_i_64(arr[1]),
+      appVersion: dco_decode_opt_String(arr[2]),
+      encryption: dco_decode_backup_encryption(arr[3]),
+      kdf: dco_decode_list_kdf_params(arr[4]),
+      albums: dco_decode_list_String(arr[5]),
+      entries: dco_decode_list_manifest_entry(arr[6]),
+    );
+  }
+
+  @protected
+  BackupReport dco_decode_backup_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BackupReport(
+      good: dco_decode_list_String(arr[0]),
+      missing: dco_decode_list_String(arr[1]),
+      corrupt: dco_decode_list_rejected_entry(arr[2]),
+    );
+  }
+
+  @protected
+  BackupSelection dco_decode_backup_selection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BackupSelection(
+      albums: dco_decode_list_String(arr[0]),
+      entries: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BackupSelection dco_decode_box_autoadd_backup_selection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_backup_selection(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  KdfConfig dco_decode_box_autoadd_kdf_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_kdf_config(raw);
+  }
+
+  @protected
+  MediaSource dco_decode_box_autoadd_media_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_media_source(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  DuplicatePolicy dco_decode_duplicate_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DuplicatePolicy.values[raw as int];
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  IoErrorKind dco_decode_io_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IoErrorKind.values[raw as int];
+  }
+
+  @protected
+  KdfConfig dco_decode_kdf_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return KdfConfig_Pbkdf2Sha256(
+          iterations: dco_decode_u_32(raw[1]),
+        );
+      case 1:
+        return KdfConfig_Argon2id(
+          memoryKib: dco_decode_u_32(raw[1]),
+          iterations: dco_decode_u_32(raw[2]),
+          parallelism: dco_decode_u_32(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  KdfParams dco_decode_kdf_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return KdfParams_Pbkdf2Sha256(
+          salt: dco_decode_list_prim_u_8_strict(raw[1]),
+          iterations: dco_decode_u_32(raw[2]),
+        );
+      case 1:
+        return KdfParams_Argon2id(
+          salt: dco_decode_list_prim_u_8_strict(raw[1]),
+          memoryKib: dco_decode_u_32(raw[2]),
+          iterations: dco_decode_u_32(raw[3]),
+          parallelism: dco_decode_u_32(raw[4]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  KeySlotInfo dco_decode_key_slot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return KeySlotInfo(
+      id: dco_decode_u_32(arr[0]),
+      kind: dco_decode_key_slot_kind(arr[1]),
+      label: dco_decode_String(arr[2]),
+      createdAt: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  KeySlotKind dco_decode_key_slot_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KeySlotKind.values[raw as int];
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BackupAlbum> dco_decode_list_backup_album(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_backup_album).toList();
+  }
+
+  @protected
+  List<BackupItem> dco_decode_list_backup_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_backup_item).toList();
+  }
+
+  @protected
+  List<KdfParams> dco_decode_list_kdf_params(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_kdf_params).toList();
+  }
+
+  @protected
+  List<KeySlotInfo> dco_decode_list_key_slot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_key_slot_info).toList();
+  }
+
+  @protected
+  List<ManifestEntry> dco_decode_list_manifest_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_manifest_entry).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, (String, double))>
+      dco_decode_list_record_string_record_string_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_record_string_f_32)
+        .toList();
+  }
+
+  @protected
+  List<RejectedEntry> dco_decode_list_rejected_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rejected_entry).toList();
+  }
+
+  @protected
+  List<RestoreConflict> dco_decode_list_restore_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_restore_conflict).toList();
+  }
+
+  @protected
+  LockReason dco_decode_lock_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LockReason.values[raw as int];
+  }
+
+  @protected
+  ManifestEntry dco_decode_manifest_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ManifestEntry(
+      name: dco_decode_String(arr[0]),
+      originalName: dco_decode_opt_String(arr[1]),
+      mimeType: dco_decode_opt_String(arr[2]),
+      modifiedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      size: dco_decode_u_64(arr[4]),
+      sha256: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  MediaInfo dco_decode_media_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MediaInfo(
+      name: dco_decode_String(arr[0]),
+      originalName: dco_decode_opt_String(arr[1]),
+      mimeType: dco_decode_opt_String(arr[2]),
+      size: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      aspectRatio: dco_decode_opt_box_autoadd_f_32(arr[4]),
+      createdAt: dco_decode_i_64(arr[5]),
+      modifiedAt: dco_decode_opt_box_autoadd_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  MediaSource dco_decode_media_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MediaSource(
+      originalName: dco_decode_opt_String(arr[0]),
+      mimeType: dco_decode_opt_String(arr[1]),
+      modifiedAt: dco_decode_opt_box_autoadd_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  Map<String, (String, double)>?
+      dco_decode_opt_Map_String_record_string_f_32_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_Map_String_record_string_f_32_None(raw);
+  }
+
+  @protected
+  RustStreamSink<ProgressEvent>? dco_decode_opt_StreamSink_progress_event_Sse(
       dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(dco_decode_list_record_string_record_string_f_32(raw)
-        .map((e) => MapEntry(e.$1, e.$2)));
+    return raw == null ? null : dco_decode_StreamSink_progress_event_Sse(raw);
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  MediaSource? dco_decode_opt_box_autoadd_media_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_media_source(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  ProgressEvent dco_decode_progress_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProgressEvent(
+      filesDone: dco_decode_u_32(arr[0]),
+      filesTotal: dco_decode_u_32(arr[1]),
+      bytesDone: dco_decode_u_64(arr[2]),
+      bytesTotal: dco_decode_u_64(arr[3]),
+      currentItem: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  (String, double) dco_decode_record_string_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  (String, (String, double)) dco_decode_record_string_record_string_f_32(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_record_string_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  RejectedEntry dco_decode_rejected_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RejectedEntry(
+      name: dco_decode_String(arr[0]),
+      reason: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  RestoreConflict dco_decode_restore_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RestoreConflict(
+      name: dco_decode_String(arr[0]),
+      existing: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  RestoreSummary dco_decode_restore_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RestoreSummary(
+      restored: dco_decode_u_32(arr[0]),
+      skipped: dco_decode_u_32(arr[1]),
+      rejected: dco_decode_list_rejected_entry(arr[2]),
+      conflicts: dco_decode_list_restore_conflict(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  VaultError dco_decode_vault_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return VaultError_Internal(
+          message: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return const VaultError_IncorrectPassword();
+      case 2:
+        return const VaultError_Locked();
+      case 3:
+        return VaultError_Corrupted(
+          path: dco_decode_opt_String(raw[1]),
+        );
+      case 4:
+        return VaultError_UnsupportedFormat(
+          path: dco_decode_opt_String(raw[1]),
+          details: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return VaultError_NotFound(
+          path: dco_decode_String(raw[1]),
+        );
+      case 6:
+        return VaultError_AlreadyExists(
+          path: dco_decode_String(raw[1]),
+        );
+      case 7:
+        return VaultError_PermissionDenied(
+          path: dco_decode_String(raw[1]),
+        );
+      case 8:
+        return VaultError_InsufficientSpace(
+          path: dco_decode_String(raw[1]),
+        );
+      case 9:
+        return VaultError_Io(
+          kind: dco_decode_io_error_kind(raw[1]),
+          path: dco_decode_opt_String(raw[2]),
+          message: dco_decode_String(raw[3]),
+        );
+      case 10:
+        return VaultError_PathOutsideVault(
+          path: dco_decode_String(raw[1]),
+        );
+      case 11:
+        return VaultError_InvalidInput(
+          message: dco_decode_String(raw[1]),
+        );
+      case 12:
+        return VaultError_InvalidBackup(
+          path: dco_decode_String(raw[1]),
+          reason: dco_decode_String(raw[2]),
+        );
+      case 13:
+        return VaultError_BackupVersionMismatch(
+          found: dco_decode_u_32(raw[1]),
+          supported: dco_decode_u_32(raw[2]),
+        );
+      case 14:
+        return const VaultError_Cancelled();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  VaultEvent dco_decode_vault_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return VaultEvent_Locked(
+          reason: dco_decode_lock_reason(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  CancelToken
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CancelTokenImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  MediaWriter
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MediaWriterImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Vault
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return VaultImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  CancelToken
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CancelTokenImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  MediaWriter
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MediaWriterImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Vault
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return VaultImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Map<String, (String, double)> sse_decode_Map_String_record_string_f_32_None(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_record_string_f_32(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  CancelToken
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CancelTokenImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  MediaWriter
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return MediaWriterImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Vault
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return VaultImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ProgressEvent> sse_decode_StreamSink_progress_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<VaultEvent> sse_decode_StreamSink_vault_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  BackupAlbum sse_decode_backup_album(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_items = sse_decode_u_32(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    return BackupAlbum(path: var_path, items: var_items, size: var_size);
+  }
+
+  @protected
+  BackupContents sse_decode_backup_contents(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_albums = sse_decode_list_backup_album(deserializer);
+    var var_items = sse_decode_list_backup_item(deserializer);
+    return BackupContents(albums: var_albums, items: var_items);
+  }
+
+  @protected
+  BackupEncryption sse_decode_backup_encryption(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BackupEncryption.values[inner];
+  }
+
+  @protected
+  BackupItem sse_decode_backup_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_album = sse_decode_String(deserializer);
+    var var_originalName = sse_decode_opt_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    return BackupItem(
+        name: var_name,
+        album: var_album,
+        originalName: var_originalName,
+        size: var_size);
+  }
+
+  @protected
+  BackupManifest sse_decode_backup_manifest(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_version = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_appVersion = sse_decode_opt_String(deserializer);
+    var var_encryption = sse_decode_backup_encryption(deserializer);
+    var var_kdf = sse_decode_list_kdf_params(deserializer);
+    var var_albums = sse_decode_list_String(deserializer);
+    var var_entries = sse_decode_list_manifest_entry(deserializer);
+    return BackupManifest(
+        version: var_version,
+        createdAt: var_createdAt,
+        appVersion: var_appVersion,
+        encryption: var_encryption,
+        kdf: var_kdf,
+        albums: var_albums,
+        entries: var_entries);
+  }
+
+  @protected
+  BackupReport sse_decode_backup_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_good = sse_decode_list_String(deserializer);
+    var var_missing = sse_decode_list_String(deserializer);
+    var var_corrupt = sse_decode_list_rejected_entry(deserializer);
+    return BackupReport(
+        good: var_good, missing: var_missing, corrupt: var_corrupt);
+  }
+
+  @protected
+  BackupSelection sse_decode_backup_selection(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_albums = sse_decode_list_String(deserializer);
+    var var_entries = sse_decode_list_String(deserializer);
+    return BackupSelection(albums: var_albums, entries: var_entries);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  BackupSelection sse_decode_box_autoadd_backup_selection(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_backup_selection(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  KdfConfig sse_decode_box_autoadd_kdf_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_kdf_config(deserializer));
+  }
+
+  @protected
+  MediaSource sse_decode_box_autoadd_media_source(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_source(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  DuplicatePolicy sse_decode_duplicate_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DuplicatePolicy.values[inner];
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  IoErrorKind sse_decode_io_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IoErrorKind.values[inner];
+  }
+
+  @protected
+  KdfConfig sse_decode_kdf_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_iterations = sse_decode_u_32(deserializer);
+        return KdfConfig_Pbkdf2Sha256(iterations: var_iterations);
+      case 1:
+        var var_memoryKib = sse_decode_u_32(deserializer);
+        var var_iterations = sse_decode_u_32(deserializer);
+        var var_parallelism = sse_decode_u_32(deserializer);
+        return KdfConfig_Argon2id(
+            memoryKib: var_memoryKib,
+            iterations: var_iterations,
+            parallelism: var_parallelism);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  KdfParams sse_decode_kdf_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_salt = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_iterations = sse_decode_u_32(deserializer);
+        return KdfParams_Pbkdf2Sha256(
+            salt: var_salt, iterations: var_iterations);
+      case 1:
+        var var_salt = sse_decode_list_prim_u_8_strict(deserializer);
+        var var_memoryKib = sse_decode_u_32(deserializer);
+        var var_iterations = sse_decode_u_32(deserializer);
+        var var_parallelism = sse_decode_u_32(deserializer);
+        return KdfParams_Argon2id(
+            salt: var_salt,
+            memoryKib: var_memoryKib,
+            iterations: var_iterations,
+            parallelism: var_parallelism);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  KeySlotInfo sse_decode_key_slot_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_32(deserializer);
+    var var_kind = sse_decode_key_slot_kind(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return KeySlotInfo(
+        id: var_id, kind: var_kind, label: var_label, createdAt: var_createdAt);
+  }
+
+  @protected
+  KeySlotKind sse_decode_key_slot_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KeySlotKind.values[inner];
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BackupAlbum> sse_decode_list_backup_album(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BackupAlbum>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_backup_album(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BackupItem> sse_decode_list_backup_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BackupItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_backup_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KdfParams> sse_decode_list_kdf_params(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KdfParams>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_kdf_params(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<KeySlotInfo> sse_decode_list_key_slot_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <KeySlotInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_key_slot_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ManifestEntry> sse_decode_list_manifest_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ManifestEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_manifest_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, (String, double))>
+      sse_decode_list_record_string_record_string_f_32(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, (String, double))>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_record_string_f_32(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RejectedEntry> sse_decode_list_rejected_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RejectedEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rejected_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RestoreConflict> sse_decode_list_restore_conflict(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RestoreConflict>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_restore_conflict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LockReason sse_decode_lock_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LockReason.values[inner];
+  }
+
+  @protected
+  ManifestEntry sse_decode_manifest_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_originalName = sse_decode_opt_String(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_modifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_sha256 = sse_decode_String(deserializer);
+    return ManifestEntry(
+        name: var_name,
+        originalName: var_originalName,
+        mimeType: var_mimeType,
+        modifiedAt: var_modifiedAt,
+        size: var_size,
+        sha256: var_sha256);
+  }
+
+  @protected
+  MediaInfo sse_decode_media_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_originalName = sse_decode_opt_String(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_size = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_aspectRatio = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_modifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return MediaInfo(
+        name: var_name,
+        originalName: var_originalName,
+        mimeType: var_mimeType,
+        size: var_size,
+        aspectRatio: var_aspectRatio,
+        createdAt: var_createdAt,
+        modifiedAt: var_modifiedAt);
+  }
+
+  @protected
+  MediaSource sse_decode_media_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_originalName = sse_decode_opt_String(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_modifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return MediaSource(
+        originalName: var_originalName,
+        mimeType: var_mimeType,
+        modifiedAt: var_modifiedAt);
+  }
+
+  @protected
+  Map<String, (String, double)>?
+      sse_decode_opt_Map_String_record_string_f_32_None(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Map_String_record_string_f_32_None(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RustStreamSink<ProgressEvent>? sse_decode_opt_StreamSink_progress_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_StreamSink_progress_event_Sse(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  MediaSource? sse_decode_opt_box_autoadd_media_source(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_media_source(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  ProgressEvent sse_decode_progress_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filesDone = sse_decode_u_32(deserializer);
+    var var_filesTotal = sse_decode_u_32(deserializer);
+    var var_bytesDone = sse_decode_u_64(deserializer);
+    var var_bytesTotal = sse_decode_u_64(deserializer);
+    var var_currentItem = sse_decode_opt_String(deserializer);
+    return ProgressEvent(
+        filesDone: var_filesDone,
+        filesTotal: var_filesTotal,
+        bytesDone: var_bytesDone,
+        bytesTotal: var_bytesTotal,
+        currentItem: var_currentItem);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  (String, double) sse_decode_record_string_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_f_32(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  List<(String, (String, double))>
-      dco_decode_list_record_string_record_string_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_record_string_f_32)
-        .toList();
+  (String, (String, double)) sse_decode_record_string_record_string_f_32(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_record_string_f_32(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  Map<String, (String, double)>?
-      dco_decode_opt_Map_String_record_string_f_32_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_Map_String_record_string_f_32_None(raw);
+  RejectedEntry sse_decode_rejected_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return RejectedEntry(name: var_name, reason: var_reason);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  RestoreConflict sse_decode_restore_conflict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_existing = sse_decode_String(deserializer);
+    return RestoreConflict(name: var_name, existing: var_existing);
   }
 
   @protected
-  (String, double) dco_decode_record_string_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_f_32(arr[1]),
-    );
+  RestoreSummary sse_decode_restore_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_restored = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_u_32(deserializer);
+    var var_rejected = sse_decode_list_rejected_entry(deserializer);
+    var var_conflicts = sse_decode_list_restore_conflict(deserializer);
+    return RestoreSummary(
+        restored: var_restored,
+        skipped: var_skipped,
+        rejected: var_rejected,
+        conflicts: var_conflicts);
   }
 
   @protected
-  (String, (String, double)) dco_decode_record_string_record_string_f_32(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_record_string_f_32(arr[1]),
-    );
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  VaultError dco_decode_vault_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  VaultError sse_decode_vault_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
       case 0:
-        return VaultError_Error(
-          dco_decode_String(raw[1]),
-        );
+        var var_message = sse_decode_String(deserializer);
+        return VaultError_Internal(message: var_message);
       case 1:
         return const VaultError_IncorrectPassword();
+      case 2:
+        return const VaultError_Locked();
+      case 3:
+        var var_path = sse_decode_opt_String(deserializer);
+        return VaultError_Corrupted(path: var_path);
+      case 4:
+        var var_path = sse_decode_opt_String(deserializer);
+        var var_details = sse_decode_String(deserializer);
+        return VaultError_UnsupportedFormat(
+            path: var_path, details: var_details);
+      case 5:
+        var var_path = sse_decode_String(deserializer);
+        return VaultError_NotFound(path: var_path);
+      case 6:
+        var var_path = sse_decode_String(deserializer);
+        return VaultError_AlreadyExists(path: var_path);
+      case 7:
+        var var_path = sse_decode_String(deserializer);
+        return VaultError_PermissionDenied(path: var_path);
+      case 8:
+        var var_path = sse_decode_String(deserializer);
+        return VaultError_InsufficientSpace(path: var_path);
+      case 9:
+        var var_kind = sse_decode_io_error_kind(deserializer);
+        var var_path = sse_decode_opt_String(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return VaultError_Io(
+            kind: var_kind, path: var_path, message: var_message);
+      case 10:
+        var var_path = sse_decode_String(deserializer);
+        return VaultError_PathOutsideVault(path: var_path);
+      case 11:
+        var var_message = sse_decode_String(deserializer);
+        return VaultError_InvalidInput(message: var_message);
+      case 12:
+        var var_path = sse_decode_String(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return VaultError_InvalidBackup(path: var_path, reason: var_reason);
+      case 13:
+        var var_found = sse_decode_u_32(deserializer);
+        var var_supported = sse_decode_u_32(deserializer);
+        return VaultError_BackupVersionMismatch(
+            found: var_found, supported: var_supported);
+      case 14:
+        return const VaultError_Cancelled();
       default:
-        throw Exception("unreachable");
+        throw UnimplementedError('');
     }
   }
 
   @protected
-  Map<String, (String, double)> sse_decode_Map_String_record_string_f_32_None(
-      SseDeserializer deserializer) {
+  VaultEvent sse_decode_vault_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_record_string_f_32(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_reason = sse_decode_lock_reason(deserializer);
+        return VaultEvent_Locked(reason: var_reason);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          CancelToken self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_usize(
+        (self as CancelTokenImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          MediaWriter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_usize(
+        (self as MediaWriterImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          Vault self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as VaultImpl).frbInternalSseEncode(move: true), serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          CancelToken self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as CancelTokenImpl).frbInternalSseEncode(move: false),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          MediaWriter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as MediaWriterImpl).frbInternalSseEncode(move: false),
+        serializer);
+  }
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          Vault self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as VaultImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_record_string_f_32_None(
+      Map<String, (String, double)> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_record_string_f_32(
+        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          CancelToken self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as CancelTokenImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          MediaWriter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as MediaWriterImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          Vault self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as VaultImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+      RustStreamSink<Uint8List> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self
+            .setupAndSerialize(
+                codec: SseCodec(
+              decodeSuccessData: sse_decode_list_prim_u_8_strict,
+              decodeErrorData: sse_decode_AnyhowException,
+            )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_progress_event_Sse(
+      RustStreamSink<ProgressEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self
+            .setupAndSerialize(
+                codec: SseCodec(
+              decodeSuccessData: sse_decode_progress_event,
+              decodeErrorData: sse_decode_AnyhowException,
+            )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_vault_event_Sse(
+      RustStreamSink<VaultEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self
+            .setupAndSerialize(
+                codec: SseCodec(
+              decodeSuccessData: sse_decode_vault_event,
+              decodeErrorData: sse_decode_AnyhowException,
+            )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_backup_album(BackupAlbum self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_32(self.items, serializer);
+    sse_encode_u_64(self.size, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_contents(
+      BackupContents self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_backup_album(self.albums, serializer);
+    sse_encode_list_backup_item(self.items, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_encryption(
+      BackupEncryption self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_item(BackupItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.album, serializer);
+    sse_encode_opt_String(self.originalName, serializer);
+    sse_encode_u_64(self.size, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_manifest(
+      BackupManifest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.version, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_opt_String(self.appVersion, serializer);
+    sse_encode_backup_encryption(self.encryption, serializer);
+    sse_encode_list_kdf_params(self.kdf, serializer);
+    sse_encode_list_String(self.albums, serializer);
+    sse_encode_list_manifest_entry(self.entries, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_report(BackupReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.good, serializer);
+    sse_encode_list_String(self.missing, serializer);
+    sse_encode_list_rejected_entry(self.corrupt, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_selection(
+      BackupSelection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.albums, serializer);
+    sse_encode_list_String(self.entries, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_backup_selection(
+      BackupSelection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_backup_selection(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+      PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_kdf_config(
+      KdfConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_kdf_config(self, serializer);
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_media_source(
+      MediaSource self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_media_source(self, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  List<(String, (String, double))>
-      sse_decode_list_record_string_record_string_f_32(
-          SseDeserializer deserializer) {
+  void sse_encode_duplicate_policy(
+      DuplicatePolicy self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, (String, double))>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_record_string_f_32(deserializer));
-    }
-    return ans_;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  Map<String, (String, double)>?
-      sse_decode_opt_Map_String_record_string_f_32_None(
-          SseDeserializer deserializer) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_Map_String_record_string_f_32_None(deserializer));
-    } else {
-      return null;
-    }
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  (String, double) sse_decode_record_string_f_32(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_f_32(deserializer);
-    return (var_field0, var_field1);
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  (String, (String, double)) sse_decode_record_string_record_string_f_32(
-      SseDeserializer deserializer) {
+  void sse_encode_io_error_kind(IoErrorKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_record_string_f_32(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_kdf_config(KdfConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    switch (self) {
+      case KdfConfig_Pbkdf2Sha256(iterations: final iterations):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(iterations, serializer);
+      case KdfConfig_Argon2id(
+          memoryKib: final memoryKib,
+          iterations: final iterations,
+          parallelism: final parallelism):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(memoryKib, serializer);
+        sse_encode_u_32(iterations, serializer);
+        sse_encode_u_32(parallelism, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_kdf_params(KdfParams self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case KdfParams_Pbkdf2Sha256(
+          salt: final salt, iterations: final iterations):
+        sse_encode_i_32(0, serializer);
+        sse_encode_list_prim_u_8_strict(salt, serializer);
+        sse_encode_u_32(iterations, serializer);
+      case KdfParams_Argon2id(
+          salt: final salt,
+          memoryKib: final memoryKib,
+          iterations: final iterations,
+          parallelism: final parallelism):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_8_strict(salt, serializer);
+        sse_encode_u_32(memoryKib, serializer);
+        sse_encode_u_32(iterations, serializer);
+        sse_encode_u_32(parallelism, serializer);
+    }
   }
 
   @protected
-  VaultError sse_decode_vault_error(SseDeserializer deserializer) {
+  void sse_encode_key_slot_info(KeySlotInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.id, serializer);
+    sse_encode_key_slot_kind(self.kind, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return VaultError_Error(var_field0);
-      case 1:
-        return const VaultError_IncorrectPassword();
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_key_slot_kind(KeySlotKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_Map_String_record_string_f_32_None(
-      Map<String, (String, double)> self, SseSerializer serializer) {
+  void sse_encode_list_backup_album(
+      List<BackupAlbum> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_record_string_f_32(
-        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_backup_album(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_backup_item(
+      List<BackupItem> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_backup_item(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_kdf_params(
+      List<KdfParams> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_kdf_params(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_list_key_slot_info(
+      List<KeySlotInfo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_key_slot_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_list_manifest_entry(
+      List<ManifestEntry> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_String(item, serializer);
+      sse_encode_manifest_entry(item, serializer);
     }
   }
 
@@ -946,6 +4368,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_rejected_entry(
+      List<RejectedEntry> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rejected_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_restore_conflict(
+      List<RestoreConflict> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_restore_conflict(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_lock_reason(LockReason self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_manifest_entry(ManifestEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.originalName, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.modifiedAt, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_String(self.sha256, serializer);
+  }
+
+  @protected
+  void sse_encode_media_info(MediaInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.originalName, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.size, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.aspectRatio, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.modifiedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_media_source(MediaSource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.originalName, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.modifiedAt, serializer);
+  }
+
   @protected
   void sse_encode_opt_Map_String_record_string_f_32_None(
       Map<String, (String, double)>? self, SseSerializer serializer) {
@@ -957,6 +4436,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_StreamSink_progress_event_Sse(
+      RustStreamSink<ProgressEvent>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_StreamSink_progress_event_Sse(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -967,6 +4457,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+      PlatformInt64? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_media_source(
+      MediaSource? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_media_source(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_progress_event(ProgressEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.filesDone, serializer);
+    sse_encode_u_32(self.filesTotal, serializer);
+    sse_encode_u_64(self.bytesDone, serializer);
+    sse_encode_u_64(self.bytesTotal, serializer);
+    sse_encode_opt_String(self.currentItem, serializer);
+  }
+
   @protected
   void sse_encode_record_string_f_32(
       (String, double) self, SseSerializer serializer) {
@@ -983,6 +4525,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_record_string_f_32(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_rejected_entry(RejectedEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_restore_conflict(
+      RestoreConflict self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.existing, serializer);
+  }
+
+  @protected
+  void sse_encode_restore_summary(
+      RestoreSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.restored, serializer);
+    sse_encode_u_32(self.skipped, serializer);
+    sse_encode_list_rejected_entry(self.rejected, serializer);
+    sse_encode_list_restore_conflict(self.conflicts, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -994,21 +4573,296 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_vault_error(VaultError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     switch (self) {
-      case VaultError_Error(field0: final field0):
+      case VaultError_Internal(message: final message):
         sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
+        sse_encode_String(message, serializer);
       case VaultError_IncorrectPassword():
         sse_encode_i_32(1, serializer);
+      case VaultError_Locked():
+        sse_encode_i_32(2, serializer);
+      case VaultError_Corrupted(path: final path):
+        sse_encode_i_32(3, serializer);
+        sse_encode_opt_String(path, serializer);
+      case VaultError_UnsupportedFormat(
+          path: final path, details: final details):
+        sse_encode_i_32(4, serializer);
+        sse_encode_opt_String(path, serializer);
+        sse_encode_String(details, serializer);
+      case VaultError_NotFound(path: final path):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(path, serializer);
+      case VaultError_AlreadyExists(path: final path):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(path, serializer);
+      case VaultError_PermissionDenied(path: final path):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(path, serializer);
+      case VaultError_InsufficientSpace(path: final path):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(path, serializer);
+      case VaultError_Io(
+          kind: final kind, path: final path, message: final message):
+        sse_encode_i_32(9, serializer);
+        sse_encode_io_error_kind(kind, serializer);
+        sse_encode_opt_String(path, serializer);
+        sse_encode_String(message, serializer);
+      case VaultError_PathOutsideVault(path: final path):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(path, serializer);
+      case VaultError_InvalidInput(message: final message):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(message, serializer);
+      case VaultError_InvalidBackup(path: final path, reason: final reason):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(reason, serializer);
+      case VaultError_BackupVersionMismatch(
+          found: final found, supported: final supported):
+        sse_encode_i_32(13, serializer);
+        sse_encode_u_32(found, serializer);
+        sse_encode_u_32(supported, serializer);
+      case VaultError_Cancelled():
+        sse_encode_i_32(14, serializer);
     }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_vault_event(VaultEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    switch (self) {
+      case VaultEvent_Locked(reason: final reason):
+        sse_encode_i_32(0, serializer);
+        sse_encode_lock_reason(reason, serializer);
+    }
   }
 }
+
+@sealed
+class CancelTokenImpl extends RustOpaque implements CancelToken {
+  // Not to be used by end users
+  CancelTokenImpl.frbInternalDcoDecode(List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  CancelTokenImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_CancelToken,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CancelToken,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_CancelTokenPtr,
+  );
+}
+
+@sealed
+class MediaWriterImpl extends RustOpaque implements MediaWriter {
+  // Not to be used by end users
+  MediaWriterImpl.frbInternalDcoDecode(List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  MediaWriterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_MediaWriter,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_MediaWriter,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_MediaWriterPtr,
+  );
+
+  Future<void> abort() => RustLib.instance.api.crateApiMediaMediaWriterAbort(
+        that: this,
+      );
+
+  Future<void> finish() => RustLib.instance.api.crateApiMediaMediaWriterFinish(
+        that: this,
+      );
+
+  Future<void> write({required List<int> data}) => RustLib.instance.api
+      .crateApiMediaMediaWriterWrite(that: this, data: data);
+}
+
+@sealed
+class VaultImpl extends RustOpaque implements Vault {
+  // Not to be used by end users
+  VaultImpl.frbInternalDcoDecode(List<dynamic> wire)
+      : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  VaultImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_Vault,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Vault,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_VaultPtr,
+  );
+
+  Future<List<String>> albums() => RustLib.instance.api
+      .crateApiVaultVaultAlbums(
+        that: this,
+      );
+
+  Future<void> createAlbum({required String name}) => RustLib.instance.api
+      .crateApiVaultVaultCreateAlbum(that: this, name: name);
+
+  Future<void> deleteAlbum({required String album}) => RustLib.instance.api
+      .crateApiVaultVaultDeleteAlbum(that: this, album: album);
+
+  Future<void> deleteFile({required String path}) =>
+      RustLib.instance.api.crateApiVaultVaultDeleteFile(that: this, path: path);
+
+  Future<Map<String, (String, double)>?> getAlbumThumb(
+          {required String album}) =>
+      RustLib.instance.api
+          .crateApiVaultVaultGetAlbumThumb(that: this, album: album);
+
+  Future<Uint8List> getFile({required String path}) =>
+      RustLib.instance.api.crateApiVaultVaultGetFile(that: this, path: path);
+
+  Future<Uint8List> getFileThumb({required String path}) => RustLib.instance.api
+      .crateApiVaultVaultGetFileThumb(that: this, path: path);
+
+  Future<Map<String, (String, double)>> getImages({required String album}) =>
+      RustLib.instance.api
+          .crateApiVaultVaultGetImages(that: this, album: album);
+
+  Future<MediaInfo> getMediaInfo({required String path}) => RustLib.instance.api
+      .crateApiVaultVaultGetMediaInfo(that: this, path: path);
+
+  Future<void> importPath(
+          {required String srcPath,
+          required String album,
+          MediaSource? source,
+          required bool secureDelete}) =>
+      RustLib.instance.api.crateApiVaultVaultImportPath(
+          that: this,
+          srcPath: srcPath,
+          album: album,
+          source: source,
+          secureDelete: secureDelete);
+
+  Future<void> importPaths(
+          {required List<String> srcPaths,
+          required String album,
+          required bool secureDelete,
+          RustStreamSink<ProgressEvent>? progress,
+          required CancelToken cancel}) =>
+      RustLib.instance.api.crateApiVaultVaultImportPaths(
+          that: this,
+          srcPaths: srcPaths,
+          album: album,
+          secureDelete: secureDelete,
+          progress: progress,
+          cancel: cancel);
+
+  Future<bool> isUnlocked() => RustLib.instance.api
+      .crateApiVaultVaultIsUnlocked(
+        that: this,
+      );
+
+  Future<void> lock() => RustLib.instance.api.crateApiVaultVaultLock(
+        that: this,
+      );
+
+  Future<String> mediaUrl({required String path}) =>
+      RustLib.instance.api.crateApiVaultVaultMediaUrl(that: this, path: path);
+
+  Future<void> moveFile({required String path, required String destAlbum}) =>
+      RustLib.instance.api.crateApiVaultVaultMoveFile(
+          that: this, path: path, destAlbum: destAlbum);
+
+  Future<MediaWriter> openWriter(
+          {required String album, MediaSource? source}) =>
+      RustLib.instance.api.crateApiVaultVaultOpenWriter(
+          that: this, album: album, source: source);
+
+  Future<Uint8List> readRange(
+          {required String path,
+          required BigInt offset,
+          required BigInt len}) =>
+      RustLib.instance.api.crateApiVaultVaultReadRange(
+          that: this, path: path, offset: offset, len: len);
+
+  Stream<Uint8List> readStream({required String path}) =>
+      RustLib.instance.api.crateApiVaultVaultReadStream(that: this, path: path);
+
+  Future<RestoreSummary> restoreBackup(
+          {required String zipPath,
+          String? password,
+          required DuplicatePolicy duplicates,
+          RustStreamSink<ProgressEvent>? progress,
+          required CancelToken cancel}) =>
+      RustLib.instance.api.crateApiVaultVaultRestoreBackup(
+          that: this,
+          zipPath: zipPath,
+          password: password,
+          duplicates: duplicates,
+          progress: progress,
+          cancel: cancel);
+
+  Future<RestoreSummary> restoreBackupSelection(
+          {required String zipPath,
+          String? password,
+          required DuplicatePolicy duplicates,
+          required BackupSelection selection,
+          RustStreamSink<ProgressEvent>? progress,
+          required CancelToken cancel}) =>
+      RustLib.instance.api.crateApiVaultVaultRestoreBackupSelection(
+          that: this,
+          zipPath: zipPath,
+          password: password,
+          duplicates: duplicates,
+          selection: selection,
+          progress: progress,
+          cancel: cancel);
+
+  Future<String> root() => RustLib.instance.api.crateApiVaultVaultRoot(
+        that: this,
+      );
+
+  Future<void> saveFile(
+          {required List<int> data,
+          required String album,
+          MediaSource? source}) =>
+      RustLib.instance.api.crateApiVaultVaultSaveFile(
+          that: this, data: data, album: album, source: source);
+
+  Future<void> saveMedia(
+          {required List<int> data,
+          required String album,
+          MediaSource? source}) =>
+      RustLib.instance.api.crateApiVaultVaultSaveMedia(
+          that: this, data: data, album: album, source: source);
+
+  Future<void> zipBackup(
+          {required String savePath,
+          required bool encryption,
+          RustStreamSink<ProgressEvent>? progress,
+          required CancelToken cancel}) =>
+      RustLib.instance.api.crateApiVaultVaultZipBackup(
+          that: this,
+          savePath: savePath,
+          encryption: encryption,
+          progress: progress,
+          cancel: cancel);
+}
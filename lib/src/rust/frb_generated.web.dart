@@ -7,11 +7,20 @@
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/file.dart';
+import 'api/media.dart';
+import 'api/vault.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'utils/backup.dart';
 import 'utils/error.dart';
+import 'utils/kdf.dart';
+import 'utils/keyslot.dart';
+import 'utils/manifest.dart';
+import 'utils/progress.dart';
+import 'utils/session.dart';
+import 'utils/storage.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -21,22 +30,170 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_CancelTokenPtr => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken;
+
+  CrossPlatformFinalizerArg
+      get rust_arc_decrement_strong_count_MediaWriterPtr => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_VaultPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  CancelToken
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          dynamic raw);
+
+  @protected
+  MediaWriter
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          dynamic raw);
+
+  @protected
+  Vault
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          dynamic raw);
+
+  @protected
+  CancelToken
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          dynamic raw);
+
+  @protected
+  MediaWriter
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          dynamic raw);
+
+  @protected
+  Vault
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          dynamic raw);
+
   @protected
   Map<String, (String, double)> dco_decode_Map_String_record_string_f_32_None(
       dynamic raw);
 
+  @protected
+  CancelToken
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          dynamic raw);
+
+  @protected
+  MediaWriter
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          dynamic raw);
+
+  @protected
+  Vault
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          dynamic raw);
+
+  @protected
+  RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<ProgressEvent> dco_decode_StreamSink_progress_event_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<VaultEvent> dco_decode_StreamSink_vault_event_Sse(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  BackupAlbum dco_decode_backup_album(dynamic raw);
+
+  @protected
+  BackupContents dco_decode_backup_contents(dynamic raw);
+
+  @protected
+  BackupEncryption dco_decode_backup_encryption(dynamic raw);
+
+  @protected
+  BackupItem dco_decode_backup_item(dynamic raw);
+
+  @protected
+  BackupManifest dco_decode_backup_manifest(dynamic raw);
+
+  @protected
+  BackupReport dco_decode_backup_report(dynamic raw);
+
+  @protected
+  BackupSelection dco_decode_backup_selection(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  BackupSelection dco_decode_box_autoadd_backup_selection(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  KdfConfig dco_decode_box_autoadd_kdf_config(dynamic raw);
+
+  @protected
+  MediaSource dco_decode_box_autoadd_media_source(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  DuplicatePolicy dco_decode_duplicate_policy(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  IoErrorKind dco_decode_io_error_kind(dynamic raw);
+
+  @protected
+  KdfConfig dco_decode_kdf_config(dynamic raw);
+
+  @protected
+  KdfParams dco_decode_kdf_params(dynamic raw);
+
+  @protected
+  KeySlotInfo dco_decode_key_slot_info(dynamic raw);
+
+  @protected
+  KeySlotKind dco_decode_key_slot_kind(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<BackupAlbum> dco_decode_list_backup_album(dynamic raw);
+
+  @protected
+  List<BackupItem> dco_decode_list_backup_item(dynamic raw);
+
+  @protected
+  List<KdfParams> dco_decode_list_kdf_params(dynamic raw);
+
+  @protected
+  List<KeySlotInfo> dco_decode_list_key_slot_info(dynamic raw);
+
+  @protected
+  List<ManifestEntry> dco_decode_list_manifest_entry(dynamic raw);
+
   @protected
   List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
@@ -47,13 +204,50 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<(String, (String, double))>
       dco_decode_list_record_string_record_string_f_32(dynamic raw);
 
+  @protected
+  List<RejectedEntry> dco_decode_list_rejected_entry(dynamic raw);
+
+  @protected
+  List<RestoreConflict> dco_decode_list_restore_conflict(dynamic raw);
+
+  @protected
+  LockReason dco_decode_lock_reason(dynamic raw);
+
+  @protected
+  ManifestEntry dco_decode_manifest_entry(dynamic raw);
+
+  @protected
+  MediaInfo dco_decode_media_info(dynamic raw);
+
+  @protected
+  MediaSource dco_decode_media_source(dynamic raw);
+
   @protected
   Map<String, (String, double)>?
       dco_decode_opt_Map_String_record_string_f_32_None(dynamic raw);
 
+  @protected
+  RustStreamSink<ProgressEvent>? dco_decode_opt_StreamSink_progress_event_Sse(
+      dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  MediaSource? dco_decode_opt_box_autoadd_media_source(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  ProgressEvent dco_decode_progress_event(dynamic raw);
+
   @protected
   (String, double) dco_decode_record_string_f_32(dynamic raw);
 
@@ -61,31 +255,192 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   (String, (String, double)) dco_decode_record_string_record_string_f_32(
       dynamic raw);
 
+  @protected
+  RejectedEntry dco_decode_rejected_entry(dynamic raw);
+
+  @protected
+  RestoreConflict dco_decode_restore_conflict(dynamic raw);
+
+  @protected
+  RestoreSummary dco_decode_restore_summary(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
   @protected
   VaultError dco_decode_vault_error(dynamic raw);
 
+  @protected
+  VaultEvent dco_decode_vault_event(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  CancelToken
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          SseDeserializer deserializer);
+
+  @protected
+  MediaWriter
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          SseDeserializer deserializer);
+
+  @protected
+  Vault
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          SseDeserializer deserializer);
+
+  @protected
+  CancelToken
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          SseDeserializer deserializer);
+
+  @protected
+  MediaWriter
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          SseDeserializer deserializer);
+
+  @protected
+  Vault
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          SseDeserializer deserializer);
+
   @protected
   Map<String, (String, double)> sse_decode_Map_String_record_string_f_32_None(
       SseDeserializer deserializer);
 
+  @protected
+  CancelToken
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          SseDeserializer deserializer);
+
+  @protected
+  MediaWriter
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          SseDeserializer deserializer);
+
+  @protected
+  Vault
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ProgressEvent> sse_decode_StreamSink_progress_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<VaultEvent> sse_decode_StreamSink_vault_event_Sse(
+      SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  BackupAlbum sse_decode_backup_album(SseDeserializer deserializer);
+
+  @protected
+  BackupContents sse_decode_backup_contents(SseDeserializer deserializer);
+
+  @protected
+  BackupEncryption sse_decode_backup_encryption(SseDeserializer deserializer);
+
+  @protected
+  BackupItem sse_decode_backup_item(SseDeserializer deserializer);
+
+  @protected
+  BackupManifest sse_decode_backup_manifest(SseDeserializer deserializer);
+
+  @protected
+  BackupReport sse_decode_backup_report(SseDeserializer deserializer);
+
+  @protected
+  BackupSelection sse_decode_backup_selection(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  BackupSelection sse_decode_box_autoadd_backup_selection(
+      SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  KdfConfig sse_decode_box_autoadd_kdf_config(SseDeserializer deserializer);
+
+  @protected
+  MediaSource sse_decode_box_autoadd_media_source(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  DuplicatePolicy sse_decode_duplicate_policy(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_32(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  IoErrorKind sse_decode_io_error_kind(SseDeserializer deserializer);
+
+  @protected
+  KdfConfig sse_decode_kdf_config(SseDeserializer deserializer);
+
+  @protected
+  KdfParams sse_decode_kdf_params(SseDeserializer deserializer);
+
+  @protected
+  KeySlotInfo sse_decode_key_slot_info(SseDeserializer deserializer);
+
+  @protected
+  KeySlotKind sse_decode_key_slot_kind(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<BackupAlbum> sse_decode_list_backup_album(SseDeserializer deserializer);
+
+  @protected
+  List<BackupItem> sse_decode_list_backup_item(SseDeserializer deserializer);
+
+  @protected
+  List<KdfParams> sse_decode_list_kdf_params(SseDeserializer deserializer);
+
+  @protected
+  List<KeySlotInfo> sse_decode_list_key_slot_info(SseDeserializer deserializer);
+
+  @protected
+  List<ManifestEntry> sse_decode_list_manifest_entry(
+      SseDeserializer deserializer);
+
   @protected
   List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
@@ -97,14 +452,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       sse_decode_list_record_string_record_string_f_32(
           SseDeserializer deserializer);
 
+  @protected
+  List<RejectedEntry> sse_decode_list_rejected_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  List<RestoreConflict> sse_decode_list_restore_conflict(
+      SseDeserializer deserializer);
+
+  @protected
+  LockReason sse_decode_lock_reason(SseDeserializer deserializer);
+
+  @protected
+  ManifestEntry sse_decode_manifest_entry(SseDeserializer deserializer);
+
+  @protected
+  MediaInfo sse_decode_media_info(SseDeserializer deserializer);
+
+  @protected
+  MediaSource sse_decode_media_source(SseDeserializer deserializer);
+
   @protected
   Map<String, (String, double)>?
       sse_decode_opt_Map_String_record_string_f_32_None(
           SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<ProgressEvent>? sse_decode_opt_StreamSink_progress_event_Sse(
+      SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  MediaSource? sse_decode_opt_box_autoadd_media_source(
+      SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  ProgressEvent sse_decode_progress_event(SseDeserializer deserializer);
+
   @protected
   (String, double) sse_decode_record_string_f_32(SseDeserializer deserializer);
 
@@ -112,34 +507,205 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   (String, (String, double)) sse_decode_record_string_record_string_f_32(
       SseDeserializer deserializer);
 
+  @protected
+  RejectedEntry sse_decode_rejected_entry(SseDeserializer deserializer);
+
+  @protected
+  RestoreConflict sse_decode_restore_conflict(SseDeserializer deserializer);
+
+  @protected
+  RestoreSummary sse_decode_restore_summary(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
   @protected
   VaultError sse_decode_vault_error(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  VaultEvent sse_decode_vault_event(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          CancelToken self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          MediaWriter self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          Vault self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          CancelToken self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          MediaWriter self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          Vault self, SseSerializer serializer);
 
   @protected
   void sse_encode_Map_String_record_string_f_32_None(
       Map<String, (String, double)> self, SseSerializer serializer);
 
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          CancelToken self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          MediaWriter self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          Vault self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_list_prim_u_8_strict_Sse(
+      RustStreamSink<Uint8List> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_progress_event_Sse(
+      RustStreamSink<ProgressEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_vault_event_Sse(
+      RustStreamSink<VaultEvent> self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_backup_album(BackupAlbum self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_contents(
+      BackupContents self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_encryption(
+      BackupEncryption self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_item(BackupItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_manifest(
+      BackupManifest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_report(BackupReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_selection(
+      BackupSelection self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_backup_selection(
+      BackupSelection self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+      PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_kdf_config(
+      KdfConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_media_source(
+      MediaSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_duplicate_policy(
+      DuplicatePolicy self, SseSerializer serializer);
+
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_io_error_kind(IoErrorKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_kdf_config(KdfConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_kdf_params(KdfParams self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_key_slot_info(KeySlotInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_key_slot_kind(KeySlotKind self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_backup_album(
+      List<BackupAlbum> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_backup_item(
+      List<BackupItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_kdf_params(
+      List<KdfParams> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_key_slot_info(
+      List<KeySlotInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_manifest_entry(
+      List<ManifestEntry> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -151,13 +717,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_record_string_record_string_f_32(
       List<(String, (String, double))> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_rejected_entry(
+      List<RejectedEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_restore_conflict(
+      List<RestoreConflict> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lock_reason(LockReason self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_manifest_entry(ManifestEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_info(MediaInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_source(MediaSource self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_Map_String_record_string_f_32_None(
       Map<String, (String, double)>? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_StreamSink_progress_event_Sse(
+      RustStreamSink<ProgressEvent>? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+      PlatformInt64? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_media_source(
+      MediaSource? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_progress_event(ProgressEvent self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_f_32(
       (String, double) self, SseSerializer serializer);
@@ -166,23 +773,85 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_record_string_record_string_f_32(
       (String, (String, double)) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_rejected_entry(RejectedEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_restore_conflict(
+      RestoreConflict self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_restore_summary(
+      RestoreSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_vault_error(VaultError self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_vault_event(VaultEvent self, SseSerializer serializer);
 }
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
   RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+              int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+              ptr);
+
+  void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+              int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+              ptr);
+
+  void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+              int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+              ptr);
+
+  void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+              int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+              ptr);
+
+  void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+              int ptr) =>
+      wasmModule
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+              ptr);
+
+  void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+              int ptr) =>
+      wasmModule
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+              ptr);
 }
 
 @JS('wasm_bindgen')
@@ -190,4 +859,28 @@ external RustLibWasmModule get wasmModule;
 
 @JS()
 @anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancelToken(
+          int ptr);
+
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMediaWriter(
+          int ptr);
+
+  external void
+      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          int ptr);
+
+  external void
+      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVault(
+          int ptr);
+}
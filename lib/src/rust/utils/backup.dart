@@ -0,0 +1,205 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class BackupAlbum {
+  final String path;
+  final int items;
+  final BigInt size;
+
+  const BackupAlbum({
+    required this.path,
+    required this.items,
+    required this.size,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ items.hashCode ^ size.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupAlbum &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          items == other.items &&
+          size == other.size;
+}
+
+class BackupContents {
+  final List<BackupAlbum> albums;
+  final List<BackupItem> items;
+
+  const BackupContents({
+    required this.albums,
+    required this.items,
+  });
+
+  @override
+  int get hashCode => albums.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupContents &&
+          runtimeType == other.runtimeType &&
+          albums == other.albums &&
+          items == other.items;
+}
+
+class BackupItem {
+  final String name;
+  final String album;
+  final String? originalName;
+  final BigInt size;
+
+  const BackupItem({
+    required this.name,
+    required this.album,
+    this.originalName,
+    required this.size,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ album.hashCode ^ originalName.hashCode ^ size.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupItem &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          album == other.album &&
+          originalName == other.originalName &&
+          size == other.size;
+}
+
+class BackupReport {
+  final List<String> good;
+  final List<String> missing;
+  final List<RejectedEntry> corrupt;
+
+  const BackupReport({
+    required this.good,
+    required this.missing,
+    required this.corrupt,
+  });
+
+  @override
+  int get hashCode => good.hashCode ^ missing.hashCode ^ corrupt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupReport &&
+          runtimeType == other.runtimeType &&
+          good == other.good &&
+          missing == other.missing &&
+          corrupt == other.corrupt;
+}
+
+class BackupSelection {
+  final List<String> albums;
+  final List<String> entries;
+
+  const BackupSelection({
+    required this.albums,
+    required this.entries,
+  });
+
+  @override
+  int get hashCode => albums.hashCode ^ entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupSelection &&
+          runtimeType == other.runtimeType &&
+          albums == other.albums &&
+          entries == other.entries;
+}
+
+enum DuplicatePolicy {
+  skip,
+  keepBoth,
+  overwrite,
+  ;
+}
+
+class RejectedEntry {
+  final String name;
+  final String reason;
+
+  const RejectedEntry({
+    required this.name,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RejectedEntry &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          reason == other.reason;
+}
+
+class RestoreConflict {
+  final String name;
+  final String existing;
+
+  const RestoreConflict({
+    required this.name,
+    required this.existing,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ existing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestoreConflict &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          existing == other.existing;
+}
+
+class RestoreSummary {
+  final int restored;
+  final int skipped;
+  final List<RejectedEntry> rejected;
+  final List<RestoreConflict> conflicts;
+
+  const RestoreSummary({
+    required this.restored,
+    required this.skipped,
+    required this.rejected,
+    required this.conflicts,
+  });
+
+  @override
+  int get hashCode =>
+      restored.hashCode ^
+      skipped.hashCode ^
+      rejected.hashCode ^
+      conflicts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestoreSummary &&
+          runtimeType == other.runtimeType &&
+          restored == other.restored &&
+          skipped == other.skipped &&
+          rejected == other.rejected &&
+          conflicts == other.conflicts;
+}
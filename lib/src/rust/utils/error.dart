@@ -8,12 +8,63 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'error.freezed.dart';
 
+enum IoErrorKind {
+  interrupted,
+  timedOut,
+  unexpectedEof,
+  invalidData,
+  readOnly,
+  busy,
+  other,
+  ;
+}
+
 @freezed
 sealed class VaultError with _$VaultError implements FrbException {
   const VaultError._();
 
-  const factory VaultError.error(
-    String field0,
-  ) = VaultError_Error;
+  const factory VaultError.internal({
+    required String message,
+  }) = VaultError_Internal;
   const factory VaultError.incorrectPassword() = VaultError_IncorrectPassword;
+  const factory VaultError.locked() = VaultError_Locked;
+  const factory VaultError.corrupted({
+    String? path,
+  }) = VaultError_Corrupted;
+  const factory VaultError.unsupportedFormat({
+    String? path,
+    required String details,
+  }) = VaultError_UnsupportedFormat;
+  const factory VaultError.notFound({
+    required String path,
+  }) = VaultError_NotFound;
+  const factory VaultError.alreadyExists({
+    required String path,
+  }) = VaultError_AlreadyExists;
+  const factory VaultError.permissionDenied({
+    required String path,
+  }) = VaultError_PermissionDenied;
+  const factory VaultError.insufficientSpace({
+    required String path,
+  }) = VaultError_InsufficientSpace;
+  const factory VaultError.io({
+    required IoErrorKind kind,
+    String? path,
+    required String message,
+  }) = VaultError_Io;
+  const factory VaultError.pathOutsideVault({
+    required String path,
+  }) = VaultError_PathOutsideVault;
+  const factory VaultError.invalidInput({
+    required String message,
+  }) = VaultError_InvalidInput;
+  const factory VaultError.invalidBackup({
+    required String path,
+    required String reason,
+  }) = VaultError_InvalidBackup;
+  const factory VaultError.backupVersionMismatch({
+    required int found,
+    required int supported,
+  }) = VaultError_BackupVersionMismatch;
+  const factory VaultError.cancelled() = VaultError_Cancelled;
 }
@@ -18,40 +18,126 @@ final _privateConstructorUsedError = UnsupportedError(
 mixin _$VaultError {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) error,
+    required TResult Function(String message) internal,
     required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? error,
+    TResult? Function(String message)? internal,
     TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? error,
+    TResult Function(String message)? internal,
     TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(VaultError_Error value) error,
+    required TResult Function(VaultError_Internal value) internal,
     required TResult Function(VaultError_IncorrectPassword value)
         incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(VaultError_Error value)? error,
+    TResult? Function(VaultError_Internal value)? internal,
     TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(VaultError_Error value)? error,
+    TResult Function(VaultError_Internal value)? internal,
     TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -79,20 +165,20 @@ class _$VaultErrorCopyWithImpl<$Res, $Val extends VaultError>
 }
 
 /// @nodoc
-abstract class _$$VaultError_ErrorImplCopyWith<$Res> {
-  factory _$$VaultError_ErrorImplCopyWith(_$VaultError_ErrorImpl value,
-          $Res Function(_$VaultError_ErrorImpl) then) =
-      __$$VaultError_ErrorImplCopyWithImpl<$Res>;
+abstract class _$$VaultError_InternalImplCopyWith<$Res> {
+  factory _$$VaultError_InternalImplCopyWith(_$VaultError_InternalImpl value,
+          $Res Function(_$VaultError_InternalImpl) then) =
+      __$$VaultError_InternalImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({String field0});
+  $Res call({String message});
 }
 
 /// @nodoc
-class __$$VaultError_ErrorImplCopyWithImpl<$Res>
-    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_ErrorImpl>
-    implements _$$VaultError_ErrorImplCopyWith<$Res> {
-  __$$VaultError_ErrorImplCopyWithImpl(_$VaultError_ErrorImpl _value,
-      $Res Function(_$VaultError_ErrorImpl) _then)
+class __$$VaultError_InternalImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_InternalImpl>
+    implements _$$VaultError_InternalImplCopyWith<$Res> {
+  __$$VaultError_InternalImplCopyWithImpl(_$VaultError_InternalImpl _value,
+      $Res Function(_$VaultError_InternalImpl) _then)
       : super(_value, _then);
 
   /// Create a copy of VaultError
@@ -100,12 +186,12 @@ class __$$VaultError_ErrorImplCopyWithImpl<$Res>
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? field0 = null,
+    Object? message = null,
   }) {
-    return _then(_$VaultError_ErrorImpl(
-      null == field0
-          ? _value.field0
-          : field0 // ignore: cast_nullable_to_non_nullable
+    return _then(_$VaultError_InternalImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
               as String,
     ));
   }
@@ -113,64 +199,104 @@ class __$$VaultError_ErrorImplCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$VaultError_ErrorImpl extends VaultError_Error {
-  const _$VaultError_ErrorImpl(this.field0) : super._();
+class _$VaultError_InternalImpl extends VaultError_Internal {
+  const _$VaultError_InternalImpl({required this.message}) : super._();
 
   @override
-  final String field0;
+  final String message;
 
   @override
   String toString() {
-    return 'VaultError.error(field0: $field0)';
+    return 'VaultError.internal(message: $message)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$VaultError_ErrorImpl &&
-            (identical(other.field0, field0) || other.field0 == field0));
+            other is _$VaultError_InternalImpl &&
+            (identical(other.message, message) || other.message == message));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, field0);
+  int get hashCode => Object.hash(runtimeType, message);
 
   /// Create a copy of VaultError
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
   @override
   @pragma('vm:prefer-inline')
-  _$$VaultError_ErrorImplCopyWith<_$VaultError_ErrorImpl> get copyWith =>
-      __$$VaultError_ErrorImplCopyWithImpl<_$VaultError_ErrorImpl>(
+  _$$VaultError_InternalImplCopyWith<_$VaultError_InternalImpl> get copyWith =>
+      __$$VaultError_InternalImplCopyWithImpl<_$VaultError_InternalImpl>(
           this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) error,
+    required TResult Function(String message) internal,
     required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
   }) {
-    return error(field0);
+    return internal(message);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? error,
+    TResult? Function(String message)? internal,
     TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
   }) {
-    return error?.call(field0);
+    return internal?.call(message);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? error,
+    TResult Function(String message)? internal,
     TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
     required TResult orElse(),
   }) {
-    if (error != null) {
-      return error(field0);
+    if (internal != null) {
+      return internal(message);
     }
     return orElse();
   }
@@ -178,46 +304,93 @@ class _$VaultError_ErrorImpl extends VaultError_Error {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(VaultError_Error value) error,
+    required TResult Function(VaultError_Internal value) internal,
     required TResult Function(VaultError_IncorrectPassword value)
         incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
   }) {
-    return error(this);
+    return internal(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(VaultError_Error value)? error,
+    TResult? Function(VaultError_Internal value)? internal,
     TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
   }) {
-    return error?.call(this);
+    return internal?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(VaultError_Error value)? error,
+    TResult Function(VaultError_Internal value)? internal,
     TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
     required TResult orElse(),
   }) {
-    if (error != null) {
-      return error(this);
+    if (internal != null) {
+      return internal(this);
     }
     return orElse();
   }
 }
 
-abstract class VaultError_Error extends VaultError {
-  const factory VaultError_Error(final String field0) = _$VaultError_ErrorImpl;
-  const VaultError_Error._() : super._();
+abstract class VaultError_Internal extends VaultError {
+  const factory VaultError_Internal({required final String message}) =
+      _$VaultError_InternalImpl;
+  const VaultError_Internal._() : super._();
 
-  String get field0;
+  String get message;
 
   /// Create a copy of VaultError
   /// with the given fields replaced by the non-null parameter values.
   @JsonKey(includeFromJson: false, includeToJson: false)
-  _$$VaultError_ErrorImplCopyWith<_$VaultError_ErrorImpl> get copyWith =>
+  _$$VaultError_InternalImplCopyWith<_$VaultError_InternalImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
@@ -265,8 +438,22 @@ class _$VaultError_IncorrectPasswordImpl extends VaultError_IncorrectPassword {
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(String field0) error,
+    required TResult Function(String message) internal,
     required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
   }) {
     return incorrectPassword();
   }
@@ -274,8 +461,21 @@ class _$VaultError_IncorrectPasswordImpl extends VaultError_IncorrectPassword {
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(String field0)? error,
+    TResult? Function(String message)? internal,
     TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
   }) {
     return incorrectPassword?.call();
   }
@@ -283,8 +483,21 @@ class _$VaultError_IncorrectPasswordImpl extends VaultError_IncorrectPassword {
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(String field0)? error,
+    TResult Function(String message)? internal,
     TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
     required TResult orElse(),
   }) {
     if (incorrectPassword != null) {
@@ -296,9 +509,27 @@ class _$VaultError_IncorrectPasswordImpl extends VaultError_IncorrectPassword {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(VaultError_Error value) error,
+    required TResult Function(VaultError_Internal value) internal,
     required TResult Function(VaultError_IncorrectPassword value)
         incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
   }) {
     return incorrectPassword(this);
   }
@@ -306,8 +537,22 @@ class _$VaultError_IncorrectPasswordImpl extends VaultError_IncorrectPassword {
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(VaultError_Error value)? error,
+    TResult? Function(VaultError_Internal value)? internal,
     TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
   }) {
     return incorrectPassword?.call(this);
   }
@@ -315,8 +560,22 @@ class _$VaultError_IncorrectPasswordImpl extends VaultError_IncorrectPassword {
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(VaultError_Error value)? error,
+    TResult Function(VaultError_Internal value)? internal,
     TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
     required TResult orElse(),
   }) {
     if (incorrectPassword != null) {
@@ -331,3 +590,3008 @@ abstract class VaultError_IncorrectPassword extends VaultError {
       _$VaultError_IncorrectPasswordImpl;
   const VaultError_IncorrectPassword._() : super._();
 }
+
+/// @nodoc
+abstract class _$$VaultError_LockedImplCopyWith<$Res> {
+  factory _$$VaultError_LockedImplCopyWith(_$VaultError_LockedImpl value,
+          $Res Function(_$VaultError_LockedImpl) then) =
+      __$$VaultError_LockedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$VaultError_LockedImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_LockedImpl>
+    implements _$$VaultError_LockedImplCopyWith<$Res> {
+  __$$VaultError_LockedImplCopyWithImpl(_$VaultError_LockedImpl _value,
+      $Res Function(_$VaultError_LockedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$VaultError_LockedImpl extends VaultError_Locked {
+  const _$VaultError_LockedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'VaultError.locked()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$VaultError_LockedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return locked();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return locked?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (locked != null) {
+      return locked();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return locked(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return locked?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (locked != null) {
+      return locked(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_Locked extends VaultError {
+  const factory VaultError_Locked() = _$VaultError_LockedImpl;
+  const VaultError_Locked._() : super._();
+}
+
+/// @nodoc
+abstract class _$$VaultError_CorruptedImplCopyWith<$Res> {
+  factory _$$VaultError_CorruptedImplCopyWith(_$VaultError_CorruptedImpl value,
+          $Res Function(_$VaultError_CorruptedImpl) then) =
+      __$$VaultError_CorruptedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? path});
+}
+
+/// @nodoc
+class __$$VaultError_CorruptedImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_CorruptedImpl>
+    implements _$$VaultError_CorruptedImplCopyWith<$Res> {
+  __$$VaultError_CorruptedImplCopyWithImpl(_$VaultError_CorruptedImpl _value,
+      $Res Function(_$VaultError_CorruptedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = freezed,
+  }) {
+    return _then(_$VaultError_CorruptedImpl(
+      path: freezed == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_CorruptedImpl extends VaultError_Corrupted {
+  const _$VaultError_CorruptedImpl({this.path}) : super._();
+
+  @override
+  final String? path;
+
+  @override
+  String toString() {
+    return 'VaultError.corrupted(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_CorruptedImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_CorruptedImplCopyWith<_$VaultError_CorruptedImpl>
+      get copyWith =>
+      __$$VaultError_CorruptedImplCopyWithImpl<_$VaultError_CorruptedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return corrupted(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return corrupted?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (corrupted != null) {
+      return corrupted(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return corrupted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return corrupted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (corrupted != null) {
+      return corrupted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_Corrupted extends VaultError {
+  const factory VaultError_Corrupted({final String? path}) =
+      _$VaultError_CorruptedImpl;
+  const VaultError_Corrupted._() : super._();
+
+  String? get path;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_CorruptedImplCopyWith<_$VaultError_CorruptedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_UnsupportedFormatImplCopyWith<$Res> {
+  factory _$$VaultError_UnsupportedFormatImplCopyWith(
+          _$VaultError_UnsupportedFormatImpl value,
+          $Res Function(_$VaultError_UnsupportedFormatImpl) then) =
+      __$$VaultError_UnsupportedFormatImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? path, String details});
+}
+
+/// @nodoc
+class __$$VaultError_UnsupportedFormatImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_UnsupportedFormatImpl>
+    implements _$$VaultError_UnsupportedFormatImplCopyWith<$Res> {
+  __$$VaultError_UnsupportedFormatImplCopyWithImpl(
+      _$VaultError_UnsupportedFormatImpl _value,
+      $Res Function(_$VaultError_UnsupportedFormatImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = freezed,
+    Object? details = null,
+  }) {
+    return _then(_$VaultError_UnsupportedFormatImpl(
+      path: freezed == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String?,
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_UnsupportedFormatImpl extends VaultError_UnsupportedFormat {
+  const _$VaultError_UnsupportedFormatImpl({this.path, required this.details})
+      : super._();
+
+  @override
+  final String? path;
+  @override
+  final String details;
+
+  @override
+  String toString() {
+    return 'VaultError.unsupportedFormat(path: $path, details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_UnsupportedFormatImpl &&
+            (identical(other.path, path) || other.path == path) &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path, details);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_UnsupportedFormatImplCopyWith<
+          _$VaultError_UnsupportedFormatImpl>
+      get copyWith => __$$VaultError_UnsupportedFormatImplCopyWithImpl<
+          _$VaultError_UnsupportedFormatImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return unsupportedFormat(path, details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return unsupportedFormat?.call(path, details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (unsupportedFormat != null) {
+      return unsupportedFormat(path, details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return unsupportedFormat(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return unsupportedFormat?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (unsupportedFormat != null) {
+      return unsupportedFormat(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_UnsupportedFormat extends VaultError {
+  const factory VaultError_UnsupportedFormat(
+          {final String? path, required final String details}) =
+      _$VaultError_UnsupportedFormatImpl;
+  const VaultError_UnsupportedFormat._() : super._();
+
+  String? get path;
+  String get details;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_UnsupportedFormatImplCopyWith<
+          _$VaultError_UnsupportedFormatImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_NotFoundImplCopyWith<$Res> {
+  factory _$$VaultError_NotFoundImplCopyWith(_$VaultError_NotFoundImpl value,
+          $Res Function(_$VaultError_NotFoundImpl) then) =
+      __$$VaultError_NotFoundImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path});
+}
+
+/// @nodoc
+class __$$VaultError_NotFoundImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_NotFoundImpl>
+    implements _$$VaultError_NotFoundImplCopyWith<$Res> {
+  __$$VaultError_NotFoundImplCopyWithImpl(_$VaultError_NotFoundImpl _value,
+      $Res Function(_$VaultError_NotFoundImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+  }) {
+    return _then(_$VaultError_NotFoundImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_NotFoundImpl extends VaultError_NotFound {
+  const _$VaultError_NotFoundImpl({required this.path}) : super._();
+
+  @override
+  final String path;
+
+  @override
+  String toString() {
+    return 'VaultError.notFound(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_NotFoundImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_NotFoundImplCopyWith<_$VaultError_NotFoundImpl> get copyWith =>
+      __$$VaultError_NotFoundImplCopyWithImpl<_$VaultError_NotFoundImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return notFound(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return notFound?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (notFound != null) {
+      return notFound(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return notFound(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return notFound?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (notFound != null) {
+      return notFound(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_NotFound extends VaultError {
+  const factory VaultError_NotFound({required final String path}) =
+      _$VaultError_NotFoundImpl;
+  const VaultError_NotFound._() : super._();
+
+  String get path;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_NotFoundImplCopyWith<_$VaultError_NotFoundImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_AlreadyExistsImplCopyWith<$Res> {
+  factory _$$VaultError_AlreadyExistsImplCopyWith(
+          _$VaultError_AlreadyExistsImpl value,
+          $Res Function(_$VaultError_AlreadyExistsImpl) then) =
+      __$$VaultError_AlreadyExistsImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path});
+}
+
+/// @nodoc
+class __$$VaultError_AlreadyExistsImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_AlreadyExistsImpl>
+    implements _$$VaultError_AlreadyExistsImplCopyWith<$Res> {
+  __$$VaultError_AlreadyExistsImplCopyWithImpl(
+      _$VaultError_AlreadyExistsImpl _value,
+      $Res Function(_$VaultError_AlreadyExistsImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+  }) {
+    return _then(_$VaultError_AlreadyExistsImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_AlreadyExistsImpl extends VaultError_AlreadyExists {
+  const _$VaultError_AlreadyExistsImpl({required this.path}) : super._();
+
+  @override
+  final String path;
+
+  @override
+  String toString() {
+    return 'VaultError.alreadyExists(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_AlreadyExistsImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_AlreadyExistsImplCopyWith<
+          _$VaultError_AlreadyExistsImpl>
+      get copyWith => __$$VaultError_AlreadyExistsImplCopyWithImpl<
+          _$VaultError_AlreadyExistsImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return alreadyExists(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return alreadyExists?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (alreadyExists != null) {
+      return alreadyExists(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return alreadyExists(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return alreadyExists?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (alreadyExists != null) {
+      return alreadyExists(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_AlreadyExists extends VaultError {
+  const factory VaultError_AlreadyExists({required final String path}) =
+      _$VaultError_AlreadyExistsImpl;
+  const VaultError_AlreadyExists._() : super._();
+
+  String get path;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_AlreadyExistsImplCopyWith<_$VaultError_AlreadyExistsImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_PermissionDeniedImplCopyWith<$Res> {
+  factory _$$VaultError_PermissionDeniedImplCopyWith(
+          _$VaultError_PermissionDeniedImpl value,
+          $Res Function(_$VaultError_PermissionDeniedImpl) then) =
+      __$$VaultError_PermissionDeniedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path});
+}
+
+/// @nodoc
+class __$$VaultError_PermissionDeniedImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_PermissionDeniedImpl>
+    implements _$$VaultError_PermissionDeniedImplCopyWith<$Res> {
+  __$$VaultError_PermissionDeniedImplCopyWithImpl(
+      _$VaultError_PermissionDeniedImpl _value,
+      $Res Function(_$VaultError_PermissionDeniedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+  }) {
+    return _then(_$VaultError_PermissionDeniedImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_PermissionDeniedImpl extends VaultError_PermissionDenied {
+  const _$VaultError_PermissionDeniedImpl({required this.path}) : super._();
+
+  @override
+  final String path;
+
+  @override
+  String toString() {
+    return 'VaultError.permissionDenied(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_PermissionDeniedImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_PermissionDeniedImplCopyWith<
+          _$VaultError_PermissionDeniedImpl>
+      get copyWith => __$$VaultError_PermissionDeniedImplCopyWithImpl<
+          _$VaultError_PermissionDeniedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return permissionDenied(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return permissionDenied?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (permissionDenied != null) {
+      return permissionDenied(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return permissionDenied(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return permissionDenied?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (permissionDenied != null) {
+      return permissionDenied(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_PermissionDenied extends VaultError {
+  const factory VaultError_PermissionDenied({required final String path}) =
+      _$VaultError_PermissionDeniedImpl;
+  const VaultError_PermissionDenied._() : super._();
+
+  String get path;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_PermissionDeniedImplCopyWith<_$VaultError_PermissionDeniedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_InsufficientSpaceImplCopyWith<$Res> {
+  factory _$$VaultError_InsufficientSpaceImplCopyWith(
+          _$VaultError_InsufficientSpaceImpl value,
+          $Res Function(_$VaultError_InsufficientSpaceImpl) then) =
+      __$$VaultError_InsufficientSpaceImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path});
+}
+
+/// @nodoc
+class __$$VaultError_InsufficientSpaceImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_InsufficientSpaceImpl>
+    implements _$$VaultError_InsufficientSpaceImplCopyWith<$Res> {
+  __$$VaultError_InsufficientSpaceImplCopyWithImpl(
+      _$VaultError_InsufficientSpaceImpl _value,
+      $Res Function(_$VaultError_InsufficientSpaceImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+  }) {
+    return _then(_$VaultError_InsufficientSpaceImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_InsufficientSpaceImpl extends VaultError_InsufficientSpace {
+  const _$VaultError_InsufficientSpaceImpl({required this.path}) : super._();
+
+  @override
+  final String path;
+
+  @override
+  String toString() {
+    return 'VaultError.insufficientSpace(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_InsufficientSpaceImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_InsufficientSpaceImplCopyWith<
+          _$VaultError_InsufficientSpaceImpl>
+      get copyWith => __$$VaultError_InsufficientSpaceImplCopyWithImpl<
+          _$VaultError_InsufficientSpaceImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return insufficientSpace(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return insufficientSpace?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (insufficientSpace != null) {
+      return insufficientSpace(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return insufficientSpace(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return insufficientSpace?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (insufficientSpace != null) {
+      return insufficientSpace(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_InsufficientSpace extends VaultError {
+  const factory VaultError_InsufficientSpace({required final String path}) =
+      _$VaultError_InsufficientSpaceImpl;
+  const VaultError_InsufficientSpace._() : super._();
+
+  String get path;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_InsufficientSpaceImplCopyWith<
+          _$VaultError_InsufficientSpaceImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_IoImplCopyWith<$Res> {
+  factory _$$VaultError_IoImplCopyWith(
+          _$VaultError_IoImpl value, $Res Function(_$VaultError_IoImpl) then) =
+      __$$VaultError_IoImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({IoErrorKind kind, String? path, String message});
+}
+
+/// @nodoc
+class __$$VaultError_IoImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_IoImpl>
+    implements _$$VaultError_IoImplCopyWith<$Res> {
+  __$$VaultError_IoImplCopyWithImpl(
+      _$VaultError_IoImpl _value, $Res Function(_$VaultError_IoImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? kind = null,
+    Object? path = freezed,
+    Object? message = null,
+  }) {
+    return _then(_$VaultError_IoImpl(
+      kind: null == kind
+          ? _value.kind
+          : kind // ignore: cast_nullable_to_non_nullable
+              as IoErrorKind,
+      path: freezed == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String?,
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_IoImpl extends VaultError_Io {
+  const _$VaultError_IoImpl(
+      {required this.kind, this.path, required this.message})
+      : super._();
+
+  @override
+  final IoErrorKind kind;
+  @override
+  final String? path;
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'VaultError.io(kind: $kind, path: $path, message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_IoImpl &&
+            (identical(other.kind, kind) || other.kind == kind) &&
+            (identical(other.path, path) || other.path == path) &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, kind, path, message);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_IoImplCopyWith<_$VaultError_IoImpl> get copyWith =>
+      __$$VaultError_IoImplCopyWithImpl<_$VaultError_IoImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return io(kind, path, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return io?.call(kind, path, message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(kind, path, message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return io(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return io?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (io != null) {
+      return io(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_Io extends VaultError {
+  const factory VaultError_Io(
+          {required final IoErrorKind kind,
+          final String? path,
+          required final String message}) =
+      _$VaultError_IoImpl;
+  const VaultError_Io._() : super._();
+
+  IoErrorKind get kind;
+  String? get path;
+  String get message;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_IoImplCopyWith<_$VaultError_IoImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_PathOutsideVaultImplCopyWith<$Res> {
+  factory _$$VaultError_PathOutsideVaultImplCopyWith(
+          _$VaultError_PathOutsideVaultImpl value,
+          $Res Function(_$VaultError_PathOutsideVaultImpl) then) =
+      __$$VaultError_PathOutsideVaultImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path});
+}
+
+/// @nodoc
+class __$$VaultError_PathOutsideVaultImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_PathOutsideVaultImpl>
+    implements _$$VaultError_PathOutsideVaultImplCopyWith<$Res> {
+  __$$VaultError_PathOutsideVaultImplCopyWithImpl(
+      _$VaultError_PathOutsideVaultImpl _value,
+      $Res Function(_$VaultError_PathOutsideVaultImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+  }) {
+    return _then(_$VaultError_PathOutsideVaultImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_PathOutsideVaultImpl extends VaultError_PathOutsideVault {
+  const _$VaultError_PathOutsideVaultImpl({required this.path}) : super._();
+
+  @override
+  final String path;
+
+  @override
+  String toString() {
+    return 'VaultError.pathOutsideVault(path: $path)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_PathOutsideVaultImpl &&
+            (identical(other.path, path) || other.path == path));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_PathOutsideVaultImplCopyWith<
+          _$VaultError_PathOutsideVaultImpl>
+      get copyWith => __$$VaultError_PathOutsideVaultImplCopyWithImpl<
+          _$VaultError_PathOutsideVaultImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return pathOutsideVault(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return pathOutsideVault?.call(path);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (pathOutsideVault != null) {
+      return pathOutsideVault(path);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return pathOutsideVault(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return pathOutsideVault?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (pathOutsideVault != null) {
+      return pathOutsideVault(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_PathOutsideVault extends VaultError {
+  const factory VaultError_PathOutsideVault({required final String path}) =
+      _$VaultError_PathOutsideVaultImpl;
+  const VaultError_PathOutsideVault._() : super._();
+
+  String get path;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_PathOutsideVaultImplCopyWith<_$VaultError_PathOutsideVaultImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_InvalidInputImplCopyWith<$Res> {
+  factory _$$VaultError_InvalidInputImplCopyWith(
+          _$VaultError_InvalidInputImpl value,
+          $Res Function(_$VaultError_InvalidInputImpl) then) =
+      __$$VaultError_InvalidInputImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$VaultError_InvalidInputImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_InvalidInputImpl>
+    implements _$$VaultError_InvalidInputImplCopyWith<$Res> {
+  __$$VaultError_InvalidInputImplCopyWithImpl(
+      _$VaultError_InvalidInputImpl _value,
+      $Res Function(_$VaultError_InvalidInputImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$VaultError_InvalidInputImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_InvalidInputImpl extends VaultError_InvalidInput {
+  const _$VaultError_InvalidInputImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'VaultError.invalidInput(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_InvalidInputImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_InvalidInputImplCopyWith<
+          _$VaultError_InvalidInputImpl>
+      get copyWith => __$$VaultError_InvalidInputImplCopyWithImpl<
+          _$VaultError_InvalidInputImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return invalidInput(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return invalidInput?.call(message);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (invalidInput != null) {
+      return invalidInput(message);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return invalidInput(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return invalidInput?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (invalidInput != null) {
+      return invalidInput(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_InvalidInput extends VaultError {
+  const factory VaultError_InvalidInput({required final String message}) =
+      _$VaultError_InvalidInputImpl;
+  const VaultError_InvalidInput._() : super._();
+
+  String get message;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_InvalidInputImplCopyWith<_$VaultError_InvalidInputImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_InvalidBackupImplCopyWith<$Res> {
+  factory _$$VaultError_InvalidBackupImplCopyWith(
+          _$VaultError_InvalidBackupImpl value,
+          $Res Function(_$VaultError_InvalidBackupImpl) then) =
+      __$$VaultError_InvalidBackupImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String path, String reason});
+}
+
+/// @nodoc
+class __$$VaultError_InvalidBackupImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_InvalidBackupImpl>
+    implements _$$VaultError_InvalidBackupImplCopyWith<$Res> {
+  __$$VaultError_InvalidBackupImplCopyWithImpl(
+      _$VaultError_InvalidBackupImpl _value,
+      $Res Function(_$VaultError_InvalidBackupImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? path = null,
+    Object? reason = null,
+  }) {
+    return _then(_$VaultError_InvalidBackupImpl(
+      path: null == path
+          ? _value.path
+          : path // ignore: cast_nullable_to_non_nullable
+              as String,
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_InvalidBackupImpl extends VaultError_InvalidBackup {
+  const _$VaultError_InvalidBackupImpl(
+      {required this.path, required this.reason})
+      : super._();
+
+  @override
+  final String path;
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'VaultError.invalidBackup(path: $path, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_InvalidBackupImpl &&
+            (identical(other.path, path) || other.path == path) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, path, reason);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_InvalidBackupImplCopyWith<
+          _$VaultError_InvalidBackupImpl>
+      get copyWith => __$$VaultError_InvalidBackupImplCopyWithImpl<
+          _$VaultError_InvalidBackupImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return invalidBackup(path, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return invalidBackup?.call(path, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (invalidBackup != null) {
+      return invalidBackup(path, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return invalidBackup(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return invalidBackup?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (invalidBackup != null) {
+      return invalidBackup(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_InvalidBackup extends VaultError {
+  const factory VaultError_InvalidBackup(
+          {required final String path, required final String reason}) =
+      _$VaultError_InvalidBackupImpl;
+  const VaultError_InvalidBackup._() : super._();
+
+  String get path;
+  String get reason;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_InvalidBackupImplCopyWith<_$VaultError_InvalidBackupImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_BackupVersionMismatchImplCopyWith<$Res> {
+  factory _$$VaultError_BackupVersionMismatchImplCopyWith(
+          _$VaultError_BackupVersionMismatchImpl value,
+          $Res Function(_$VaultError_BackupVersionMismatchImpl) then) =
+      __$$VaultError_BackupVersionMismatchImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int found, int supported});
+}
+
+/// @nodoc
+class __$$VaultError_BackupVersionMismatchImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res,
+        _$VaultError_BackupVersionMismatchImpl>
+    implements _$$VaultError_BackupVersionMismatchImplCopyWith<$Res> {
+  __$$VaultError_BackupVersionMismatchImplCopyWithImpl(
+      _$VaultError_BackupVersionMismatchImpl _value,
+      $Res Function(_$VaultError_BackupVersionMismatchImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? found = null,
+    Object? supported = null,
+  }) {
+    return _then(_$VaultError_BackupVersionMismatchImpl(
+      found: null == found
+          ? _value.found
+          : found // ignore: cast_nullable_to_non_nullable
+              as int,
+      supported: null == supported
+          ? _value.supported
+          : supported // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultError_BackupVersionMismatchImpl
+    extends VaultError_BackupVersionMismatch {
+  const _$VaultError_BackupVersionMismatchImpl(
+      {required this.found, required this.supported})
+      : super._();
+
+  @override
+  final int found;
+  @override
+  final int supported;
+
+  @override
+  String toString() {
+    return 'VaultError.backupVersionMismatch(found: $found, supported: $supported)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_BackupVersionMismatchImpl &&
+            (identical(other.found, found) || other.found == found) &&
+            (identical(other.supported, supported) ||
+                other.supported == supported));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, found, supported);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultError_BackupVersionMismatchImplCopyWith<
+          _$VaultError_BackupVersionMismatchImpl>
+      get copyWith => __$$VaultError_BackupVersionMismatchImplCopyWithImpl<
+          _$VaultError_BackupVersionMismatchImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return backupVersionMismatch(found, supported);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return backupVersionMismatch?.call(found, supported);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (backupVersionMismatch != null) {
+      return backupVersionMismatch(found, supported);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return backupVersionMismatch(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return backupVersionMismatch?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (backupVersionMismatch != null) {
+      return backupVersionMismatch(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_BackupVersionMismatch extends VaultError {
+  const factory VaultError_BackupVersionMismatch(
+          {required final int found, required final int supported}) =
+      _$VaultError_BackupVersionMismatchImpl;
+  const VaultError_BackupVersionMismatch._() : super._();
+
+  int get found;
+  int get supported;
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultError_BackupVersionMismatchImplCopyWith<
+          _$VaultError_BackupVersionMismatchImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$VaultError_CancelledImplCopyWith<$Res> {
+  factory _$$VaultError_CancelledImplCopyWith(_$VaultError_CancelledImpl value,
+          $Res Function(_$VaultError_CancelledImpl) then) =
+      __$$VaultError_CancelledImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$VaultError_CancelledImplCopyWithImpl<$Res>
+    extends _$VaultErrorCopyWithImpl<$Res, _$VaultError_CancelledImpl>
+    implements _$$VaultError_CancelledImplCopyWith<$Res> {
+  __$$VaultError_CancelledImplCopyWithImpl(_$VaultError_CancelledImpl _value,
+      $Res Function(_$VaultError_CancelledImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultError
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+
+class _$VaultError_CancelledImpl extends VaultError_Cancelled {
+  const _$VaultError_CancelledImpl() : super._();
+
+  @override
+  String toString() {
+    return 'VaultError.cancelled()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultError_CancelledImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String message) internal,
+    required TResult Function() incorrectPassword,
+    required TResult Function() locked,
+    required TResult Function(String? path) corrupted,
+    required TResult Function(String? path, String details) unsupportedFormat,
+    required TResult Function(String path) notFound,
+    required TResult Function(String path) alreadyExists,
+    required TResult Function(String path) permissionDenied,
+    required TResult Function(String path) insufficientSpace,
+    required TResult Function(IoErrorKind kind, String? path, String message)
+        io,
+    required TResult Function(String path) pathOutsideVault,
+    required TResult Function(String message) invalidInput,
+    required TResult Function(String path, String reason) invalidBackup,
+    required TResult Function(int found, int supported) backupVersionMismatch,
+    required TResult Function() cancelled,
+  }) {
+    return cancelled();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String message)? internal,
+    TResult? Function()? incorrectPassword,
+    TResult? Function()? locked,
+    TResult? Function(String? path)? corrupted,
+    TResult? Function(String? path, String details)? unsupportedFormat,
+    TResult? Function(String path)? notFound,
+    TResult? Function(String path)? alreadyExists,
+    TResult? Function(String path)? permissionDenied,
+    TResult? Function(String path)? insufficientSpace,
+    TResult? Function(IoErrorKind kind, String? path, String message)? io,
+    TResult? Function(String path)? pathOutsideVault,
+    TResult? Function(String message)? invalidInput,
+    TResult? Function(String path, String reason)? invalidBackup,
+    TResult? Function(int found, int supported)? backupVersionMismatch,
+    TResult? Function()? cancelled,
+  }) {
+    return cancelled?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String message)? internal,
+    TResult Function()? incorrectPassword,
+    TResult Function()? locked,
+    TResult Function(String? path)? corrupted,
+    TResult Function(String? path, String details)? unsupportedFormat,
+    TResult Function(String path)? notFound,
+    TResult Function(String path)? alreadyExists,
+    TResult Function(String path)? permissionDenied,
+    TResult Function(String path)? insufficientSpace,
+    TResult Function(IoErrorKind kind, String? path, String message)? io,
+    TResult Function(String path)? pathOutsideVault,
+    TResult Function(String message)? invalidInput,
+    TResult Function(String path, String reason)? invalidBackup,
+    TResult Function(int found, int supported)? backupVersionMismatch,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultError_Internal value) internal,
+    required TResult Function(VaultError_IncorrectPassword value)
+        incorrectPassword,
+    required TResult Function(VaultError_Locked value) locked,
+    required TResult Function(VaultError_Corrupted value) corrupted,
+    required TResult Function(VaultError_UnsupportedFormat value)
+        unsupportedFormat,
+    required TResult Function(VaultError_NotFound value) notFound,
+    required TResult Function(VaultError_AlreadyExists value) alreadyExists,
+    required TResult Function(VaultError_PermissionDenied value)
+        permissionDenied,
+    required TResult Function(VaultError_InsufficientSpace value)
+        insufficientSpace,
+    required TResult Function(VaultError_Io value) io,
+    required TResult Function(VaultError_PathOutsideVault value)
+        pathOutsideVault,
+    required TResult Function(VaultError_InvalidInput value) invalidInput,
+    required TResult Function(VaultError_InvalidBackup value) invalidBackup,
+    required TResult Function(VaultError_BackupVersionMismatch value)
+        backupVersionMismatch,
+    required TResult Function(VaultError_Cancelled value) cancelled,
+  }) {
+    return cancelled(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultError_Internal value)? internal,
+    TResult? Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult? Function(VaultError_Locked value)? locked,
+    TResult? Function(VaultError_Corrupted value)? corrupted,
+    TResult? Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult? Function(VaultError_NotFound value)? notFound,
+    TResult? Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult? Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult? Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult? Function(VaultError_Io value)? io,
+    TResult? Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult? Function(VaultError_InvalidInput value)? invalidInput,
+    TResult? Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult? Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult? Function(VaultError_Cancelled value)? cancelled,
+  }) {
+    return cancelled?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultError_Internal value)? internal,
+    TResult Function(VaultError_IncorrectPassword value)? incorrectPassword,
+    TResult Function(VaultError_Locked value)? locked,
+    TResult Function(VaultError_Corrupted value)? corrupted,
+    TResult Function(VaultError_UnsupportedFormat value)? unsupportedFormat,
+    TResult Function(VaultError_NotFound value)? notFound,
+    TResult Function(VaultError_AlreadyExists value)? alreadyExists,
+    TResult Function(VaultError_PermissionDenied value)? permissionDenied,
+    TResult Function(VaultError_InsufficientSpace value)? insufficientSpace,
+    TResult Function(VaultError_Io value)? io,
+    TResult Function(VaultError_PathOutsideVault value)? pathOutsideVault,
+    TResult Function(VaultError_InvalidInput value)? invalidInput,
+    TResult Function(VaultError_InvalidBackup value)? invalidBackup,
+    TResult Function(VaultError_BackupVersionMismatch value)?
+        backupVersionMismatch,
+    TResult Function(VaultError_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (cancelled != null) {
+      return cancelled(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultError_Cancelled extends VaultError {
+  const factory VaultError_Cancelled() = _$VaultError_CancelledImpl;
+  const VaultError_Cancelled._() : super._();
+}
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'kdf.freezed.dart';
+
+@freezed
+sealed class KdfConfig with _$KdfConfig {
+  const KdfConfig._();
+
+  const factory KdfConfig.pbkdf2Sha256({
+    required int iterations,
+  }) = KdfConfig_Pbkdf2Sha256;
+  const factory KdfConfig.argon2Id({
+    required int memoryKib,
+    required int iterations,
+    required int parallelism,
+  }) = KdfConfig_Argon2id;
+}
+
+@freezed
+sealed class KdfParams with _$KdfParams {
+  const KdfParams._();
+
+  const factory KdfParams.pbkdf2Sha256({
+    required Uint8List salt,
+    required int iterations,
+  }) = KdfParams_Pbkdf2Sha256;
+  const factory KdfParams.argon2Id({
+    required Uint8List salt,
+    required int memoryKib,
+    required int iterations,
+    required int parallelism,
+  }) = KdfParams_Argon2id;
+}
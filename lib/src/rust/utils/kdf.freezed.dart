@@ -0,0 +1,892 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'kdf.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$KdfConfig {
+  int get iterations => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int iterations) pbkdf2Sha256,
+    required TResult Function(int memoryKib, int iterations, int parallelism)
+        argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int iterations)? pbkdf2Sha256,
+    TResult? Function(int memoryKib, int iterations, int parallelism)? argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int iterations)? pbkdf2Sha256,
+    TResult Function(int memoryKib, int iterations, int parallelism)? argon2Id,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(KdfConfig_Pbkdf2Sha256 value) pbkdf2Sha256,
+    required TResult Function(KdfConfig_Argon2id value) argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(KdfConfig_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult? Function(KdfConfig_Argon2id value)? argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(KdfConfig_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult Function(KdfConfig_Argon2id value)? argon2Id,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $KdfConfigCopyWith<KdfConfig> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $KdfConfigCopyWith<$Res> {
+  factory $KdfConfigCopyWith(KdfConfig value, $Res Function(KdfConfig) then) =
+      _$KdfConfigCopyWithImpl<$Res, KdfConfig>;
+  @useResult
+  $Res call({int iterations});
+}
+
+/// @nodoc
+class _$KdfConfigCopyWithImpl<$Res, $Val extends KdfConfig>
+    implements $KdfConfigCopyWith<$Res> {
+  _$KdfConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? iterations = null,
+  }) {
+    return _then(_value.copyWith(
+      iterations: null == iterations
+          ? _value.iterations
+          : iterations // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$KdfConfig_Pbkdf2Sha256ImplCopyWith<$Res>
+    implements $KdfConfigCopyWith<$Res> {
+  factory _$$KdfConfig_Pbkdf2Sha256ImplCopyWith(
+          _$KdfConfig_Pbkdf2Sha256Impl value,
+          $Res Function(_$KdfConfig_Pbkdf2Sha256Impl) then) =
+      __$$KdfConfig_Pbkdf2Sha256ImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int iterations});
+}
+
+/// @nodoc
+class __$$KdfConfig_Pbkdf2Sha256ImplCopyWithImpl<$Res>
+    extends _$KdfConfigCopyWithImpl<$Res, _$KdfConfig_Pbkdf2Sha256Impl>
+    implements _$$KdfConfig_Pbkdf2Sha256ImplCopyWith<$Res> {
+  __$$KdfConfig_Pbkdf2Sha256ImplCopyWithImpl(
+      _$KdfConfig_Pbkdf2Sha256Impl _value,
+      $Res Function(_$KdfConfig_Pbkdf2Sha256Impl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? iterations = null,
+  }) {
+    return _then(_$KdfConfig_Pbkdf2Sha256Impl(
+      iterations: null == iterations
+          ? _value.iterations
+          : iterations // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$KdfConfig_Pbkdf2Sha256Impl extends KdfConfig_Pbkdf2Sha256 {
+  const _$KdfConfig_Pbkdf2Sha256Impl({required this.iterations}) : super._();
+
+  @override
+  final int iterations;
+
+  @override
+  String toString() {
+    return 'KdfConfig.pbkdf2Sha256(iterations: $iterations)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$KdfConfig_Pbkdf2Sha256Impl &&
+            (identical(other.iterations, iterations) ||
+                other.iterations == iterations));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, iterations);
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$KdfConfig_Pbkdf2Sha256ImplCopyWith<_$KdfConfig_Pbkdf2Sha256Impl>
+      get copyWith =>
+      __$$KdfConfig_Pbkdf2Sha256ImplCopyWithImpl<_$KdfConfig_Pbkdf2Sha256Impl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int iterations) pbkdf2Sha256,
+    required TResult Function(int memoryKib, int iterations, int parallelism)
+        argon2Id,
+  }) {
+    return pbkdf2Sha256(iterations);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int iterations)? pbkdf2Sha256,
+    TResult? Function(int memoryKib, int iterations, int parallelism)? argon2Id,
+  }) {
+    return pbkdf2Sha256?.call(iterations);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int iterations)? pbkdf2Sha256,
+    TResult Function(int memoryKib, int iterations, int parallelism)? argon2Id,
+    required TResult orElse(),
+  }) {
+    if (pbkdf2Sha256 != null) {
+      return pbkdf2Sha256(iterations);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(KdfConfig_Pbkdf2Sha256 value) pbkdf2Sha256,
+    required TResult Function(KdfConfig_Argon2id value) argon2Id,
+  }) {
+    return pbkdf2Sha256(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(KdfConfig_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult? Function(KdfConfig_Argon2id value)? argon2Id,
+  }) {
+    return pbkdf2Sha256?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(KdfConfig_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult Function(KdfConfig_Argon2id value)? argon2Id,
+    required TResult orElse(),
+  }) {
+    if (pbkdf2Sha256 != null) {
+      return pbkdf2Sha256(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class KdfConfig_Pbkdf2Sha256 extends KdfConfig {
+  const factory KdfConfig_Pbkdf2Sha256({required final int iterations}) =
+      _$KdfConfig_Pbkdf2Sha256Impl;
+  const KdfConfig_Pbkdf2Sha256._() : super._();
+
+  @override
+  int get iterations;
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$KdfConfig_Pbkdf2Sha256ImplCopyWith<_$KdfConfig_Pbkdf2Sha256Impl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$KdfConfig_Argon2idImplCopyWith<$Res>
+    implements $KdfConfigCopyWith<$Res> {
+  factory _$$KdfConfig_Argon2idImplCopyWith(_$KdfConfig_Argon2idImpl value,
+          $Res Function(_$KdfConfig_Argon2idImpl) then) =
+      __$$KdfConfig_Argon2idImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({int memoryKib, int iterations, int parallelism});
+}
+
+/// @nodoc
+class __$$KdfConfig_Argon2idImplCopyWithImpl<$Res>
+    extends _$KdfConfigCopyWithImpl<$Res, _$KdfConfig_Argon2idImpl>
+    implements _$$KdfConfig_Argon2idImplCopyWith<$Res> {
+  __$$KdfConfig_Argon2idImplCopyWithImpl(_$KdfConfig_Argon2idImpl _value,
+      $Res Function(_$KdfConfig_Argon2idImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? memoryKib = null,
+    Object? iterations = null,
+    Object? parallelism = null,
+  }) {
+    return _then(_$KdfConfig_Argon2idImpl(
+      memoryKib: null == memoryKib
+          ? _value.memoryKib
+          : memoryKib // ignore: cast_nullable_to_non_nullable
+              as int,
+      iterations: null == iterations
+          ? _value.iterations
+          : iterations // ignore: cast_nullable_to_non_nullable
+              as int,
+      parallelism: null == parallelism
+          ? _value.parallelism
+          : parallelism // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$KdfConfig_Argon2idImpl extends KdfConfig_Argon2id {
+  const _$KdfConfig_Argon2idImpl(
+      {required this.memoryKib,
+      required this.iterations,
+      required this.parallelism})
+      : super._();
+
+  @override
+  final int memoryKib;
+  @override
+  final int iterations;
+  @override
+  final int parallelism;
+
+  @override
+  String toString() {
+    return 'KdfConfig.argon2Id(memoryKib: $memoryKib, iterations: $iterations, parallelism: $parallelism)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$KdfConfig_Argon2idImpl &&
+            (identical(other.memoryKib, memoryKib) ||
+                other.memoryKib == memoryKib) &&
+            (identical(other.iterations, iterations) ||
+                other.iterations == iterations) &&
+            (identical(other.parallelism, parallelism) ||
+                other.parallelism == parallelism));
+  }
+
+  @override
+  int get hashCode =>
+      Object.hash(runtimeType, memoryKib, iterations, parallelism);
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$KdfConfig_Argon2idImplCopyWith<_$KdfConfig_Argon2idImpl> get copyWith =>
+      __$$KdfConfig_Argon2idImplCopyWithImpl<_$KdfConfig_Argon2idImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int iterations) pbkdf2Sha256,
+    required TResult Function(int memoryKib, int iterations, int parallelism)
+        argon2Id,
+  }) {
+    return argon2Id(memoryKib, iterations, parallelism);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int iterations)? pbkdf2Sha256,
+    TResult? Function(int memoryKib, int iterations, int parallelism)? argon2Id,
+  }) {
+    return argon2Id?.call(memoryKib, iterations, parallelism);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int iterations)? pbkdf2Sha256,
+    TResult Function(int memoryKib, int iterations, int parallelism)? argon2Id,
+    required TResult orElse(),
+  }) {
+    if (argon2Id != null) {
+      return argon2Id(memoryKib, iterations, parallelism);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(KdfConfig_Pbkdf2Sha256 value) pbkdf2Sha256,
+    required TResult Function(KdfConfig_Argon2id value) argon2Id,
+  }) {
+    return argon2Id(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(KdfConfig_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult? Function(KdfConfig_Argon2id value)? argon2Id,
+  }) {
+    return argon2Id?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(KdfConfig_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult Function(KdfConfig_Argon2id value)? argon2Id,
+    required TResult orElse(),
+  }) {
+    if (argon2Id != null) {
+      return argon2Id(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class KdfConfig_Argon2id extends KdfConfig {
+  const factory KdfConfig_Argon2id(
+          {required final int memoryKib,
+          required final int iterations,
+          required final int parallelism}) =
+      _$KdfConfig_Argon2idImpl;
+  const KdfConfig_Argon2id._() : super._();
+
+  int get memoryKib;
+  @override
+  int get iterations;
+  int get parallelism;
+
+  /// Create a copy of KdfConfig
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$KdfConfig_Argon2idImplCopyWith<_$KdfConfig_Argon2idImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$KdfParams {
+  Uint8List get salt => throw _privateConstructorUsedError;
+  int get iterations => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint8List salt, int iterations) pbkdf2Sha256,
+    required TResult Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)
+        argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint8List salt, int iterations)? pbkdf2Sha256,
+    TResult? Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)?
+        argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint8List salt, int iterations)? pbkdf2Sha256,
+    TResult Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)?
+        argon2Id,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(KdfParams_Pbkdf2Sha256 value) pbkdf2Sha256,
+    required TResult Function(KdfParams_Argon2id value) argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(KdfParams_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult? Function(KdfParams_Argon2id value)? argon2Id,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(KdfParams_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult Function(KdfParams_Argon2id value)? argon2Id,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $KdfParamsCopyWith<KdfParams> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $KdfParamsCopyWith<$Res> {
+  factory $KdfParamsCopyWith(KdfParams value, $Res Function(KdfParams) then) =
+      _$KdfParamsCopyWithImpl<$Res, KdfParams>;
+  @useResult
+  $Res call({Uint8List salt, int iterations});
+}
+
+/// @nodoc
+class _$KdfParamsCopyWithImpl<$Res, $Val extends KdfParams>
+    implements $KdfParamsCopyWith<$Res> {
+  _$KdfParamsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? salt = null,
+    Object? iterations = null,
+  }) {
+    return _then(_value.copyWith(
+      salt: null == salt
+          ? _value.salt
+          : salt // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      iterations: null == iterations
+          ? _value.iterations
+          : iterations // ignore: cast_nullable_to_non_nullable
+              as int,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$KdfParams_Pbkdf2Sha256ImplCopyWith<$Res>
+    implements $KdfParamsCopyWith<$Res> {
+  factory _$$KdfParams_Pbkdf2Sha256ImplCopyWith(
+          _$KdfParams_Pbkdf2Sha256Impl value,
+          $Res Function(_$KdfParams_Pbkdf2Sha256Impl) then) =
+      __$$KdfParams_Pbkdf2Sha256ImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({Uint8List salt, int iterations});
+}
+
+/// @nodoc
+class __$$KdfParams_Pbkdf2Sha256ImplCopyWithImpl<$Res>
+    extends _$KdfParamsCopyWithImpl<$Res, _$KdfParams_Pbkdf2Sha256Impl>
+    implements _$$KdfParams_Pbkdf2Sha256ImplCopyWith<$Res> {
+  __$$KdfParams_Pbkdf2Sha256ImplCopyWithImpl(
+      _$KdfParams_Pbkdf2Sha256Impl _value,
+      $Res Function(_$KdfParams_Pbkdf2Sha256Impl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? salt = null,
+    Object? iterations = null,
+  }) {
+    return _then(_$KdfParams_Pbkdf2Sha256Impl(
+      salt: null == salt
+          ? _value.salt
+          : salt // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      iterations: null == iterations
+          ? _value.iterations
+          : iterations // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$KdfParams_Pbkdf2Sha256Impl extends KdfParams_Pbkdf2Sha256 {
+  const _$KdfParams_Pbkdf2Sha256Impl(
+      {required this.salt, required this.iterations})
+      : super._();
+
+  @override
+  final Uint8List salt;
+  @override
+  final int iterations;
+
+  @override
+  String toString() {
+    return 'KdfParams.pbkdf2Sha256(salt: $salt, iterations: $iterations)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$KdfParams_Pbkdf2Sha256Impl &&
+            const DeepCollectionEquality().equals(other.salt, salt) &&
+            (identical(other.iterations, iterations) ||
+                other.iterations == iterations));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType, const DeepCollectionEquality().hash(salt), iterations);
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$KdfParams_Pbkdf2Sha256ImplCopyWith<_$KdfParams_Pbkdf2Sha256Impl>
+      get copyWith =>
+      __$$KdfParams_Pbkdf2Sha256ImplCopyWithImpl<_$KdfParams_Pbkdf2Sha256Impl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint8List salt, int iterations) pbkdf2Sha256,
+    required TResult Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)
+        argon2Id,
+  }) {
+    return pbkdf2Sha256(salt, iterations);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint8List salt, int iterations)? pbkdf2Sha256,
+    TResult? Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)?
+        argon2Id,
+  }) {
+    return pbkdf2Sha256?.call(salt, iterations);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint8List salt, int iterations)? pbkdf2Sha256,
+    TResult Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)?
+        argon2Id,
+    required TResult orElse(),
+  }) {
+    if (pbkdf2Sha256 != null) {
+      return pbkdf2Sha256(salt, iterations);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(KdfParams_Pbkdf2Sha256 value) pbkdf2Sha256,
+    required TResult Function(KdfParams_Argon2id value) argon2Id,
+  }) {
+    return pbkdf2Sha256(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(KdfParams_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult? Function(KdfParams_Argon2id value)? argon2Id,
+  }) {
+    return pbkdf2Sha256?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(KdfParams_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult Function(KdfParams_Argon2id value)? argon2Id,
+    required TResult orElse(),
+  }) {
+    if (pbkdf2Sha256 != null) {
+      return pbkdf2Sha256(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class KdfParams_Pbkdf2Sha256 extends KdfParams {
+  const factory KdfParams_Pbkdf2Sha256(
+          {required final Uint8List salt, required final int iterations}) =
+      _$KdfParams_Pbkdf2Sha256Impl;
+  const KdfParams_Pbkdf2Sha256._() : super._();
+
+  @override
+  Uint8List get salt;
+  @override
+  int get iterations;
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$KdfParams_Pbkdf2Sha256ImplCopyWith<_$KdfParams_Pbkdf2Sha256Impl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$KdfParams_Argon2idImplCopyWith<$Res>
+    implements $KdfParamsCopyWith<$Res> {
+  factory _$$KdfParams_Argon2idImplCopyWith(_$KdfParams_Argon2idImpl value,
+          $Res Function(_$KdfParams_Argon2idImpl) then) =
+      __$$KdfParams_Argon2idImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({Uint8List salt, int memoryKib, int iterations, int parallelism});
+}
+
+/// @nodoc
+class __$$KdfParams_Argon2idImplCopyWithImpl<$Res>
+    extends _$KdfParamsCopyWithImpl<$Res, _$KdfParams_Argon2idImpl>
+    implements _$$KdfParams_Argon2idImplCopyWith<$Res> {
+  __$$KdfParams_Argon2idImplCopyWithImpl(_$KdfParams_Argon2idImpl _value,
+      $Res Function(_$KdfParams_Argon2idImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? salt = null,
+    Object? memoryKib = null,
+    Object? iterations = null,
+    Object? parallelism = null,
+  }) {
+    return _then(_$KdfParams_Argon2idImpl(
+      salt: null == salt
+          ? _value.salt
+          : salt // ignore: cast_nullable_to_non_nullable
+              as Uint8List,
+      memoryKib: null == memoryKib
+          ? _value.memoryKib
+          : memoryKib // ignore: cast_nullable_to_non_nullable
+              as int,
+      iterations: null == iterations
+          ? _value.iterations
+          : iterations // ignore: cast_nullable_to_non_nullable
+              as int,
+      parallelism: null == parallelism
+          ? _value.parallelism
+          : parallelism // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$KdfParams_Argon2idImpl extends KdfParams_Argon2id {
+  const _$KdfParams_Argon2idImpl(
+      {required this.salt,
+      required this.memoryKib,
+      required this.iterations,
+      required this.parallelism})
+      : super._();
+
+  @override
+  final Uint8List salt;
+  @override
+  final int memoryKib;
+  @override
+  final int iterations;
+  @override
+  final int parallelism;
+
+  @override
+  String toString() {
+    return 'KdfParams.argon2Id(salt: $salt, memoryKib: $memoryKib, iterations: $iterations, parallelism: $parallelism)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$KdfParams_Argon2idImpl &&
+            const DeepCollectionEquality().equals(other.salt, salt) &&
+            (identical(other.memoryKib, memoryKib) ||
+                other.memoryKib == memoryKib) &&
+            (identical(other.iterations, iterations) ||
+                other.iterations == iterations) &&
+            (identical(other.parallelism, parallelism) ||
+                other.parallelism == parallelism));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      const DeepCollectionEquality().hash(salt),
+      memoryKib,
+      iterations,
+      parallelism);
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$KdfParams_Argon2idImplCopyWith<_$KdfParams_Argon2idImpl> get copyWith =>
+      __$$KdfParams_Argon2idImplCopyWithImpl<_$KdfParams_Argon2idImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(Uint8List salt, int iterations) pbkdf2Sha256,
+    required TResult Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)
+        argon2Id,
+  }) {
+    return argon2Id(salt, memoryKib, iterations, parallelism);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(Uint8List salt, int iterations)? pbkdf2Sha256,
+    TResult? Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)?
+        argon2Id,
+  }) {
+    return argon2Id?.call(salt, memoryKib, iterations, parallelism);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(Uint8List salt, int iterations)? pbkdf2Sha256,
+    TResult Function(
+            Uint8List salt, int memoryKib, int iterations, int parallelism)?
+        argon2Id,
+    required TResult orElse(),
+  }) {
+    if (argon2Id != null) {
+      return argon2Id(salt, memoryKib, iterations, parallelism);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(KdfParams_Pbkdf2Sha256 value) pbkdf2Sha256,
+    required TResult Function(KdfParams_Argon2id value) argon2Id,
+  }) {
+    return argon2Id(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(KdfParams_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult? Function(KdfParams_Argon2id value)? argon2Id,
+  }) {
+    return argon2Id?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(KdfParams_Pbkdf2Sha256 value)? pbkdf2Sha256,
+    TResult Function(KdfParams_Argon2id value)? argon2Id,
+    required TResult orElse(),
+  }) {
+    if (argon2Id != null) {
+      return argon2Id(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class KdfParams_Argon2id extends KdfParams {
+  const factory KdfParams_Argon2id(
+          {required final Uint8List salt,
+          required final int memoryKib,
+          required final int iterations,
+          required final int parallelism}) =
+      _$KdfParams_Argon2idImpl;
+  const KdfParams_Argon2id._() : super._();
+
+  @override
+  Uint8List get salt;
+  int get memoryKib;
+  @override
+  int get iterations;
+  int get parallelism;
+
+  /// Create a copy of KdfParams
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$KdfParams_Argon2idImplCopyWith<_$KdfParams_Argon2idImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
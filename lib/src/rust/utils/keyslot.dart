@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class KeySlotInfo {
+  final int id;
+  final KeySlotKind kind;
+  final String label;
+  final PlatformInt64 createdAt;
+
+  const KeySlotInfo({
+    required this.id,
+    required this.kind,
+    required this.label,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ kind.hashCode ^ label.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KeySlotInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          kind == other.kind &&
+          label == other.label &&
+          createdAt == other.createdAt;
+}
+
+enum KeySlotKind {
+  password,
+  pin,
+  recoveryKey,
+  ;
+}
@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'kdf.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+enum BackupEncryption {
+  plain,
+  vaultKey,
+  ;
+}
+
+class BackupManifest {
+  final int version;
+  final PlatformInt64 createdAt;
+  final String? appVersion;
+  final BackupEncryption encryption;
+  final List<KdfParams> kdf;
+  final List<String> albums;
+  final List<ManifestEntry> entries;
+
+  const BackupManifest({
+    required this.version,
+    required this.createdAt,
+    this.appVersion,
+    required this.encryption,
+    required this.kdf,
+    required this.albums,
+    required this.entries,
+  });
+
+  @override
+  int get hashCode =>
+      version.hashCode ^
+      createdAt.hashCode ^
+      appVersion.hashCode ^
+      encryption.hashCode ^
+      kdf.hashCode ^
+      albums.hashCode ^
+      entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BackupManifest &&
+          runtimeType == other.runtimeType &&
+          version == other.version &&
+          createdAt == other.createdAt &&
+          appVersion == other.appVersion &&
+          encryption == other.encryption &&
+          kdf == other.kdf &&
+          albums == other.albums &&
+          entries == other.entries;
+}
+
+class ManifestEntry {
+  final String name;
+  final String? originalName;
+  final String? mimeType;
+  final PlatformInt64? modifiedAt;
+  final BigInt size;
+  final String sha256;
+
+  const ManifestEntry({
+    required this.name,
+    this.originalName,
+    this.mimeType,
+    this.modifiedAt,
+    required this.size,
+    required this.sha256,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      originalName.hashCode ^
+      mimeType.hashCode ^
+      modifiedAt.hashCode ^
+      size.hashCode ^
+      sha256.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ManifestEntry &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          originalName == other.originalName &&
+          mimeType == other.mimeType &&
+          modifiedAt == other.modifiedAt &&
+          size == other.size &&
+          sha256 == other.sha256;
+}
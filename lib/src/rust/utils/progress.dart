@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancelToken>>
+abstract class CancelToken implements RustOpaqueInterface {}
+
+class ProgressEvent {
+  final int filesDone;
+  final int filesTotal;
+  final BigInt bytesDone;
+  final BigInt bytesTotal;
+  final String? currentItem;
+
+  const ProgressEvent({
+    required this.filesDone,
+    required this.filesTotal,
+    required this.bytesDone,
+    required this.bytesTotal,
+    this.currentItem,
+  });
+
+  @override
+  int get hashCode =>
+      filesDone.hashCode ^
+      filesTotal.hashCode ^
+      bytesDone.hashCode ^
+      bytesTotal.hashCode ^
+      currentItem.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProgressEvent &&
+          runtimeType == other.runtimeType &&
+          filesDone == other.filesDone &&
+          filesTotal == other.filesTotal &&
+          bytesDone == other.bytesDone &&
+          bytesTotal == other.bytesTotal &&
+          currentItem == other.currentItem;
+}
@@ -0,0 +1,24 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'session.freezed.dart';
+
+enum LockReason {
+  manual,
+  inactivity,
+  ;
+}
+
+@freezed
+sealed class VaultEvent with _$VaultEvent {
+  const VaultEvent._();
+
+  const factory VaultEvent.locked({
+    required LockReason reason,
+  }) = VaultEvent_Locked;
+}
@@ -0,0 +1,234 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'session.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$VaultEvent {
+  LockReason get reason => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LockReason reason) locked,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LockReason reason)? locked,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LockReason reason)? locked,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultEvent_Locked value) locked,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultEvent_Locked value)? locked,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultEvent_Locked value)? locked,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  /// Create a copy of VaultEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  $VaultEventCopyWith<VaultEvent> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $VaultEventCopyWith<$Res> {
+  factory $VaultEventCopyWith(
+          VaultEvent value, $Res Function(VaultEvent) then) =
+      _$VaultEventCopyWithImpl<$Res, VaultEvent>;
+  @useResult
+  $Res call({LockReason reason});
+}
+
+/// @nodoc
+class _$VaultEventCopyWithImpl<$Res, $Val extends VaultEvent>
+    implements $VaultEventCopyWith<$Res> {
+  _$VaultEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of VaultEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? reason = null,
+  }) {
+    return _then(_value.copyWith(
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as LockReason,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$VaultEvent_LockedImplCopyWith<$Res>
+    implements $VaultEventCopyWith<$Res> {
+  factory _$$VaultEvent_LockedImplCopyWith(_$VaultEvent_LockedImpl value,
+          $Res Function(_$VaultEvent_LockedImpl) then) =
+      __$$VaultEvent_LockedImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({LockReason reason});
+}
+
+/// @nodoc
+class __$$VaultEvent_LockedImplCopyWithImpl<$Res>
+    extends _$VaultEventCopyWithImpl<$Res, _$VaultEvent_LockedImpl>
+    implements _$$VaultEvent_LockedImplCopyWith<$Res> {
+  __$$VaultEvent_LockedImplCopyWithImpl(_$VaultEvent_LockedImpl _value,
+      $Res Function(_$VaultEvent_LockedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of VaultEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? reason = null,
+  }) {
+    return _then(_$VaultEvent_LockedImpl(
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as LockReason,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$VaultEvent_LockedImpl extends VaultEvent_Locked {
+  const _$VaultEvent_LockedImpl({required this.reason}) : super._();
+
+  @override
+  final LockReason reason;
+
+  @override
+  String toString() {
+    return 'VaultEvent.locked(reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$VaultEvent_LockedImpl &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, reason);
+
+  /// Create a copy of VaultEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$VaultEvent_LockedImplCopyWith<_$VaultEvent_LockedImpl> get copyWith =>
+      __$$VaultEvent_LockedImplCopyWithImpl<_$VaultEvent_LockedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LockReason reason) locked,
+  }) {
+    return locked(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LockReason reason)? locked,
+  }) {
+    return locked?.call(reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LockReason reason)? locked,
+    required TResult orElse(),
+  }) {
+    if (locked != null) {
+      return locked(reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(VaultEvent_Locked value) locked,
+  }) {
+    return locked(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(VaultEvent_Locked value)? locked,
+  }) {
+    return locked?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(VaultEvent_Locked value)? locked,
+    required TResult orElse(),
+  }) {
+    if (locked != null) {
+      return locked(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class VaultEvent_Locked extends VaultEvent {
+  const factory VaultEvent_Locked({required final LockReason reason}) =
+      _$VaultEvent_LockedImpl;
+  const VaultEvent_Locked._() : super._();
+
+  @override
+  LockReason get reason;
+
+  /// Create a copy of VaultEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$VaultEvent_LockedImplCopyWith<_$VaultEvent_LockedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class MediaInfo {
+  final String name;
+  final String? originalName;
+  final String? mimeType;
+  final BigInt? size;
+  final double? aspectRatio;
+  final PlatformInt64 createdAt;
+  final PlatformInt64? modifiedAt;
+
+  const MediaInfo({
+    required this.name,
+    this.originalName,
+    this.mimeType,
+    this.size,
+    this.aspectRatio,
+    required this.createdAt,
+    this.modifiedAt,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      originalName.hashCode ^
+      mimeType.hashCode ^
+      size.hashCode ^
+      aspectRatio.hashCode ^
+      createdAt.hashCode ^
+      modifiedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaInfo &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          originalName == other.originalName &&
+          mimeType == other.mimeType &&
+          size == other.size &&
+          aspectRatio == other.aspectRatio &&
+          createdAt == other.createdAt &&
+          modifiedAt == other.modifiedAt;
+}
+
+class MediaSource {
+  final String? originalName;
+  final String? mimeType;
+  final PlatformInt64? modifiedAt;
+
+  const MediaSource({
+    this.originalName,
+    this.mimeType,
+    this.modifiedAt,
+  });
+
+  @override
+  int get hashCode =>
+      originalName.hashCode ^ mimeType.hashCode ^ modifiedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaSource &&
+          runtimeType == other.runtimeType &&
+          originalName == other.originalName &&
+          mimeType == other.mimeType &&
+          modifiedAt == other.modifiedAt;
+}
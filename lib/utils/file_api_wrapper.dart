@@ -3,7 +3,14 @@ import 'package:isolate_pool_2/isolate_pool_2.dart';
 import 'package:vault/providers.dart';
 import 'package:vault/src/rust/api/file.dart' as file_api;
 import 'package:vault/src/rust/frb_generated.dart';
+import 'package:vault/src/rust/utils/backup.dart';
+import 'package:vault/src/rust/utils/error.dart';
+import 'package:vault/src/rust/utils/progress.dart';
+import 'package:vault/src/rust/utils/session.dart';
+import 'package:vault/src/rust/utils/storage.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge.dart'
+    show RustStreamSink;
 
 final Set<String> _pendingRequests = {};
 
@@ -125,11 +132,12 @@ mixin FileApiWrapper {
     }
   }
 
-  Future<void> saveImageWrapper(data, path) async {
+  Future<void> saveImageWrapper(data, path, {MediaSource? source}) async {
     return await file_api
         .saveMedia(
           imageData: data,
           dir: path,
+          source: source,
         )
         .catchError((e) => debugPrint("Vault error: WARN: $e"));
   }
@@ -147,20 +155,31 @@ mixin FileApiWrapper {
   }
 
   Future<bool> setPasswordWrapper(password, dir) async {
-    return await file_api
-        .setPassword(password: password, dir: dir)
-        .then((value) => value)
-        .catchError((e) {
-      debugPrint("Vault error: WARN: $e");
+    try {
+      return await file_api.setPassword(password: password, dir: dir);
+    } on VaultError_IncorrectPassword {
       return false;
-    });
+    } catch (e) {
+      debugPrint("Vault error: WARN: $e");
+      rethrow;
+    }
   }
 
   Future<void> savePasswordWrapper(password, dir) async {
-    await file_api
-        .savePassword(dir: dir, password: password)
-        .then((value) => {value})
-        .catchError((e) => {debugPrint("Vault error: WARN: $e")});
+    await file_api.savePassword(dir: dir, password: password).catchError((e) {
+      debugPrint("Vault error: WARN: $e");
+      throw e;
+    });
+  }
+
+  Future<void> lockVaultWrapper() async {
+    return await file_api
+        .lockVault()
+        .catchError((e) => debugPrint("Vault error: WARN: $e"));
+  }
+
+  Stream<VaultEvent> vaultEventsWrapper() {
+    return file_api.vaultEvents();
   }
 
   Future<bool> checkPasswordExistWrapper(dir) async {
@@ -173,16 +192,50 @@ mixin FileApiWrapper {
     });
   }
 
-  Future<void> zipBackupWrapper(rootDir, savePath, bool encryption) async {
+  Future<CancelToken> createCancelTokenWrapper() async {
+    return await file_api.createCancelToken();
+  }
+
+  Future<void> cancelOperationWrapper(CancelToken token) async {
     return await file_api
-        .zipBackup(rootDir: rootDir, savePath: savePath, encryption: encryption)
+        .cancelOperation(token: token)
         .catchError((e) => debugPrint("Vault error: WARN: $e"));
   }
 
-  Future<void> restoreBackupWrapper(rootDir, zipPath, password) async {
+  // The progress sink is registered synchronously when the call is made, so
+  // callers can listen to `progress.stream` right after invoking these.
+  Future<void> zipBackupWrapper(rootDir, savePath, bool encryption,
+      {required CancelToken cancel,
+      RustStreamSink<ProgressEvent>? progress}) async {
     return await file_api
-        .restoreBackup(rootDir: rootDir, zipPath: zipPath, password: password)
-        .catchError((e) => debugPrint("Vault error: WARN: $e"));
+        .zipBackup(
+            rootDir: rootDir,
+            savePath: savePath,
+            encryption: encryption,
+            progress: progress,
+            cancel: cancel)
+        .catchError((e) {
+      debugPrint("Vault error: WARN: $e");
+      throw e;
+    });
+  }
+
+  Future<RestoreSummary> restoreBackupWrapper(rootDir, zipPath, password,
+      {required CancelToken cancel,
+      DuplicatePolicy duplicates = DuplicatePolicy.skip,
+      RustStreamSink<ProgressEvent>? progress}) async {
+    try {
+      return await file_api.restoreBackup(
+          rootDir: rootDir,
+          zipPath: zipPath,
+          password: password,
+          duplicates: duplicates,
+          progress: progress,
+          cancel: cancel);
+    } catch (e) {
+      debugPrint("Vault error: WARN: $e");
+      rethrow;
+    }
   }
 
   Future<bool> checkZipEncryptedWrapper(zipPath) async {
@@ -219,6 +272,51 @@ mixin FileApiWrapper {
   }
 }
 
+// Text shown to the user for an error coming back from the Rust side
+String vaultErrorMessage(Object error) {
+  switch (error) {
+    case VaultError_IncorrectPassword():
+      return "Incorrect password.";
+    case VaultError_Locked():
+      return "The vault is locked. Unlock it and try again.";
+    case VaultError_NotFound(:final path):
+      return "${_fileName(path)} no longer exists.";
+    case VaultError_AlreadyExists(:final path):
+      return "${_fileName(path)} already exists.";
+    case VaultError_PermissionDenied(:final path):
+      return "Vault has no permission to access ${_fileName(path)}.";
+    case VaultError_InsufficientSpace():
+      return "There is not enough free storage.";
+    case VaultError_Corrupted():
+      return "The file is damaged and can't be opened.";
+    case VaultError_UnsupportedFormat(:final details):
+      return "This file type is not supported ($details).";
+    case VaultError_Io(kind: IoErrorKind.readOnly):
+      return "The storage is read-only.";
+    case VaultError_Io(kind: IoErrorKind.busy):
+      return "The file is in use. Try again in a moment.";
+    case VaultError_Io(:final message):
+      return "Could not read or write a file: $message";
+    case VaultError_PathOutsideVault():
+      return "That location is outside the vault.";
+    case VaultError_InvalidInput(:final message):
+      return message;
+    case VaultError_InvalidBackup(:final reason):
+      return "This backup can't be restored: $reason";
+    case VaultError_BackupVersionMismatch(:final found, :final supported):
+      return "This backup uses format $found, but this version of Vault only "
+          "reads up to format $supported. Update the app to restore it.";
+    case VaultError_Cancelled():
+      return "The operation was cancelled.";
+    case VaultError_Internal(:final message):
+      return "Something went wrong: $message";
+    default:
+      return error.toString();
+  }
+}
+
+String _fileName(String path) => path.split('/').last;
+
 // Future<Uint8List> _isolateGetFile(String path) async {
 //   await RustLib.init();
 
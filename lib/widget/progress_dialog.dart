@@ -0,0 +1,96 @@
+import 'package:flutter/cupertino.dart';
+import 'package:vault/src/rust/utils/progress.dart';
+
+class ProgressDialog extends StatefulWidget {
+  final String title;
+  final Stream<ProgressEvent> progress;
+  final VoidCallback onCancel;
+
+  const ProgressDialog({
+    super.key,
+    required this.title,
+    required this.progress,
+    required this.onCancel,
+  });
+
+  @override
+  State<ProgressDialog> createState() => _ProgressDialogState();
+}
+
+class _ProgressDialogState extends State<ProgressDialog> {
+  bool _cancelling = false;
+
+  static String _formatBytes(BigInt bytes) {
+    const units = ["B", "KB", "MB", "GB"];
+    double value = bytes.toDouble();
+    int unit = 0;
+    while (value >= 1024 && unit < units.length - 1) {
+      value /= 1024;
+      unit++;
+    }
+    return unit == 0
+        ? "${bytes.toString()} ${units[unit]}"
+        : "${value.toStringAsFixed(1)} ${units[unit]}";
+  }
+
+  void _cancel() {
+    setState(() => _cancelling = true);
+    widget.onCancel();
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return CupertinoAlertDialog(
+      title: Text(widget.title),
+      content: StreamBuilder<ProgressEvent>(
+        stream: widget.progress,
+        builder: (context, snapshot) {
+          final event = snapshot.data;
+          if (event == null) {
+            return const Padding(
+              padding: EdgeInsets.only(top: 15.0),
+              child: CupertinoActivityIndicator(radius: 15),
+            );
+          }
+
+          final fraction = event.bytesTotal == BigInt.zero
+              ? 0.0
+              : (event.bytesDone.toDouble() / event.bytesTotal.toDouble())
+                  .clamp(0.0, 1.0);
+
+          return Column(
+            children: [
+              const SizedBox(height: 15),
+              CupertinoActivityIndicator.partiallyRevealed(
+                  radius: 15, progress: fraction),
+              const SizedBox(height: 15),
+              Text("${event.filesDone} of ${event.filesTotal} files"),
+              Text(
+                "${_formatBytes(event.bytesDone)} of "
+                "${_formatBytes(event.bytesTotal)}",
+                style: const TextStyle(
+                    color: CupertinoColors.systemGrey, fontSize: 13),
+              ),
+              if (event.currentItem != null)
+                Padding(
+                  padding: const EdgeInsets.only(top: 8.0),
+                  child: Text(event.currentItem!,
+                      maxLines: 1,
+                      overflow: TextOverflow.ellipsis,
+                      style: const TextStyle(
+                          color: CupertinoColors.systemGrey, fontSize: 13)),
+                ),
+            ],
+          );
+        },
+      ),
+      actions: [
+        CupertinoDialogAction(
+          isDestructiveAction: true,
+          onPressed: _cancelling ? null : _cancel,
+          child: Text(_cancelling ? "Cancelling..." : "Cancel"),
+        ),
+      ],
+    );
+  }
+}
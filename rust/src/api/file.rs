@@ -1,460 +1,362 @@
 // file manipulation
 use std::{
     collections::HashMap,
-    fs::{self, remove_dir_all, remove_file, File},
-    io::{self, BufReader, Cursor, Read, Write},
-    path::{Path, PathBuf},
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+    sync::{Arc, RwLock},
 };
 
-use walkdir::WalkDir;
-use zip::{write::FileOptions, ZipArchive, ZipWriter};
+use lazy_static::lazy_static;
+use zip::ZipArchive;
 
 // Custom error
 use crate::utils::{
     encryption::{VAULT_FILE, VERIFICATION_DATA},
-    error::VaultError,
+    error::{catch_panic, VaultError},
     utils::verify_and_get_decrypter,
 };
 // Encrytion
 use crate::utils::encryption::{
-    check_password, check_validation_data_exists, decrypt_data, encrypt_data, save_validation_data,
+    add_recovery_slot, add_validation_slot, change_validation_password,
+    check_validation_data_exists, create_vault_key, list_validation_slots, open_vault_key,
+    remove_validation_slot, CryptoParams,
 };
-// Caching
-use crate::utils::cache::cache_image;
-// Utils
-use crate::utils::utils::generate_unique_filename;
-use crate::utils::utils::rename_with_parent;
+// Session
+use crate::frb_generated::StreamSink;
+use crate::utils::session::{add_event_sink, lock, set_auto_lock, LockReason, VaultEvent};
+// Media server
+use crate::utils::server;
+// Progress
+use crate::utils::progress::{CancelToken, ProgressEvent};
+// Backup manifest
+use crate::utils::manifest::BackupManifest;
+// Vault operations
+use crate::api::media::MediaWriter;
+use crate::api::vault::Vault;
+use crate::utils::backup::{
+    self, BackupContents, BackupReport, BackupSelection, DuplicatePolicy, RestoreSummary,
+};
+use crate::utils::storage::{MediaInfo, MediaSource};
+// Key derivation
+use crate::utils::kdf::{benchmark_argon2, KdfConfig};
+// Key slots
+use crate::utils::keyslot::{KeySlotInfo, KeySlotKind};
 
 use infer;
 
+lazy_static! {
+    // The vault unlocked by set_password or save_password, the vault functions below are
+    // thin wrappers over it that keep taking full paths
+    static ref SESSION_VAULT: RwLock<Option<Arc<Vault>>> = RwLock::new(None);
+}
+
 pub fn set_password(password: &str, dir: &str) -> Result<bool, VaultError> {
-    Ok(check_password(password, dir)?)
+    catch_panic(|| match open_vault_key(dir, password)? {
+        Some(key) => {
+            start_session(dir, key)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    })
 }
 pub fn save_password(password: &str, dir: &str) -> Result<(), VaultError> {
-    Ok(save_validation_data(dir, password)?)
+    catch_panic(|| start_session(dir, create_vault_key(dir, password, &KdfConfig::default())?))
+}
+pub fn save_password_with_kdf(password: &str, dir: &str, kdf: KdfConfig) -> Result<(), VaultError> {
+    catch_panic(|| start_session(dir, create_vault_key(dir, password, &kdf)?))
+}
+pub fn benchmark_kdf(target_millis: u32) -> Result<KdfConfig, VaultError> {
+    catch_panic(|| benchmark_argon2(target_millis))
+}
+pub fn change_password(
+    old_password: &str,
+    new_password: &str,
+    dir: &str,
+) -> Result<(), VaultError> {
+    catch_panic(|| change_validation_password(dir, old_password, new_password))
+}
+pub fn add_key_slot(
+    password: &str,
+    dir: &str,
+    kind: KeySlotKind,
+    secret: &str,
+    label: &str,
+) -> Result<u32, VaultError> {
+    catch_panic(|| add_validation_slot(dir, password, kind, secret, label))
+}
+// The returned code is shown once for the user to write down, it is never stored
+pub fn add_recovery_key(password: &str, dir: &str) -> Result<String, VaultError> {
+    catch_panic(|| add_recovery_slot(dir, password))
+}
+pub fn list_key_slots(dir: &str) -> Result<Vec<KeySlotInfo>, VaultError> {
+    catch_panic(|| list_validation_slots(dir))
+}
+pub fn revoke_key_slot(password: &str, dir: &str, slot_id: u32) -> Result<(), VaultError> {
+    catch_panic(|| remove_validation_slot(dir, password, slot_id))
+}
+// Forgets the key, every vault call fails with VaultError::Locked until set_password
+pub fn lock_vault() -> Result<(), VaultError> {
+    catch_panic(|| lock(LockReason::Manual))
+}
+pub fn is_unlocked() -> bool {
+    session_vault().is_ok_and(|vault| vault.is_unlocked())
+}
+// Locks the vault after `seconds` without any vault call, 0 turns it off
+pub fn set_auto_lock_timeout(seconds: u64) -> Result<(), VaultError> {
+    catch_panic(|| {
+        set_auto_lock(seconds);
+        Ok(())
+    })
+}
+pub fn vault_events(sink: StreamSink<VaultEvent>) -> Result<(), VaultError> {
+    catch_panic(|| add_event_sink(sink))
 }
 pub fn check_password_exist(dir: &str) -> bool {
     check_validation_data_exists(dir)
 }
 pub fn is_video(image_data: Vec<u8>) -> Result<bool, VaultError> {
-    Ok(infer::is_video(&image_data))
+    catch_panic(|| Ok(infer::is_video(&image_data)))
 }
 
 pub fn create_dir(dir: String, album_name: String) -> Result<(), VaultError> {
-    match fs::create_dir(dir.clone() + "/" + &album_name) {
-        Ok(_) => Ok(()),
-        Err(e) => {
-            if e.kind() == io::ErrorKind::NotFound {
-                fs::create_dir_all(dir.clone()).map_err(|e| VaultError::Error(e.to_string()))?;
-                fs::create_dir(dir + "/" + &album_name)
-                    .map_err(|e| VaultError::Error(e.to_string()))?;
-                Ok(())
-            } else {
-                Err(VaultError::Error(e.to_string()))
-            }
-        }
-    }
+    session_root(&dir)?.create_album(album_name)
 }
 
 pub fn delete_dir(dir: &str) -> Result<(), VaultError> {
-    match remove_dir_all(&dir) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(VaultError::Error(e.to_string())),
-    }
+    session_vault()?.delete_album(dir.to_string())
 }
 
 pub fn get_dirs(dir: String) -> Result<Vec<String>, VaultError> {
-    match fs::read_dir(dir.clone()) {
-        Ok(entries) => {
-            let mut directories: Vec<String> = vec![];
-            for entry in entries {
-                match entry {
-                    Ok(entry) => {
-                        if entry.path().is_dir() {
-                            directories.push(entry.file_name().to_string_lossy().to_string());
-                        };
-                    }
-                    Err(e) => return Err(VaultError::Error(e.to_string())),
-                }
-            }
-            Ok(directories)
-        }
-        Err(_) => match fs::create_dir(dir) {
-            Ok(_) => Ok(vec![]),
-            Err(e) => Err(VaultError::Error(e.to_string())),
-        },
-    }
+    session_root(&dir)?.albums()
 }
 
 pub fn get_images(dir: String) -> Result<HashMap<String, (String, f32)>, VaultError> {
-    match fs::read_dir(&dir) {
-        Ok(entries) => {
-            let mut files: HashMap<String, (String, f32)> = HashMap::new();
-            for entry in entries {
-                match entry {
-                    Ok(entry) => {
-                        if entry.path().is_file() {
-                            let value = entry.file_name().to_string_lossy().to_string();
-                            let file_path = Path::new(&dir).join(".hash").join(&value);
-
-                            let buffer = fs::read(&file_path)
-                                .map_err(|e| VaultError::Error(e.to_string()))?;
-                            let contents = String::from_utf8(decrypt_data(&buffer)?)
-                                .map_err(|e| VaultError::Error(e.to_string()))?
-                                .split(" ")
-                                .map(|s| s.to_string())
-                                .collect::<Vec<String>>();
-
-                            let aspect_ratio: f32 = contents[1].parse().unwrap();
-                            let hash = contents[0].clone();
-
-                            files.insert(value, (hash, aspect_ratio));
-                        };
-                    }
-                    Err(e) => return Err(VaultError::Error(e.to_string())),
-                }
-            }
-            Ok(files)
-        }
-        Err(e) => Err(VaultError::Error(e.to_string())),
-    }
+    session_vault()?.get_images(dir)
 }
 
 pub fn get_album_thumb(dir: &str) -> Result<Option<HashMap<String, (String, f32)>>, VaultError> {
-    let images = get_images(dir.to_owned())?;
-    let smallest_entry = images.iter().min_by_key(|entry| entry.0.clone());
-
-    match smallest_entry {
-        Some((key, value)) => {
-            let mut map = HashMap::new();
-            map.insert(key.clone(), value.clone());
-            Ok(Some(map))
-        }
-        None => Ok(None),
-    }
+    session_vault()?.get_album_thumb(dir.to_string())
 }
 
 pub fn get_file_thumb(path: &str) -> Result<Vec<u8>, VaultError> {
-    let mut files_list = path
-        .to_string()
-        .split("/")
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
-    // let filename = files_list.pop().unwrap();
-    match files_list.pop() {
-        Some(filename) => {
-            let path = files_list.join("/") + "/.thumbs/" + &filename;
-
-            match File::open(path) {
-                Ok(file) => {
-                    let mut reader = BufReader::new(file);
-                    let mut buffer = Vec::new();
-                    match reader.read_to_end(&mut buffer) {
-                        Ok(_) => Ok(decrypt_data(&buffer)?),
-                        Err(e) => Err(VaultError::Error(e.to_string())),
-                    }
-                }
-                Err(e) => Err(VaultError::Error(e.to_string())),
-            }
-        }
-        None => Err(VaultError::Error("Invalid path".to_string())),
-    }
+    session_vault()?.get_file_thumb(path.to_string())
 }
 
 pub fn get_file(path: &str) -> Result<Vec<u8>, VaultError> {
-    match File::open(path) {
-        Ok(file) => {
-            let mut reader = BufReader::new(file);
-            let mut buffer = Vec::new();
-            match reader.read_to_end(&mut buffer) {
-                Ok(_) => Ok(decrypt_data(&buffer)?),
-                Err(e) => Err(VaultError::Error(e.to_string())),
-            }
-        }
-        Err(e) => Err(VaultError::Error(e.to_string())),
-    }
+    session_vault()?.get_file(path.to_string())
 }
 
-pub fn save_media(image_data: Vec<u8>, dir: String) -> Result<(), VaultError> {
-    let info = infer::get(&image_data);
-
-    let category = match info {
-        Some(t) if t.matcher_type() == infer::MatcherType::IMAGE => "image",
-        Some(t) if t.matcher_type() == infer::MatcherType::VIDEO => "video",
-        _ => "file",
-    };
-
-    let filename = generate_unique_filename(&dir, category);
-    let path = Path::new(&dir).join(&filename);
-
-    cache_image(
-        &image_data,
-        path.as_os_str().to_string_lossy().to_string(),
-        2,
-        2,
-    )?;
-    let encrypted_data = encrypt_data(&image_data)?;
-
-    match File::create(&path) {
-        Ok(mut file) => match file.write_all(&encrypted_data) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(VaultError::Error(e.to_string())),
-        },
-        Err(e) => Err(VaultError::Error(e.to_string())),
-    }
+pub fn read_range(path: &str, offset: u64, len: u64) -> Result<Vec<u8>, VaultError> {
+    session_vault()?.read_range(path.to_string(), offset, len)
 }
 
-pub fn save_file(image_data: Vec<u8>, dir: String) -> Result<(), VaultError> {
-    let path = Path::new(&dir).join(generate_unique_filename(&dir, "file"));
-    let encrypted_data = encrypt_data(&image_data)?;
-
-    match File::create(&path) {
-        Ok(mut file) => match file.write_all(&encrypted_data) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(VaultError::Error(e.to_string())),
-        },
-        Err(e) => Err(VaultError::Error(e.to_string())),
-    }
+// Sends the decrypted file to Dart one chunk at a time instead of as one buffer
+pub fn read_file_stream(path: String, sink: StreamSink<Vec<u8>>) -> Result<(), VaultError> {
+    session_vault()?.read_stream(path, sink)
 }
 
-pub fn delete_file(path: &str) -> Result<(), VaultError> {
-    let file_path = Path::new(path);
-    let parent_folder = file_path.parent().unwrap();
-    let file_name = file_path.file_name().unwrap();
-
-    let mut thumbs_file = PathBuf::from(parent_folder);
-    thumbs_file.push(".thumbs");
-    thumbs_file.push(file_name);
-
-    let mut hash_file = PathBuf::from(parent_folder);
-    hash_file.push(".hash");
-    hash_file.push(file_name);
+// URL a player can stream the file from, it stops working once the vault is locked
+pub fn media_url(path: &str) -> Result<String, VaultError> {
+    session_vault()?.media_url(path.to_string())
+}
 
-    remove_file(path).map_err(|e| VaultError::Error(e.to_string()))?;
+// Also expires every URL handed out so far
+pub fn stop_media_server() -> Result<(), VaultError> {
+    catch_panic(server::stop_server)
+}
 
-    if thumbs_file.exists() {
-        remove_file(&thumbs_file).map_err(|e| VaultError::Error(e.to_string()))?;
-    }
+pub fn save_media(
+    image_data: Vec<u8>,
+    dir: String,
+    source: Option<MediaSource>,
+) -> Result<(), VaultError> {
+    session_vault()?.save_media(image_data, dir, source)
+}
 
-    if hash_file.exists() {
-        remove_file(&hash_file).map_err(|e| VaultError::Error(e.to_string()))?;
-    }
+pub fn save_file(
+    image_data: Vec<u8>,
+    dir: String,
+    source: Option<MediaSource>,
+) -> Result<(), VaultError> {
+    session_vault()?.save_file(image_data, dir, source)
+}
 
-    Ok(())
+// Imports the file at `src_path` without copying it through Dart, see storage::import_path
+pub fn import_path(
+    src_path: String,
+    dir: String,
+    source: Option<MediaSource>,
+    secure_delete: bool,
+) -> Result<(), VaultError> {
+    session_vault()?.import_path(src_path, dir, source, secure_delete)
 }
 
-pub fn move_file(source_file: &str, dest_dir: &str) -> Result<(), VaultError> {
-    let source_path = Path::new(source_file);
-    let source_name = source_path.file_name().unwrap();
+// Imports several files into `dir`, see CancelToken for stopping it half way
+pub fn import_paths(
+    src_paths: Vec<String>,
+    dir: String,
+    secure_delete: bool,
+    progress: Option<StreamSink<ProgressEvent>>,
+    cancel: &CancelToken,
+) -> Result<(), VaultError> {
+    session_vault()?.import_paths(src_paths, dir, secure_delete, progress, cancel)
+}
 
-    let dest_dir_path = Path::new(dest_dir);
+// For data that isn't in a file Dart can pass to import_path, see MediaWriter
+pub fn open_media_writer(
+    dir: String,
+    source: Option<MediaSource>,
+) -> Result<MediaWriter, VaultError> {
+    session_vault()?.open_writer(dir, source)
+}
 
-    let mut dest_file = PathBuf::from(dest_dir_path);
-    dest_file.push(source_name);
+pub fn get_media_info(path: &str) -> Result<MediaInfo, VaultError> {
+    session_vault()?.get_media_info(path.to_string())
+}
 
-    let parent_folder = source_path.parent().unwrap();
+pub fn delete_file(path: &str) -> Result<(), VaultError> {
+    session_vault()?.delete_file(path.to_string())
+}
 
-    let mut thumbs_file = PathBuf::from(parent_folder);
-    thumbs_file.push(".thumbs");
-    thumbs_file.push(source_name);
+pub fn move_file(source_file: &str, dest_dir: &str) -> Result<(), VaultError> {
+    session_vault()?.move_file(source_file.to_string(), dest_dir.to_string())
+}
 
-    let mut dest_thumb_file = PathBuf::from(dest_dir_path);
-    dest_thumb_file.push(".thumbs");
-    dest_thumb_file.push(source_name);
+// A token for one of the operations that take a CancelToken
+pub fn create_cancel_token() -> CancelToken {
+    CancelToken::new()
+}
 
-    let mut hash_file = PathBuf::from(parent_folder);
-    hash_file.push(".hash");
-    hash_file.push(source_name);
+// The operation stops at the next file or chunk and fails with VaultError::Cancelled
+pub fn cancel_operation(token: &CancelToken) {
+    token.cancel();
+}
 
-    let mut dest_hash_file = PathBuf::from(dest_dir_path);
-    dest_hash_file.push(".hash");
-    dest_hash_file.push(source_name);
+pub fn zip_backup(
+    root_dir: &str,
+    save_path: &str,
+    encryption: bool,
+    progress: Option<StreamSink<ProgressEvent>>,
+    cancel: &CancelToken,
+) -> Result<(), VaultError> {
+    session_root(root_dir)?.zip_backup(save_path.to_string(), encryption, progress, cancel)
+}
 
-    rename_with_parent(source_path, dest_file.as_path())?;
-    if thumbs_file.exists() {
-        rename_with_parent(thumbs_file.as_path(), dest_thumb_file.as_path())?;
-    }
-    if hash_file.exists() {
-        rename_with_parent(hash_file.as_path(), dest_hash_file.as_path())?;
-    }
+pub fn restore_backup(
+    root_dir: &str,
+    zip_path: &str,
+    password: Option<String>,
+    duplicates: DuplicatePolicy,
+    progress: Option<StreamSink<ProgressEvent>>,
+    cancel: &CancelToken,
+) -> Result<RestoreSummary, VaultError> {
+    session_root(root_dir)?.restore_backup(
+        zip_path.to_string(),
+        password,
+        duplicates,
+        progress,
+        cancel,
+    )
+}
 
-    Ok(())
+// Restores only the albums and entries in `selection`, names as given by list_backup
+pub fn restore_backup_selection(
+    root_dir: &str,
+    zip_path: &str,
+    password: Option<String>,
+    duplicates: DuplicatePolicy,
+    selection: BackupSelection,
+    progress: Option<StreamSink<ProgressEvent>>,
+    cancel: &CancelToken,
+) -> Result<RestoreSummary, VaultError> {
+    session_root(root_dir)?.restore_backup_selection(
+        zip_path.to_string(),
+        password,
+        duplicates,
+        selection,
+        progress,
+        cancel,
+    )
 }
 
-pub fn zip_backup(root_dir: &str, save_path: &str, encryption: bool) -> Result<(), VaultError> {
-    const SKIP_PATTERNS: &[&str] = &[".hash", ".thumbs"];
-
-    let mut buffer = Cursor::new(Vec::new());
-    let mut zip = ZipWriter::new(&mut buffer);
-    let options = FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
-    let src_path = Path::new(root_dir);
-
-    for entry in WalkDir::new(src_path) {
-        let entry = entry.unwrap();
-        let path = entry.path();
-        let name = path.strip_prefix(src_path).unwrap();
-
-        // Skip .hash and .thumb folders and anything inside them
-        if path.components().any(|component| {
-            if let Some(component_str) = component.as_os_str().to_str() {
-                SKIP_PATTERNS
-                    .iter()
-                    .any(|&pattern| component_str == pattern)
-            } else {
-                false
-            }
-        }) {
-            continue;
-        }
+// Albums and media of a backup with their sizes, to choose what restore_backup_selection restores
+pub fn list_backup(zip_path: &str) -> Result<BackupContents, VaultError> {
+    catch_panic(|| backup::list_backup(zip_path))
+}
 
-        if path.is_file() {
-            zip.start_file(name.to_string_lossy(), options)
-                .map_err(|e| VaultError::Error(e.to_string()))?;
-
-            if encryption {
-                match File::open(path.to_str().unwrap()) {
-                    Ok(file) => {
-                        let mut reader = BufReader::new(file);
-                        let mut buffer = Vec::new();
-                        match reader.read_to_end(&mut buffer) {
-                            Ok(_) => zip
-                                .write_all(&buffer)
-                                .map_err(|e| VaultError::Error(format!("{:?}", e)))?,
-                            Err(e) => return Err(VaultError::Error(e.to_string())),
-                        }
-                    }
-                    Err(e) => return Err(VaultError::Error(e.to_string())),
-                }
-            } else {
-                let decrypted_data = get_file(path.to_str().unwrap())?;
-                zip.write_all(&decrypted_data)
-                    .map_err(|e| VaultError::Error(format!("{:?}", e)))?;
-            }
-        } else if !name.as_os_str().is_empty() {
-            zip.add_directory(name.to_string_lossy(), options)
-                .map_err(|e| VaultError::Error(e.to_string()))?;
+pub fn check_zip_password(zip_path: &str, password: &str) -> Result<bool, VaultError> {
+    catch_panic(|| {
+        let zip = File::open(zip_path).map_err(|e| VaultError::io(e, zip_path))?;
+        let reader = BufReader::new(zip);
+        let mut archive = ZipArchive::new(reader).map_err(|e| VaultError::zip(e, zip_path))?;
+
+        match verify_and_get_decrypter(&mut archive, password) {
+            Ok(_) => Ok(true),
+            Err(VaultError::IncorrectPassword) => Ok(false),
+            Err(e) => Err(e),
         }
-    }
-
-    drop(zip);
+    })
+}
 
-    match File::create(save_path) {
-        Ok(mut file) => file
-            .write_all(&buffer.into_inner())
-            .map_err(|e| VaultError::Error(e.to_string())),
-        Err(e) => Err(VaultError::Error(e.to_string())),
-    }
+pub fn check_zip_encrypted(zip_path: &str) -> Result<bool, VaultError> {
+    catch_panic(|| {
+        let zip = File::open(zip_path).map_err(|e| VaultError::io(e, zip_path))?;
+        let reader = BufReader::new(zip);
+        let mut archive = ZipArchive::new(reader).map_err(|e| VaultError::zip(e, zip_path))?;
+
+        let mut file = match archive.by_name(VAULT_FILE) {
+            Ok(file_in_zip) => file_in_zip,
+            Err(_) => return Ok(false),
+        };
+
+        let mut content = Vec::new();
+        file.read_to_end(&mut content)
+            .map_err(|e| VaultError::io(e, VAULT_FILE))?;
+
+        Ok(content != VERIFICATION_DATA)
+    })
 }
 
-pub fn restore_backup(
-    root_dir: &str,
-    zip_path: &str,
-    password: Option<String>,
-) -> Result<(), VaultError> {
-    const SKIP_PATTERNS: &[&str] = &[".hash", ".thumbs", ".vault-key"];
-
-    let zip = File::open(zip_path)
-        .map_err(|e| VaultError::Error(format!("Failed to open ZIP file: {}", e)))?;
-    let reader = BufReader::new(zip);
-    let root_dir_path = Path::new(root_dir);
-
-    let mut archive = ZipArchive::new(reader)
-        .map_err(|e| VaultError::Error(format!("Failed to open ZIP archive: {}", e)))?;
-    fs::create_dir_all(root_dir_path)
-        .map_err(|e| VaultError::Error(format!("Failed to create target directory: {}", e)))?;
-
-    // let decrypter = password.as_deref().map(PasswordDecrypter::new);
-    let decrypter = if let Some(pass) = password {
-        Some(verify_and_get_decrypter(&mut archive, &pass)?)
-    } else {
-        None
-    };
-
-    for i in 0..archive.len() {
-        let mut file = archive
-            .by_index(i)
-            .map_err(|e| VaultError::Error(format!("Failed to access file in archive: {}", e)))?;
-
-        let outpath = root_dir_path.join(file.name());
-
-        // Skip files with .cache .hash .vault-key
-        if SKIP_PATTERNS
-            .iter()
-            .any(|&pattern| file.name().contains(pattern))
-        {
-            continue;
-        }
+// Format, KDF parameters, albums and entries of a backup, without needing its password
+pub fn inspect_backup(zip_path: &str) -> Result<BackupManifest, VaultError> {
+    catch_panic(|| backup::inspect_backup(zip_path))
+}
 
-        if file.name().ends_with('/') {
-            // Its a directory
-            fs::create_dir_all(&outpath).map_err(|e| {
-                VaultError::Error(format!(
-                    "Failed to create directory {}: {}",
-                    outpath.display(),
-                    e
-                ))
-            })?;
-        } else {
-            // Its a file
-
-            // Creating parent directory if its not exists
-            if let Some(parent) = outpath.parent() {
-                fs::create_dir_all(parent).map_err(|e| {
-                    VaultError::Error(format!("Failed to create parent directory: {}", e))
-                })?;
-            }
-
-            // Get file data
-            let mut file_content = Vec::new();
-            file.read_to_end(&mut file_content).map_err(|e| {
-                VaultError::Error(format!("Failed to read file from archive: {}", e))
-            })?;
-
-            if let Some(ref decrypter) = decrypter {
-                let decrypted_data = decrypter.decrypt(&file_content)?;
-                if let Some(parent_dir) = outpath.parent() {
-                    save_media(decrypted_data, parent_dir.to_string_lossy().to_string())?;
-                }
-            } else {
-                if let Some(parent_dir) = outpath.parent() {
-                    save_media(file_content, parent_dir.to_string_lossy().to_string())?;
-                }
-            }
-        }
-    }
+// Checks every entry of a backup without restoring anything, encrypted backups need the
+// password their vault had when the backup was made
+pub fn verify_backup(zip_path: &str, password: Option<String>) -> Result<BackupReport, VaultError> {
+    catch_panic(|| backup::verify_backup(zip_path, password))
+}
 
+// Opens the session vault with the key set_password or save_password just unlocked. Its
+// handle key is registered like any other, so lock_vault and the auto-lock lock it too,
+// and it's the only place the session keeps the key.
+fn start_session(dir: &str, key: CryptoParams) -> Result<(), VaultError> {
+    let vault = Vault::new(dir.to_string(), key);
+    *SESSION_VAULT.write().map_err(|e| VaultError::Internal {
+        message: e.to_string(),
+    })? = Some(Arc::new(vault));
     Ok(())
 }
 
-pub fn check_zip_password(zip_path: &str, password: &str) -> Result<bool, VaultError> {
-    let zip = File::open(zip_path)
-        .map_err(|e| VaultError::Error(format!("Failed to open ZIP file: {}", e)))?;
-    let reader = BufReader::new(zip);
-    let mut archive = ZipArchive::new(reader)
-        .map_err(|e| VaultError::Error(format!("Failed to open ZIP archive: {}", e)))?;
-
-    match verify_and_get_decrypter(&mut archive, password) {
-        Ok(_) => Ok(true),
-        Err(VaultError::IncorrectPassword) => Ok(false),
-        Err(e) => Err(e),
-    }
+fn session_vault() -> Result<Arc<Vault>, VaultError> {
+    SESSION_VAULT
+        .read()
+        .map_err(|e| VaultError::Internal {
+            message: e.to_string(),
+        })?
+        .clone()
+        .ok_or(VaultError::Locked)
 }
 
-pub fn check_zip_encrypted(zip_path: &str) -> Result<bool, VaultError> {
-    let zip = File::open(zip_path)
-        .map_err(|e| VaultError::Error(format!("Failed to open ZIP file: {}", e)))?;
-    let reader = BufReader::new(zip);
-    let mut archive = ZipArchive::new(reader)
-        .map_err(|e| VaultError::Error(format!("Failed to open ZIP archive: {}", e)))?;
-
-    let mut file = match archive.by_name(VAULT_FILE) {
-        Ok(file_in_zip) => file_in_zip,
-        Err(_) => return Ok(false),
-    };
-
-    let mut content = Vec::new();
-    file.read_to_end(&mut content)
-        .map_err(|e| VaultError::Error(format!("Failed to read .vault-key content: {}", e)))?;
-
-    Ok(content != VERIFICATION_DATA)
+// For the functions that still name the vault root, it has to be the session vault's
+fn session_root(dir: &str) -> Result<Arc<Vault>, VaultError> {
+    let vault = session_vault()?;
+    if Path::new(dir) != Path::new(&vault.root()) {
+        return Err(VaultError::PathOutsideVault {
+            path: dir.to_string(),
+        });
+    }
+    Ok(vault)
 }
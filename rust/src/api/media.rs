@@ -0,0 +1,106 @@
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+use flutter_rust_bridge::frb;
+
+// Custom error
+use crate::utils::error::{catch_panic, VaultError};
+// Encrytion
+use crate::utils::encryption::CryptoParams;
+// Session
+use crate::utils::session::{record_activity, register_handle_key};
+// Vault operations
+use crate::utils::storage::PendingMedia;
+
+// Runs once the file is in its album, Vault handles use it to drop their cached images
+type OnFinish = Box<dyn FnOnce() + Send>;
+
+// A file streamed in from Dart piece by piece, for data that doesn't come from a file
+// import_path could read. Nothing shows up in the album until finish is called.
+#[frb(opaque)]
+pub struct MediaWriter {
+    pending: Mutex<Option<PendingMedia>>,
+    on_finish: Mutex<Option<OnFinish>>,
+    key: Arc<RwLock<Option<CryptoParams>>>,
+}
+
+impl MediaWriter {
+    pub(crate) fn new(
+        pending: PendingMedia,
+        key: CryptoParams,
+        on_finish: Option<OnFinish>,
+    ) -> Self {
+        let key = Arc::new(RwLock::new(Some(key)));
+        // Locking the vault also stops writers that are still open
+        register_handle_key(&key);
+
+        MediaWriter {
+            pending: Mutex::new(Some(pending)),
+            on_finish: Mutex::new(on_finish),
+            key,
+        }
+    }
+
+    pub fn write(&self, data: Vec<u8>) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let mut pending = self.pending()?;
+            if let Err(e) = self.key() {
+                // The part file goes away with the pending media
+                pending.take();
+                return Err(e);
+            }
+            pending.as_mut().ok_or_else(closed)?.write(&data)
+        })
+    }
+
+    pub fn finish(&self) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let media = self.pending()?.take().ok_or_else(closed)?;
+            media.finish(&self.key()?)?;
+
+            let on_finish = self
+                .on_finish
+                .lock()
+                .map_err(|e| VaultError::Internal {
+                    message: e.to_string(),
+                })?
+                .take();
+            if let Some(on_finish) = on_finish {
+                on_finish();
+            }
+            Ok(())
+        })
+    }
+
+    // Throws away everything written so far
+    pub fn abort(&self) -> Result<(), VaultError> {
+        catch_panic(|| {
+            self.pending()?.take();
+            Ok(())
+        })
+    }
+
+    fn pending(&self) -> Result<MutexGuard<'_, Option<PendingMedia>>, VaultError> {
+        self.pending.lock().map_err(|e| VaultError::Internal {
+            message: e.to_string(),
+        })
+    }
+
+    fn key(&self) -> Result<CryptoParams, VaultError> {
+        let key = self
+            .key
+            .read()
+            .map_err(|e| VaultError::Internal {
+                message: e.to_string(),
+            })?
+            .clone()
+            .ok_or(VaultError::Locked)?;
+        record_activity();
+        Ok(key)
+    }
+}
+
+fn closed() -> VaultError {
+    VaultError::InvalidInput {
+        message: "The writer was already finished or aborted".to_string(),
+    }
+}
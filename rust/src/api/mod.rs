@@ -1,2 +1,3 @@
 pub mod file;
-
+pub mod media;
+pub mod vault;
@@ -0,0 +1,529 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, RwLock},
+};
+
+use flutter_rust_bridge::frb;
+
+use crate::frb_generated::StreamSink;
+
+// Custom error
+use crate::utils::error::{catch_panic, VaultError};
+// Encrytion
+use crate::utils::encryption::{
+    check_validation_data_exists, create_vault_key, open_vault_key, CryptoParams,
+};
+// Session
+use crate::utils::session::{record_activity, register_handle_key};
+// Album index
+use crate::utils::index::forget_indexes;
+// Vault operations
+use crate::api::media::MediaWriter;
+use crate::utils::backup::{self, BackupSelection, DuplicatePolicy, RestoreSummary};
+use crate::utils::storage::{self, MediaInfo, MediaSource};
+// Media server
+use crate::utils::server;
+// Progress
+use crate::utils::progress::{CancelToken, Progress, ProgressEvent};
+// Utils
+use crate::utils::utils::resolve_in_vault;
+// Key derivation
+use crate::utils::kdf::KdfConfig;
+
+// Blurhash and aspect ratio of every image in an album
+type AlbumImages = HashMap<String, (String, f32)>;
+
+// An unlocked vault. Owns its root directory and key, albums and media are
+// addressed relative to the root so several vaults can be open at once.
+#[frb(opaque)]
+pub struct Vault {
+    root: PathBuf,
+    key: Arc<RwLock<Option<CryptoParams>>>,
+    // Shared with the writers of this vault, which clear it once they finish
+    images_cache: Arc<Mutex<HashMap<String, AlbumImages>>>,
+}
+
+impl Vault {
+    pub fn open(root_dir: String, password: String) -> Result<Vault, VaultError> {
+        catch_panic(|| match open_vault_key(&root_dir, &password)? {
+            Some(key) => Ok(Vault::new(root_dir, key)),
+            None => Err(VaultError::IncorrectPassword),
+        })
+    }
+
+    pub fn create(root_dir: String, password: String) -> Result<Vault, VaultError> {
+        catch_panic(|| {
+            if check_validation_data_exists(&root_dir) {
+                return Err(VaultError::AlreadyExists { path: root_dir });
+            }
+            let key = create_vault_key(&root_dir, &password, &KdfConfig::default())?;
+            Ok(Vault::new(root_dir, key))
+        })
+    }
+
+    // Also how api::file opens its session vault, with the key set_password unlocked
+    pub(crate) fn new(root_dir: String, key: CryptoParams) -> Vault {
+        let key = Arc::new(RwLock::new(Some(key)));
+        // lock_vault and the auto-lock also lock every open handle
+        register_handle_key(&key);
+        record_activity();
+
+        Vault {
+            root: PathBuf::from(root_dir),
+            key,
+            images_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // Forgets the key, every call on this handle fails with VaultError::Locked afterwards
+    pub fn lock(&self) -> Result<(), VaultError> {
+        catch_panic(|| {
+            self.key
+                .write()
+                .map_err(|e| VaultError::Internal {
+                    message: e.to_string(),
+                })?
+                .take();
+            self.clear_cache(None);
+            forget_indexes();
+            server::forget_expired_tokens();
+            Ok(())
+        })
+    }
+
+    pub fn is_unlocked(&self) -> bool {
+        self.key.read().map(|key| key.is_some()).unwrap_or(false)
+    }
+
+    pub fn root(&self) -> String {
+        self.root.to_string_lossy().to_string()
+    }
+
+    pub fn albums(&self) -> Result<Vec<String>, VaultError> {
+        catch_panic(|| {
+            self.key()?;
+            storage::get_dirs(self.root())
+        })
+    }
+
+    pub fn create_album(&self, name: String) -> Result<(), VaultError> {
+        catch_panic(|| {
+            self.key()?;
+            self.resolve(&name)?;
+            storage::create_dir(self.root(), name)
+        })
+    }
+
+    pub fn delete_album(&self, album: String) -> Result<(), VaultError> {
+        catch_panic(|| {
+            self.key()?;
+            storage::delete_dir(&self.resolve(&album)?)?;
+            self.clear_cache(Some(&album));
+            Ok(())
+        })
+    }
+
+    pub fn get_images(&self, album: String) -> Result<HashMap<String, (String, f32)>, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            let dir = self.resolve(&album)?;
+            if let Some(images) = self.cached_images(&dir) {
+                return Ok(images);
+            }
+
+            let images = storage::get_images(dir.clone(), &key)?;
+            if let Ok(mut cache) = self.images_cache.lock() {
+                cache.insert(dir, images.clone());
+            }
+            Ok(images)
+        })
+    }
+
+    pub fn get_album_thumb(
+        &self,
+        album: String,
+    ) -> Result<Option<HashMap<String, (String, f32)>>, VaultError> {
+        catch_panic(|| {
+            let images = self.get_images(album)?;
+            let smallest_entry = images.into_iter().min_by_key(|entry| entry.0.clone());
+
+            Ok(smallest_entry.map(|(name, value)| HashMap::from([(name, value)])))
+        })
+    }
+
+    pub fn get_file(&self, path: String) -> Result<Vec<u8>, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::get_file(&self.resolve(&path)?, &key)
+        })
+    }
+
+    pub fn get_file_thumb(&self, path: String) -> Result<Vec<u8>, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::get_file_thumb(&self.resolve(&path)?, &key)
+        })
+    }
+
+    pub fn read_range(&self, path: String, offset: u64, len: u64) -> Result<Vec<u8>, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::read_range(&self.resolve(&path)?, offset, len, &key)
+        })
+    }
+
+    // Sends the decrypted file to Dart one chunk at a time instead of as one buffer
+    pub fn read_stream(&self, path: String, sink: StreamSink<Vec<u8>>) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::stream_file(&self.resolve(&path)?, &key, |chunk| {
+                // Stops once the vault is locked, and keeps it from auto-locking while reading
+                self.key()?;
+                sink.add(chunk).map_err(|e| VaultError::Internal {
+                    message: e.to_string(),
+                })
+            })
+        })
+    }
+
+    // URL a player can stream the file from, it stops working once this handle is locked
+    pub fn media_url(&self, path: String) -> Result<String, VaultError> {
+        catch_panic(|| {
+            self.key()?;
+            server::media_url(Path::new(&self.resolve(&path)?), self.key.clone())
+        })
+    }
+
+    pub fn save_media(
+        &self,
+        data: Vec<u8>,
+        album: String,
+        source: Option<MediaSource>,
+    ) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::save_media(data, self.resolve(&album)?, source, &key)?;
+            self.clear_cache(Some(&album));
+            Ok(())
+        })
+    }
+
+    pub fn save_file(
+        &self,
+        data: Vec<u8>,
+        album: String,
+        source: Option<MediaSource>,
+    ) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::save_file(data, self.resolve(&album)?, source, &key)?;
+            self.clear_cache(Some(&album));
+            Ok(())
+        })
+    }
+
+    // Imports the file at `src_path` without copying it through Dart, see storage::import_path
+    pub fn import_path(
+        &self,
+        src_path: String,
+        album: String,
+        source: Option<MediaSource>,
+        secure_delete: bool,
+    ) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::import_path(
+                &src_path,
+                self.resolve(&album)?,
+                source,
+                secure_delete,
+                &key,
+                &mut Progress::none(),
+            )?;
+            self.clear_cache(Some(&album));
+            Ok(())
+        })
+    }
+
+    // Imports several files into `album`, see CancelToken for stopping it half way
+    pub fn import_paths(
+        &self,
+        src_paths: Vec<String>,
+        album: String,
+        secure_delete: bool,
+        progress: Option<StreamSink<ProgressEvent>>,
+        cancel: &CancelToken,
+    ) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            let imported = storage::import_paths(
+                &src_paths,
+                self.resolve(&album)?,
+                secure_delete,
+                &key,
+                &mut Progress::new(progress, Some(cancel)),
+            );
+            // A cancelled import still added the files before it
+            self.clear_cache(Some(&album));
+            imported
+        })
+    }
+
+    // For data that isn't in a file Dart can pass to import_path, see MediaWriter
+    pub fn open_writer(
+        &self,
+        album: String,
+        source: Option<MediaSource>,
+    ) -> Result<MediaWriter, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            let dir = self.resolve(&album)?;
+            let pending = storage::begin_media(dir.clone(), source, &key)?;
+
+            let cache = self.images_cache.clone();
+            let on_finish = Box::new(move || {
+                if let Ok(mut cache) = cache.lock() {
+                    cache.remove(&dir);
+                }
+            });
+            Ok(MediaWriter::new(pending, key, Some(on_finish)))
+        })
+    }
+
+    pub fn get_media_info(&self, path: String) -> Result<MediaInfo, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::get_media_info(&self.resolve(&path)?, &key)
+        })
+    }
+
+    pub fn delete_file(&self, path: String) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::delete_file(&self.resolve(&path)?, &key)?;
+            self.clear_cache(album_of(&path).as_deref());
+            Ok(())
+        })
+    }
+
+    pub fn move_file(&self, path: String, dest_album: String) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            storage::move_file(&self.resolve(&path)?, &self.resolve(&dest_album)?, &key)?;
+            self.clear_cache(album_of(&path).as_deref());
+            self.clear_cache(Some(&dest_album));
+            Ok(())
+        })
+    }
+
+    pub fn zip_backup(
+        &self,
+        save_path: String,
+        encryption: bool,
+        progress: Option<StreamSink<ProgressEvent>>,
+        cancel: &CancelToken,
+    ) -> Result<(), VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            backup::zip_backup(
+                &self.root(),
+                &save_path,
+                encryption,
+                &key,
+                &mut Progress::new(progress, Some(cancel)),
+            )
+        })
+    }
+
+    pub fn restore_backup(
+        &self,
+        zip_path: String,
+        password: Option<String>,
+        duplicates: DuplicatePolicy,
+        progress: Option<StreamSink<ProgressEvent>>,
+        cancel: &CancelToken,
+    ) -> Result<RestoreSummary, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            let restored = backup::restore_backup(
+                &self.root(),
+                &zip_path,
+                password,
+                None,
+                duplicates,
+                &key,
+                &mut Progress::new(progress, Some(cancel)),
+            );
+            // A cancelled restore still added the entries before it
+            self.clear_cache(None);
+            restored
+        })
+    }
+
+    // Restores only the albums and entries in `selection`, see list_backup
+    pub fn restore_backup_selection(
+        &self,
+        zip_path: String,
+        password: Option<String>,
+        duplicates: DuplicatePolicy,
+        selection: BackupSelection,
+        progress: Option<StreamSink<ProgressEvent>>,
+        cancel: &CancelToken,
+    ) -> Result<RestoreSummary, VaultError> {
+        catch_panic(|| {
+            let key = self.key()?;
+            let restored = backup::restore_backup(
+                &self.root(),
+                &zip_path,
+                password,
+                Some(&selection),
+                duplicates,
+                &key,
+                &mut Progress::new(progress, Some(cancel)),
+            );
+            self.clear_cache(None);
+            restored
+        })
+    }
+
+    // Copy of the key for one call, also counts as activity for the auto-lock
+    fn key(&self) -> Result<CryptoParams, VaultError> {
+        let key = self
+            .key
+            .read()
+            .map_err(|e| VaultError::Internal {
+                message: e.to_string(),
+            })?
+            .clone()
+            .ok_or(VaultError::Locked)?;
+        record_activity();
+        Ok(key)
+    }
+
+    // Every path from Dart goes through here, see resolve_in_vault
+    fn resolve(&self, path: &str) -> Result<String, VaultError> {
+        Ok(resolve_in_vault(&self.root, path)?
+            .to_string_lossy()
+            .to_string())
+    }
+
+    // Cached by the resolved directory, albums can be named relative to the root or not
+    fn cached_images(&self, dir: &str) -> Option<AlbumImages> {
+        self.images_cache.lock().ok()?.get(dir).cloned()
+    }
+
+    // Drops the cached images of one album, or of every album
+    fn clear_cache(&self, album: Option<&str>) {
+        if let Ok(mut cache) = self.images_cache.lock() {
+            match album {
+                Some(album) => {
+                    if let Ok(dir) = self.resolve(album) {
+                        cache.remove(&dir);
+                    }
+                }
+                None => cache.clear(),
+            }
+        }
+    }
+}
+
+fn album_of(path: &str) -> Option<String> {
+    Path::new(path)
+        .parent()
+        .map(|parent| parent.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::utils::encryption::VAULT_FILE;
+    use crate::utils::testing::{vault, TempDir, PASSWORD, SESSION_KEYS};
+    use crate::utils::utils::is_internal_name;
+
+    // Names of the media saved in `album`
+    fn media(root: &TempDir, album: &str) -> Vec<String> {
+        fs::read_dir(root.path().join(album))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .filter(|name| !is_internal_name(name))
+            .map(|name| name.to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn create_and_open() {
+        let _keys = SESSION_KEYS.read().unwrap_or_else(|e| e.into_inner());
+        let root = TempDir::new("vault_create");
+        let created = Vault::create(root.join(""), PASSWORD.to_string()).unwrap();
+        assert!(created.is_unlocked());
+        assert!(root.path().join(VAULT_FILE).is_file());
+        assert!(matches!(
+            Vault::create(root.join(""), "other".to_string()),
+            Err(VaultError::AlreadyExists { .. })
+        ));
+
+        assert!(matches!(
+            Vault::open(root.join(""), "wrong".to_string()),
+            Err(VaultError::IncorrectPassword)
+        ));
+        let opened = Vault::open(root.join(""), PASSWORD.to_string()).unwrap();
+        assert!(opened.is_unlocked());
+        assert!(opened.albums().unwrap().is_empty());
+    }
+
+    #[test]
+    fn save_and_get() {
+        let _keys = SESSION_KEYS.read().unwrap_or_else(|e| e.into_inner());
+        let (root, key) = vault("vault_save");
+        let vault = Vault::new(root.join(""), key);
+        vault
+            .save_file(b"sand".to_vec(), "album".to_string(), None)
+            .unwrap();
+        assert_eq!(vault.albums().unwrap(), vec!["album".to_string()]);
+
+        let names = media(&root, "album");
+        assert_eq!(names.len(), 1);
+        let relative = format!("album/{}", names[0]);
+        assert_eq!(vault.get_file(relative.clone()).unwrap(), b"sand");
+        // Full paths into the root work as well
+        assert_eq!(vault.get_file(root.join(&relative)).unwrap(), b"sand");
+        assert_eq!(
+            vault.read_range(relative.clone(), 1, 2).unwrap(),
+            b"an".to_vec()
+        );
+        assert_eq!(vault.get_media_info(relative).unwrap().size, Some(4));
+
+        assert!(matches!(
+            vault.get_file("../outside".to_string()),
+            Err(VaultError::PathOutsideVault { .. })
+        ));
+        assert!(matches!(
+            vault.get_file(VAULT_FILE.to_string()),
+            Err(VaultError::PathOutsideVault { .. })
+        ));
+    }
+
+    #[test]
+    fn lock_forgets_the_key() {
+        let _keys = SESSION_KEYS.read().unwrap_or_else(|e| e.into_inner());
+        let (root, key) = vault("vault_lock");
+        let vault = Vault::new(root.join(""), key);
+        vault
+            .save_file(b"sand".to_vec(), "album".to_string(), None)
+            .unwrap();
+        let path = format!("album/{}", media(&root, "album")[0]);
+
+        vault.lock().unwrap();
+        assert!(!vault.is_unlocked());
+        assert!(matches!(vault.get_file(path), Err(VaultError::Locked)));
+        assert!(matches!(vault.albums(), Err(VaultError::Locked)));
+        assert!(matches!(
+            vault.save_file(b"more".to_vec(), "album".to_string(), None),
+            Err(VaultError::Locked)
+        ));
+        assert_eq!(media(&root, "album").len(), 1);
+    }
+}
@@ -0,0 +1,1362 @@
+// Backups of the whole vault as zip archives and restoring them
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    fs::{self, remove_file, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use walkdir::WalkDir;
+use zip::{write::FileOptions, ZipArchive, ZipWriter};
+
+// Custom error
+use crate::utils::{
+    encryption::{VAULT_FILE, VERIFICATION_DATA},
+    error::VaultError,
+    utils::verify_and_get_decrypter,
+};
+// Encrytion
+use crate::utils::encryption::{
+    validation_kdf_params, CryptoParams, PasswordDecrypter, CHUNK_SIZE,
+};
+// Progress
+use crate::utils::progress::Progress;
+// Backup manifest
+use crate::utils::manifest::{
+    open_name, seal_name, BackupEncryption, BackupManifest, EntryHasher, HashReader, ManifestEntry,
+    MANIFEST_FILE,
+};
+// Streaming encryption
+use crate::utils::stream::decrypt_reader;
+// Album index
+use crate::utils::index::{lock_indexes, MediaEntry, INDEX_FILE};
+// Storage
+use crate::utils::storage::{begin_media, delete_file, is_part_file, stream_file, MediaSource};
+// Utils
+use crate::utils::utils::resolve_in_vault;
+
+// Entries are streamed into a hidden file next to `save_path` and renamed once the archive
+// is complete, so memory use doesn't grow with the vault and a failed or cancelled backup
+// never leaves a half written archive behind.
+pub fn zip_backup(
+    root_dir: &str,
+    save_path: &str,
+    encryption: bool,
+    key: &CryptoParams,
+    progress: &mut Progress,
+) -> Result<(), VaultError> {
+    const SKIP_PATTERNS: &[&str] = &[".hash", ".thumbs", INDEX_FILE];
+
+    let src_path = Path::new(root_dir);
+
+    // Listed up front so progress knows the totals
+    let mut entries = Vec::new();
+    for entry in WalkDir::new(src_path) {
+        let entry = entry.map_err(|e| {
+            let path = e.path().unwrap_or(src_path).to_path_buf();
+            VaultError::io(e.into(), path)
+        })?;
+
+        // Skip .hash and .thumb folders and anything inside them
+        if entry.path().components().any(|component| {
+            if let Some(component_str) = component.as_os_str().to_str() {
+                SKIP_PATTERNS
+                    .iter()
+                    .any(|&pattern| component_str == pattern)
+            } else {
+                false
+            }
+        }) {
+            continue;
+        }
+        // Unfinished files of a MediaWriter
+        if is_part_file(entry.path()) {
+            continue;
+        }
+        entries.push(entry);
+    }
+    let files: Vec<_> = entries
+        .iter()
+        .filter(|entry| entry.path().is_file())
+        .collect();
+    let bytes_total = files
+        .iter()
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum();
+    progress.start(files.len() as u32, bytes_total);
+    progress.check()?;
+
+    let mut manifest = if encryption {
+        BackupManifest::new(BackupEncryption::VaultKey, validation_kdf_params(root_dir)?)
+    } else {
+        BackupManifest::new(BackupEncryption::Plain, Vec::new())
+    };
+
+    let save = Path::new(save_path);
+    let file_name = save.file_name().ok_or_else(|| VaultError::InvalidInput {
+        message: format!("{} is not a file path", save_path),
+    })?;
+    let temp_path = save.with_file_name(format!(".{}.part", file_name.to_string_lossy()));
+
+    let written = File::create(&temp_path)
+        .map_err(|e| VaultError::io(e, &temp_path))
+        .and_then(|file| {
+            let mut zip = ZipWriter::new(BufWriter::new(file));
+            write_backup(
+                &mut zip,
+                &entries,
+                src_path,
+                &temp_path,
+                &mut manifest,
+                key,
+                progress,
+            )?;
+
+            // Last, so it can hold the hash of everything before it
+            let options =
+                FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+            zip.start_file(MANIFEST_FILE, options)
+                .map_err(|e| VaultError::zip(e, &temp_path))?;
+            zip.write_all(&manifest.to_bytes()?)
+                .map_err(|e| VaultError::io(e, &temp_path))?;
+            zip.finish()
+                .map_err(|e| VaultError::zip(e, &temp_path))?
+                .into_inner()
+                .map_err(|e| VaultError::io(e.into_error(), &temp_path))?
+                .sync_all()
+                .map_err(|e| VaultError::io(e, &temp_path))
+        })
+        .and_then(|_| fs::rename(&temp_path, save).map_err(|e| VaultError::io(e, save)));
+
+    if written.is_err() {
+        let _ = remove_file(&temp_path);
+    }
+    written
+}
+
+fn write_backup<W: Write + io::Seek>(
+    zip: &mut ZipWriter<W>,
+    entries: &[walkdir::DirEntry],
+    src_path: &Path,
+    zip_path: &Path,
+    manifest: &mut BackupManifest,
+    key: &CryptoParams,
+    progress: &mut Progress,
+) -> Result<(), VaultError> {
+    let options = FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    let encryption = manifest.encryption == BackupEncryption::VaultKey;
+
+    for entry in entries {
+        let path = entry.path();
+        let name = path
+            .strip_prefix(src_path)
+            .map_err(|_| VaultError::PathOutsideVault {
+                path: path.to_string_lossy().to_string(),
+            })?;
+
+        if path.is_file() {
+            progress.begin_item(&name.to_string_lossy())?;
+            zip.start_file(zip_name(name), options)
+                .map_err(|e| VaultError::zip(e, zip_path))?;
+            let size = entry.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+            // Bytes are counted as read from the vault, a plain backup writes a bit less
+            let mut counted = 0;
+            let mut hasher = EntryHasher::new();
+
+            if name == Path::new(VAULT_FILE) && !encryption {
+                // Plain backups only mark themselves as unencrypted
+                zip.write_all(VERIFICATION_DATA)
+                    .map_err(|e| VaultError::io(e, zip_path))?;
+                hasher.update(VERIFICATION_DATA);
+            } else if encryption {
+                let mut reader = File::open(path).map_err(|e| VaultError::io(e, path))?;
+                let mut chunk = vec![0u8; CHUNK_SIZE];
+                loop {
+                    let read = match reader.read(&mut chunk) {
+                        Ok(0) => break,
+                        Ok(read) => read,
+                        Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                        Err(e) => return Err(VaultError::io(e, path)),
+                    };
+                    zip.write_all(&chunk[..read])
+                        .map_err(|e| VaultError::io(e, zip_path))?;
+                    hasher.update(&chunk[..read]);
+                    counted += read as u64;
+                    progress.add_bytes(read as u64)?;
+                }
+            } else {
+                stream_file(&path.to_string_lossy(), key, |chunk| {
+                    zip.write_all(&chunk)
+                        .map_err(|e| VaultError::io(e, zip_path))?;
+                    hasher.update(&chunk);
+                    let read = (chunk.len() as u64).min(size - counted);
+                    counted += read;
+                    progress.add_bytes(read)
+                })?;
+            }
+
+            progress.add_bytes(size.saturating_sub(counted))?;
+
+            let indexed = match path.parent() {
+                Some(dir) if name != Path::new(VAULT_FILE) => {
+                    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                    lock_indexes().open(dir, key)?.get(&file_name).cloned()
+                }
+                _ => None,
+            };
+            let (original_name, mime_type, modified_at) = match indexed {
+                Some(entry) => (entry.original_name, entry.mime_type, entry.modified_at),
+                None => (None, None, None),
+            };
+            manifest.entries.push(ManifestEntry {
+                name: zip_name(name),
+                original_name: match original_name {
+                    Some(original_name) if encryption => Some(seal_name(&original_name, key)?),
+                    original_name => original_name,
+                },
+                mime_type,
+                modified_at,
+                size: hasher.size(),
+                sha256: hasher.finish(),
+            });
+            progress.finish_item();
+        } else if !name.as_os_str().is_empty() {
+            zip.add_directory(zip_name(name), options)
+                .map_err(|e| VaultError::zip(e, zip_path))?;
+            manifest.albums.push(zip_name(name));
+        }
+    }
+    Ok(())
+}
+
+// Entry names always use '/', also when the backup is made on Windows
+fn zip_name(path: &Path) -> String {
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Manifest of the archive at `zip_path`, original names are left out of encrypted archives
+pub fn inspect_backup(zip_path: &str) -> Result<BackupManifest, VaultError> {
+    let zip = File::open(zip_path).map_err(|e| VaultError::io(e, zip_path))?;
+    let mut archive =
+        ZipArchive::new(BufReader::new(zip)).map_err(|e| VaultError::zip(e, zip_path))?;
+
+    BackupManifest::read(&mut archive, zip_path)?
+        .map(BackupManifest::without_sealed_names)
+        .ok_or_else(|| VaultError::InvalidBackup {
+            path: zip_path.to_string(),
+            reason: "archive has no manifest".to_string(),
+        })
+}
+
+// What restore_backup does with an entry whose content is already in its album
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    Skip,
+    // Restored next to the file that's already there
+    KeepBoth,
+    // Replaces the file that's already there. The restored copy gets a name of its own
+    // and keeps the dates of the backup.
+    Overwrite,
+}
+
+// What restore_backup did with the entries of an archive
+pub struct RestoreSummary {
+    pub restored: u32,
+    // Thumbnails, indexes and the vault key, which are rebuilt rather than restored,
+    // and duplicates left out by DuplicatePolicy::Skip
+    pub skipped: u32,
+    pub rejected: Vec<RejectedEntry>,
+    // Every duplicate, whatever the policy did with it
+    pub conflicts: Vec<RestoreConflict>,
+}
+
+pub struct RestoreConflict {
+    // Name of the entry in the archive
+    pub name: String,
+    // Path of the file already in the vault, relative to its root
+    pub existing: String,
+}
+
+pub struct RejectedEntry {
+    pub name: String,
+    pub reason: String,
+}
+
+// Albums and entries to restore, an album brings everything below it along
+pub struct BackupSelection {
+    pub albums: Vec<String>,
+    pub entries: Vec<String>,
+}
+
+impl BackupSelection {
+    fn contains(&self, name: &str) -> bool {
+        let name = name.trim_end_matches('/');
+        self.entries.iter().any(|entry| entry == name)
+            || self.albums.iter().any(|album| {
+                let album = album.trim_matches('/');
+                name == album
+                    || name
+                        .strip_prefix(album)
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+    }
+}
+
+// What list_backup found in an archive
+pub struct BackupContents {
+    pub albums: Vec<BackupAlbum>,
+    pub items: Vec<BackupItem>,
+}
+
+pub struct BackupAlbum {
+    pub path: String,
+    // Only what's directly in the album, not in albums below it
+    pub items: u32,
+    pub size: u64,
+}
+
+pub struct BackupItem {
+    // Name of the entry in the archive, what a BackupSelection refers to
+    pub name: String,
+    // Empty for files in the vault root
+    pub album: String,
+    // Left out of encrypted archives, see inspect_backup
+    pub original_name: Option<String>,
+    pub size: u64,
+}
+
+// Albums and media of a backup, also for archives from before manifests
+pub fn list_backup(zip_path: &str) -> Result<BackupContents, VaultError> {
+    let zip = File::open(zip_path).map_err(|e| VaultError::io(e, zip_path))?;
+    let mut archive =
+        ZipArchive::new(BufReader::new(zip)).map_err(|e| VaultError::zip(e, zip_path))?;
+    let manifest =
+        BackupManifest::read(&mut archive, zip_path)?.map(BackupManifest::without_sealed_names);
+
+    let listed = manifest.as_ref().map(BackupManifest::entries_by_name);
+
+    let mut albums: Vec<BackupAlbum> = Vec::new();
+    let mut items = Vec::new();
+    for album in manifest.iter().flat_map(|manifest| &manifest.albums) {
+        album_at(&mut albums, album);
+    }
+    for i in 0..archive.len() {
+        let file = archive
+            .by_index_raw(i)
+            .map_err(|e| VaultError::zip(e, zip_path))?;
+        let name = file.name().to_string();
+        // Thumbnails, indexes, the vault key and the manifest aren't media
+        if name
+            .split('/')
+            .any(|component| SKIP_PATTERNS.contains(&component))
+        {
+            continue;
+        }
+        if file.is_dir() {
+            album_at(&mut albums, &name);
+            continue;
+        }
+
+        let album = name.rsplit_once('/').map(|(album, _)| album).unwrap_or("");
+        if !album.is_empty() {
+            let position = album_at(&mut albums, album);
+            albums[position].items += 1;
+            albums[position].size += file.size();
+        }
+        items.push(BackupItem {
+            original_name: listed
+                .as_ref()
+                .and_then(|listed| listed.get(name.as_str()))
+                .and_then(|entry| entry.original_name.clone()),
+            album: album.to_string(),
+            size: file.size(),
+            name,
+        });
+    }
+
+    albums.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(BackupContents { albums, items })
+}
+
+// Position of the album in the list, added the first time it shows up
+fn album_at(albums: &mut Vec<BackupAlbum>, path: &str) -> usize {
+    let path = path.trim_end_matches('/');
+    match albums.iter().position(|album| album.path == path) {
+        Some(position) => position,
+        None => {
+            albums.push(BackupAlbum {
+                path: path.to_string(),
+                items: 0,
+                size: 0,
+            });
+            albums.len() - 1
+        }
+    }
+}
+
+// What verify_backup found, entries are listed by their name in the archive
+pub struct BackupReport {
+    pub good: Vec<String>,
+    // Listed in the manifest but not in the archive
+    pub missing: Vec<String>,
+    pub corrupt: Vec<RejectedEntry>,
+}
+
+// Reads every entry of a backup and checks it against the manifest, entries of encrypted
+// backups are also decrypted. Nothing is written anywhere, the vault isn't needed.
+pub fn verify_backup(zip_path: &str, password: Option<String>) -> Result<BackupReport, VaultError> {
+    let zip = File::open(zip_path).map_err(|e| VaultError::io(e, zip_path))?;
+    let mut archive =
+        ZipArchive::new(BufReader::new(zip)).map_err(|e| VaultError::zip(e, zip_path))?;
+    check_archive_structure(&mut archive, zip_path)?;
+
+    // Archives from before manifests can only be checked for entries that don't decrypt
+    let manifest = BackupManifest::read(&mut archive, zip_path)?;
+    let encrypted = match &manifest {
+        Some(manifest) => manifest.encryption == BackupEncryption::VaultKey,
+        None => password.is_some(),
+    };
+    let key = if encrypted {
+        let password = password.ok_or(VaultError::IncorrectPassword)?;
+        Some(verify_and_get_decrypter(&mut archive, &password)?.crypto_params())
+    } else {
+        None
+    };
+
+    let mut report = BackupReport {
+        good: Vec::new(),
+        missing: Vec::new(),
+        corrupt: Vec::new(),
+    };
+    let mut found = HashSet::new();
+    let entries = manifest.as_ref().map(BackupManifest::entries_by_name);
+
+    for i in 0..archive.len() {
+        let file = archive
+            .by_index(i)
+            .map_err(|e| VaultError::zip(e, zip_path))?;
+        let name = file.name().to_string();
+        if file.is_dir() || name == MANIFEST_FILE {
+            continue;
+        }
+        found.insert(name.clone());
+
+        let listed = entries
+            .as_ref()
+            .map(|entries| entries.get(name.as_str()).copied());
+        if let Some(None) = listed {
+            report.corrupt.push(RejectedEntry {
+                name,
+                reason: "not in the manifest".to_string(),
+            });
+            continue;
+        }
+
+        let mut reader = HashReader::new(file);
+        let read = match &key {
+            // The vault key isn't one of the encrypted blobs
+            Some(key) if name != VAULT_FILE => {
+                decrypt_reader(&mut reader, Path::new(&name), key, |_| Ok(()))
+            }
+            _ => io::copy(&mut reader, &mut io::sink())
+                .map(|_| ())
+                .map_err(|e| VaultError::io(e, &name)),
+        };
+        // Whatever is left of an entry that failed to decrypt still counts for the hash
+        let rest = io::copy(&mut reader, &mut io::sink());
+
+        let reason = match (read, rest) {
+            (Err(VaultError::Corrupted { .. }), _) => Some("does not decrypt".to_string()),
+            (Err(e), _) => Some(e.to_string()),
+            (Ok(_), Err(e)) => Some(e.to_string()),
+            (Ok(_), Ok(_)) => {
+                let hasher = reader.into_hasher();
+                match listed.flatten() {
+                    Some(listed)
+                        if hasher.size() != listed.size || hasher.finish() != listed.sha256 =>
+                    {
+                        Some("checksum mismatch".to_string())
+                    }
+                    _ => None,
+                }
+            }
+        };
+        match reason {
+            Some(reason) => report.corrupt.push(RejectedEntry { name, reason }),
+            None => report.good.push(name),
+        }
+    }
+
+    for entry in manifest.iter().flat_map(|manifest| &manifest.entries) {
+        if !found.contains(&entry.name) {
+            report.missing.push(entry.name.clone());
+        }
+    }
+    Ok(report)
+}
+
+const SKIP_PATTERNS: &[&str] = &[".hash", ".thumbs", ".vault-key", INDEX_FILE, MANIFEST_FILE];
+// Unix file type bits of a symlink entry
+const S_IFMT: u32 = 0o170000;
+const S_IFLNK: u32 = 0o120000;
+
+// Checks the archive as a whole before anything is written. Backups made by zip_backup
+// never contain links or the same name twice, an archive that does was not made by us.
+fn check_archive_structure(
+    archive: &mut ZipArchive<BufReader<File>>,
+    zip_path: &str,
+) -> Result<(), VaultError> {
+    let mut names = HashSet::new();
+    for i in 0..archive.len() {
+        let file = archive
+            .by_index(i)
+            .map_err(|e| VaultError::zip(e, zip_path))?;
+
+        if file
+            .unix_mode()
+            .is_some_and(|mode| mode & S_IFMT == S_IFLNK)
+        {
+            return Err(VaultError::InvalidBackup {
+                path: zip_path.to_string(),
+                reason: format!("symbolic link {}", file.name()),
+            });
+        }
+        if !names.insert(file.name().trim_end_matches('/').to_string()) {
+            return Err(VaultError::InvalidBackup {
+                path: zip_path.to_string(),
+                reason: format!("entry {} appears more than once", file.name()),
+            });
+        }
+    }
+    Ok(())
+}
+
+// Turns an entry name into a path relative to the vault root, or says why it can't be restored
+fn check_entry_name(name: &str) -> Result<PathBuf, &'static str> {
+    if name.is_empty() || name.contains('\0') {
+        return Err("invalid name");
+    }
+    // Zip names always use `/`, a backslash only shows up in archives made to escape on Windows
+    if name.contains('\\') {
+        return Err("backslash in name");
+    }
+    if name.starts_with('/')
+        || name
+            .split('/')
+            .next()
+            .is_some_and(|part| part.contains(':'))
+    {
+        return Err("absolute path");
+    }
+
+    let mut relative = PathBuf::new();
+    for part in name.split('/') {
+        match part {
+            "" | "." => continue,
+            ".." => return Err("parent directory reference"),
+            part => relative.push(part),
+        }
+    }
+    if relative.as_os_str().is_empty() {
+        return Err("invalid name");
+    }
+    Ok(relative)
+}
+
+pub fn restore_backup(
+    root_dir: &str,
+    zip_path: &str,
+    password: Option<String>,
+    selection: Option<&BackupSelection>,
+    duplicates: DuplicatePolicy,
+    key: &CryptoParams,
+    progress: &mut Progress,
+) -> Result<RestoreSummary, VaultError> {
+    let zip = File::open(zip_path).map_err(|e| VaultError::io(e, zip_path))?;
+    let reader = BufReader::new(zip);
+    let root_dir_path = Path::new(root_dir);
+
+    let mut archive = ZipArchive::new(reader).map_err(|e| VaultError::zip(e, zip_path))?;
+    check_archive_structure(&mut archive, zip_path)?;
+    fs::create_dir_all(root_dir_path).map_err(|e| VaultError::io(e, root_dir_path))?;
+
+    // Archives from before manifests are restored without the checks it allows
+    let manifest = BackupManifest::read(&mut archive, zip_path)?;
+
+    let decrypter = if let Some(pass) = password {
+        Some(verify_and_get_decrypter(&mut archive, &pass)?)
+    } else {
+        None
+    };
+    if decrypter.is_none()
+        && manifest
+            .as_ref()
+            .is_some_and(|manifest| manifest.encryption == BackupEncryption::VaultKey)
+    {
+        // Without it every file would be restored still encrypted
+        return Err(VaultError::IncorrectPassword);
+    }
+
+    // Chunked entries are opened with it directly, see stream::decrypt_reader
+    let backup_key = decrypter.as_ref().map(PasswordDecrypter::crypto_params);
+
+    let selected = |name: &str| selection.is_none_or(|selection| selection.contains(name));
+
+    // Albums that were empty have no entries to create them
+    for album in manifest.iter().flat_map(|manifest| &manifest.albums) {
+        if !selected(album) {
+            continue;
+        }
+        if let Ok(relative) = check_entry_name(album) {
+            if let Ok(path) = resolve_in_vault(root_dir_path, &relative.to_string_lossy()) {
+                fs::create_dir_all(&path).map_err(|e| VaultError::io(e, &path))?;
+            }
+        }
+    }
+
+    let mut summary = RestoreSummary {
+        restored: 0,
+        skipped: 0,
+        rejected: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    // Content hashes of every album restored into, loaded when the first entry lands there
+    let mut album_hashes: HashMap<PathBuf, HashMap<String, String>> = HashMap::new();
+    let entries = manifest.as_ref().map(BackupManifest::entries_by_name);
+
+    let mut files_total = 0;
+    let mut bytes_total = 0;
+    for i in 0..archive.len() {
+        let file = archive
+            .by_index_raw(i)
+            .map_err(|e| VaultError::zip(e, zip_path))?;
+        if !file.is_dir() && selected(file.name()) {
+            files_total += 1;
+            bytes_total += file.size();
+        }
+    }
+    progress.start(files_total, bytes_total);
+
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| VaultError::zip(e, zip_path))?;
+        let name = file.name().to_string();
+        let size = file.size();
+        if !selected(&name) {
+            continue;
+        }
+        if !file.is_dir() {
+            progress.begin_item(&name)?;
+        }
+
+        let relative = match check_entry_name(&name) {
+            Ok(relative) => relative,
+            Err(reason) => {
+                summary.rejected.push(RejectedEntry {
+                    name,
+                    reason: reason.to_string(),
+                });
+                progress.add_bytes(size)?;
+                progress.finish_item();
+                continue;
+            }
+        };
+
+        // Skip .thumbs .hash .index .vault-key
+        if relative.components().any(|component| {
+            SKIP_PATTERNS.contains(&component.as_os_str().to_string_lossy().as_ref())
+        }) {
+            summary.skipped += 1;
+            if !file.is_dir() {
+                progress.add_bytes(size)?;
+                progress.finish_item();
+            }
+            continue;
+        }
+
+        // Also refuses entries that would land on a symlink already inside the vault
+        let outpath = match resolve_in_vault(root_dir_path, &relative.to_string_lossy()) {
+            Ok(outpath) => outpath,
+            Err(VaultError::PathOutsideVault { .. }) => {
+                summary.rejected.push(RejectedEntry {
+                    name,
+                    reason: "outside the vault".to_string(),
+                });
+                if !file.is_dir() {
+                    progress.add_bytes(size)?;
+                    progress.finish_item();
+                }
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        if file.is_dir() {
+            // Its a directory
+            fs::create_dir_all(&outpath).map_err(|e| VaultError::io(e, &outpath))?;
+            continue;
+        }
+
+        // Its a file, saved next to where it was in the vault
+        let parent_dir = match outpath.parent() {
+            Some(parent_dir) => parent_dir,
+            None => continue,
+        };
+        fs::create_dir_all(parent_dir).map_err(|e| VaultError::io(e, parent_dir))?;
+
+        let mut source = None;
+        let mut listed = None;
+        if let Some(entries) = &entries {
+            let entry = match entries.get(name.as_str()) {
+                Some(entry) => *entry,
+                None => {
+                    summary.rejected.push(RejectedEntry {
+                        name,
+                        reason: "not in the manifest".to_string(),
+                    });
+                    progress.add_bytes(size)?;
+                    progress.finish_item();
+                    continue;
+                }
+            };
+            listed = Some(entry);
+
+            let original_name = match (&entry.original_name, &decrypter) {
+                (Some(sealed), Some(decrypter)) => open_name(sealed, decrypter),
+                (original_name, _) => original_name.clone(),
+            };
+            source = Some(MediaSource {
+                original_name,
+                mime_type: entry.mime_type.clone(),
+                modified_at: entry.modified_at,
+            });
+        }
+
+        // Decrypted a chunk at a time into a part file, it only joins the album once the
+        // checksum of the whole entry was checked. Dropping it removes the part file.
+        let mut pending = begin_media(parent_dir.to_string_lossy().to_string(), source, key)?;
+        let mut reader = HashReader::new(&mut file);
+        // Failing to write to the album stops the restore, a bad entry is only rejected
+        let mut write_error = None;
+        let mut write = |chunk: &[u8]| {
+            pending.write(chunk).map_err(|e| {
+                let stopped = VaultError::Internal {
+                    message: e.to_string(),
+                };
+                write_error = Some(e);
+                stopped
+            })
+        };
+        let read = match &backup_key {
+            Some(backup_key) => {
+                decrypt_reader(&mut reader, Path::new(&name), backup_key, |chunk| {
+                    write(&chunk)
+                })
+            }
+            None => read_chunks(&mut reader, &name, write),
+        };
+        if let Some(e) = write_error {
+            return Err(e);
+        }
+        // Whatever is left of an entry that failed to decrypt still counts for the checksum
+        let rest = io::copy(&mut reader, &mut io::sink()).map_err(|e| VaultError::io(e, &name));
+
+        let hasher = reader.into_hasher();
+        let reason = match (read, rest) {
+            (_, Err(e)) => Some(e.to_string()),
+            _ if listed.is_some_and(|listed| {
+                hasher.size() != listed.size || hasher.finish() != listed.sha256
+            }) =>
+            {
+                Some("checksum mismatch".to_string())
+            }
+            // The checksum only covers what's in the archive, and archives from before
+            // manifests have none
+            (Err(VaultError::Corrupted { .. }), _) => Some("does not decrypt".to_string()),
+            (Err(e), _) => Some(e.to_string()),
+            (Ok(_), Ok(_)) => None,
+        };
+        progress.add_bytes(size)?;
+        if let Some(reason) = reason {
+            summary.rejected.push(RejectedEntry { name, reason });
+            progress.finish_item();
+            continue;
+        }
+
+        let hashes = match album_hashes.entry(parent_dir.to_path_buf()) {
+            Entry::Occupied(hashes) => hashes.into_mut(),
+            Entry::Vacant(hashes) => hashes.insert(content_hashes(parent_dir, key)?),
+        };
+        let hash = pending.sha256();
+
+        let mut replaced = None;
+        if let Some(existing) = hashes.get(&hash) {
+            let existing_path = parent_dir.join(existing);
+            summary.conflicts.push(RestoreConflict {
+                name,
+                existing: existing_path
+                    .strip_prefix(root_dir_path)
+                    .map(zip_name)
+                    .unwrap_or_else(|_| existing.clone()),
+            });
+            match duplicates {
+                DuplicatePolicy::Skip => {
+                    summary.skipped += 1;
+                    progress.finish_item();
+                    continue;
+                }
+                DuplicatePolicy::KeepBoth => {}
+                DuplicatePolicy::Overwrite => replaced = Some(existing_path),
+            }
+        }
+
+        let path = pending.finish(key)?;
+        // Only once the restored copy is in place, a failed restore keeps the old one
+        if let Some(existing_path) = replaced {
+            delete_file(&existing_path.to_string_lossy(), key)?;
+        }
+        if let Some(file_name) = path.file_name() {
+            let file_name = file_name.to_string_lossy().to_string();
+            if duplicates == DuplicatePolicy::Overwrite {
+                hashes.insert(hash, file_name);
+            } else {
+                // Keeps pointing at the first copy when both are kept
+                hashes.entry(hash).or_insert(file_name);
+            }
+        }
+        summary.restored += 1;
+        progress.finish_item();
+    }
+
+    Ok(summary)
+}
+
+// Hands an entry of an archive without encryption to `each` a chunk at a time, the way
+// decrypt_reader does for encrypted ones
+fn read_chunks(
+    mut reader: impl Read,
+    name: &str,
+    mut each: impl FnMut(&[u8]) -> Result<(), VaultError>,
+) -> Result<(), VaultError> {
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => each(&buffer[..read])?,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(VaultError::io(e, name)),
+        }
+    }
+}
+
+// Name of the file holding each content in `dir`. Entries from before content hashes get
+// theirs now and keep it in the index, so it's only ever worked out once per file.
+fn content_hashes(dir: &Path, key: &CryptoParams) -> Result<HashMap<String, String>, VaultError> {
+    let mut hashes = HashMap::new();
+    if !dir.is_dir() {
+        return Ok(hashes);
+    }
+    let entries = lock_indexes().open(dir, key)?.entries().clone();
+
+    for (name, entry) in entries {
+        let path = dir.join(&name);
+        if !path.is_file() {
+            continue;
+        }
+        let hash = match entry.sha256.clone() {
+            Some(hash) => hash,
+            None => {
+                let mut hasher = EntryHasher::new();
+                // A file that can't be read can't be a duplicate either
+                let read = stream_file(&path.to_string_lossy(), key, |chunk| {
+                    hasher.update(&chunk);
+                    Ok(())
+                });
+                if read.is_err() {
+                    continue;
+                }
+                let hash = hasher.finish();
+
+                let mut indexes = lock_indexes();
+                let index = indexes.open(dir, key)?;
+                if let Some(current) = index.get(&name).cloned() {
+                    let entry = MediaEntry {
+                        sha256: Some(hash.clone()),
+                        ..current
+                    };
+                    index.put(&name, entry, key)?;
+                }
+                hash
+            }
+        };
+        // Any of them will do when the album already holds the same content twice
+        hashes.entry(hash).or_insert(name);
+    }
+    Ok(hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::{content_hash, get_file, save_file};
+    use crate::utils::testing::{vault, TempDir, PASSWORD};
+    use crate::utils::utils::is_internal_name;
+
+    fn source() -> MediaSource {
+        MediaSource {
+            original_name: Some("beach.txt".to_string()),
+            mime_type: Some("text/plain".to_string()),
+            modified_at: Some(1_600_000_000),
+        }
+    }
+
+    // Backs `root` up into a directory of its own, removed together with the archive
+    fn backup(root: &TempDir, encryption: bool, key: &CryptoParams) -> (TempDir, String) {
+        let dir = TempDir::new("backup");
+        let zip_path = dir.join("backup.zip");
+        zip_backup(
+            &root.join(""),
+            &zip_path,
+            encryption,
+            key,
+            &mut Progress::none(),
+        )
+        .unwrap();
+        (dir, zip_path)
+    }
+
+    // Copies a backup entry by entry, `edit` changes an entry or drops it by returning None
+    fn rewrite_backup(zip_path: &str, mut edit: impl FnMut(&str, Vec<u8>) -> Option<Vec<u8>>) {
+        let mut archive = ZipArchive::new(BufReader::new(File::open(zip_path).unwrap())).unwrap();
+        let mut entries = Vec::new();
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i).unwrap();
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).unwrap();
+            entries.push((file.name().to_string(), data));
+        }
+
+        let mut zip = ZipWriter::new(File::create(zip_path).unwrap());
+        for (name, data) in entries {
+            if let Some(data) = edit(&name, data) {
+                zip.start_file(name, FileOptions::default()).unwrap();
+                zip.write_all(&data).unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    fn append_to_backup(zip_path: &str, name: &str, data: &[u8]) {
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(zip_path)
+            .unwrap();
+        let mut zip = ZipWriter::new_append(file).unwrap();
+        zip.start_file(name, FileOptions::default()).unwrap();
+        zip.write_all(data).unwrap();
+        zip.finish().unwrap();
+    }
+
+    // Entries of media in the album, not the album itself or the index and thumbnails
+    fn is_album_media(name: &str) -> bool {
+        name.starts_with("album/") && !name.ends_with('/') && !name.contains("/.")
+    }
+
+    // The name in the archive of the first media file of the album
+    fn media_entry(zip_path: &str) -> String {
+        let archive = ZipArchive::new(BufReader::new(File::open(zip_path).unwrap())).unwrap();
+        let name = archive
+            .file_names()
+            .find(|name| is_album_media(name))
+            .unwrap()
+            .to_string();
+        name
+    }
+
+    fn album_entries(root: &TempDir, key: &CryptoParams) -> Vec<MediaEntry> {
+        lock_indexes()
+            .open(&root.path().join("album"), key)
+            .unwrap()
+            .entries()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn restore_keeps_the_media_source() {
+        for encryption in [false, true] {
+            let (from, from_key) = vault("backup_source_from");
+            save_file(
+                b"sand".to_vec(),
+                from.join("album"),
+                Some(source()),
+                &from_key,
+            )
+            .unwrap();
+            let (_archive, zip_path) = backup(&from, encryption, &from_key);
+
+            let zip = File::open(&zip_path).unwrap();
+            let mut archive = ZipArchive::new(BufReader::new(zip)).unwrap();
+            let manifest = BackupManifest::read(&mut archive, &zip_path)
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                manifest.app_version.as_deref(),
+                Some(env!("CARGO_PKG_VERSION"))
+            );
+
+            let (to, to_key) = vault("backup_source_to");
+            let summary = restore_backup(
+                &to.join(""),
+                &zip_path,
+                encryption.then(|| PASSWORD.to_string()),
+                None,
+                DuplicatePolicy::KeepBoth,
+                &to_key,
+                &mut Progress::none(),
+            )
+            .unwrap();
+            assert_eq!(summary.restored, 1);
+
+            let restored = album_entries(&to, &to_key);
+            assert_eq!(restored.len(), 1);
+            let expected = source();
+            assert_eq!(restored[0].original_name, expected.original_name);
+            assert_eq!(restored[0].mime_type, expected.mime_type);
+            assert_eq!(restored[0].modified_at, expected.modified_at);
+        }
+    }
+
+    #[test]
+    fn restore_streams_entries_across_chunks() {
+        let data: Vec<u8> = (0..CHUNK_SIZE * 2 + 5).map(|i| (i % 251) as u8).collect();
+        for encryption in [false, true] {
+            let (from, from_key) = vault("backup_stream_from");
+            save_file(data.clone(), from.join("album"), None, &from_key).unwrap();
+            let (_archive, zip_path) = backup(&from, encryption, &from_key);
+
+            let (to, to_key) = vault("backup_stream_to");
+            let summary = restore_backup(
+                &to.join(""),
+                &zip_path,
+                encryption.then(|| PASSWORD.to_string()),
+                None,
+                DuplicatePolicy::KeepBoth,
+                &to_key,
+                &mut Progress::none(),
+            )
+            .unwrap();
+            assert_eq!(summary.restored, 1);
+
+            let (name, entry) = lock_indexes()
+                .open(&to.path().join("album"), &to_key)
+                .unwrap()
+                .entries()
+                .iter()
+                .map(|(name, entry)| (name.clone(), entry.clone()))
+                .next()
+                .unwrap();
+            assert_eq!(entry.size, Some(data.len() as u64));
+            assert_eq!(entry.sha256, Some(content_hash(&data)));
+            let mut read = Vec::new();
+            stream_file(&to.join(&format!("album/{}", name)), &to_key, |chunk| {
+                read.extend_from_slice(&chunk);
+                Ok(())
+            })
+            .unwrap();
+            assert_eq!(read, data);
+        }
+    }
+
+    #[test]
+    fn restore_leaves_nothing_of_a_tampered_entry() {
+        let (from, from_key) = vault("backup_tampered_from");
+        let data = vec![7u8; CHUNK_SIZE + 1];
+        save_file(data, from.join("album"), None, &from_key).unwrap();
+        let (_archive, zip_path) = backup(&from, true, &from_key);
+        let tampered = media_entry(&zip_path);
+        rewrite_backup(&zip_path, |name, mut data| {
+            if name == tampered {
+                let last = data.len() - 1;
+                data[last] ^= 1;
+            }
+            Some(data)
+        });
+
+        let (to, to_key) = vault("backup_tampered_to");
+        let summary = restore_backup(
+            &to.join(""),
+            &zip_path,
+            Some(PASSWORD.to_string()),
+            None,
+            DuplicatePolicy::KeepBoth,
+            &to_key,
+            &mut Progress::none(),
+        )
+        .unwrap();
+        assert_eq!(summary.restored, 0);
+        assert_eq!(summary.rejected.len(), 1);
+        assert_eq!(summary.rejected[0].name, tampered);
+        assert_eq!(summary.rejected[0].reason, "checksum mismatch");
+
+        // Not even the part file it was decrypted into is left
+        let left: Vec<_> = fs::read_dir(to.path().join("album"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .filter(|name| !is_internal_name(name))
+            .collect();
+        assert!(left.is_empty(), "{:?}", left);
+    }
+
+    #[test]
+    fn verify_backup_finds_tampered_missing_and_extra_entries() {
+        for encryption in [false, true] {
+            let (root, key) = vault("backup_verify");
+            for data in [b"sand".to_vec(), b"shells".to_vec()] {
+                save_file(data, root.join("album"), None, &key).unwrap();
+            }
+            let (_archive, zip_path) = backup(&root, encryption, &key);
+            let password = || encryption.then(|| PASSWORD.to_string());
+
+            let report = verify_backup(&zip_path, password()).unwrap();
+            assert!(report.missing.is_empty());
+            assert!(report.corrupt.is_empty());
+            assert!(report.good.len() >= 2);
+            if encryption {
+                assert!(matches!(
+                    verify_backup(&zip_path, Some("wrong".to_string())),
+                    Err(VaultError::IncorrectPassword)
+                ));
+            }
+
+            let tampered = media_entry(&zip_path);
+            let mut missing = None;
+            rewrite_backup(&zip_path, |name, mut data| {
+                if name == tampered {
+                    let last = data.len() - 1;
+                    data[last] ^= 1;
+                } else if missing.is_none() && is_album_media(name) {
+                    missing = Some(name.to_string());
+                    return None;
+                }
+                Some(data)
+            });
+            append_to_backup(&zip_path, "album/extra.file", b"not listed");
+
+            let report = verify_backup(&zip_path, password()).unwrap();
+            assert_eq!(report.missing, vec![missing.unwrap()]);
+            let reason = |name: &str| {
+                report
+                    .corrupt
+                    .iter()
+                    .find(|entry| entry.name == name)
+                    .map(|entry| entry.reason.clone())
+            };
+            // Encrypted entries fail to decrypt before their checksum is looked at
+            let expected = if encryption {
+                "does not decrypt"
+            } else {
+                "checksum mismatch"
+            };
+            assert_eq!(reason(&tampered).as_deref(), Some(expected));
+            assert_eq!(
+                reason("album/extra.file").as_deref(),
+                Some("not in the manifest")
+            );
+            assert_eq!(report.corrupt.len(), 2);
+            assert!(!report.good.contains(&tampered));
+        }
+    }
+
+    #[test]
+    fn list_backup_and_restore_a_selection() {
+        for encryption in [false, true] {
+            let (from, from_key) = vault("backup_selection_from");
+            fs::create_dir(from.path().join("beach")).unwrap();
+            fs::create_dir(from.path().join("empty")).unwrap();
+            save_file(
+                b"sand".to_vec(),
+                from.join("album"),
+                Some(source()),
+                &from_key,
+            )
+            .unwrap();
+            for data in [b"waves".to_vec(), b"gulls".to_vec()] {
+                save_file(data, from.join("beach"), None, &from_key).unwrap();
+            }
+            let (_archive, zip_path) = backup(&from, encryption, &from_key);
+
+            let contents = list_backup(&zip_path).unwrap();
+            let albums: Vec<_> = contents
+                .albums
+                .iter()
+                .map(|album| (album.path.as_str(), album.items))
+                .collect();
+            assert_eq!(albums, vec![("album", 1), ("beach", 2), ("empty", 0)]);
+            assert_eq!(contents.items.len(), 3);
+            let sand = contents
+                .items
+                .iter()
+                .find(|item| item.album == "album")
+                .unwrap();
+            assert!(sand.size > 0);
+            // Sealed names can't be read without the password
+            let expected = (!encryption).then(|| "beach.txt".to_string());
+            assert_eq!(sand.original_name, expected);
+
+            let (to, to_key) = vault("backup_selection_to");
+            let restore = |selection: BackupSelection| {
+                restore_backup(
+                    &to.join(""),
+                    &zip_path,
+                    encryption.then(|| PASSWORD.to_string()),
+                    Some(&selection),
+                    DuplicatePolicy::KeepBoth,
+                    &to_key,
+                    &mut Progress::none(),
+                )
+                .unwrap()
+            };
+            let media = |album: &str| {
+                lock_indexes()
+                    .open(&to.path().join(album), &to_key)
+                    .unwrap()
+                    .entries()
+                    .len()
+            };
+
+            let summary = restore(BackupSelection {
+                albums: vec!["beach".to_string()],
+                entries: Vec::new(),
+            });
+            assert_eq!(summary.restored, 2);
+            assert_eq!(media("beach"), 2);
+            assert_eq!(media("album"), 0);
+            assert!(!to.path().join("empty").exists());
+
+            let summary = restore(BackupSelection {
+                albums: vec!["empty".to_string()],
+                entries: vec![sand.name.clone()],
+            });
+            assert_eq!(summary.restored, 1);
+            assert!(to.path().join("empty").is_dir());
+            let restored = album_entries(&to, &to_key);
+            assert_eq!(restored.len(), 1);
+            assert_eq!(restored[0].original_name, source().original_name);
+            assert_eq!(media("beach"), 2);
+        }
+    }
+
+    #[test]
+    fn entries_that_do_not_decrypt_are_rejected() {
+        // With a checksum that matches the tampered entry, and without any manifest
+        for keep_manifest in [true, false] {
+            let (from, from_key) = vault("backup_undecryptable_from");
+            for data in [b"sand".to_vec(), b"shells".to_vec()] {
+                save_file(data, from.join("album"), None, &from_key).unwrap();
+            }
+            let (_archive, zip_path) = backup(&from, true, &from_key);
+
+            let tampered = media_entry(&zip_path);
+            let mut archive =
+                ZipArchive::new(BufReader::new(File::open(&zip_path).unwrap())).unwrap();
+            let mut data = Vec::new();
+            archive
+                .by_name(&tampered)
+                .unwrap()
+                .read_to_end(&mut data)
+                .unwrap();
+            let last = data.len() - 1;
+            data[last] ^= 1;
+            let mut hasher = EntryHasher::new();
+            hasher.update(&data);
+            let (size, sha256) = (hasher.size(), hasher.finish());
+
+            rewrite_backup(&zip_path, |name, entry| {
+                if name == tampered {
+                    return Some(data.clone());
+                }
+                if name != MANIFEST_FILE {
+                    return Some(entry);
+                }
+                let mut manifest: BackupManifest = serde_json::from_slice(&entry).unwrap();
+                for listed in &mut manifest.entries {
+                    if listed.name == tampered {
+                        listed.size = size;
+                        listed.sha256 = sha256.clone();
+                    }
+                }
+                keep_manifest.then(|| serde_json::to_vec(&manifest).unwrap())
+            });
+
+            let (to, to_key) = vault("backup_undecryptable_to");
+            let summary = restore_backup(
+                &to.join(""),
+                &zip_path,
+                Some(PASSWORD.to_string()),
+                None,
+                DuplicatePolicy::KeepBoth,
+                &to_key,
+                &mut Progress::none(),
+            )
+            .unwrap();
+            // The rest of the archive is still restored
+            assert_eq!(summary.restored, 1);
+            assert_eq!(summary.rejected.len(), 1);
+            assert_eq!(summary.rejected[0].name, tampered);
+            assert_eq!(summary.rejected[0].reason, "does not decrypt");
+            assert_eq!(album_entries(&to, &to_key).len(), 1);
+        }
+    }
+
+    #[test]
+    fn overwrite_keeps_the_original_until_the_copy_is_in_place() {
+        let (from, from_key) = vault("backup_overwrite_from");
+        save_file(b"sand".to_vec(), from.join("album"), None, &from_key).unwrap();
+        let (_archive, zip_path) = backup(&from, false, &from_key);
+
+        let (to, to_key) = vault("backup_overwrite_to");
+        save_file(b"sand".to_vec(), to.join("album"), None, &to_key).unwrap();
+        let names = || {
+            let mut indexes = lock_indexes();
+            let index = indexes.open(&to.path().join("album"), &to_key).unwrap();
+            index.entries().keys().cloned().collect::<Vec<_>>()
+        };
+        let original = names();
+        let restore = || {
+            restore_backup(
+                &to.join(""),
+                &zip_path,
+                None,
+                None,
+                DuplicatePolicy::Overwrite,
+                &to_key,
+                &mut Progress::none(),
+            )
+        };
+
+        // The thumbnail of the restored copy can't be written, so it never gets finished
+        let thumbs = to.path().join("album/.thumbs");
+        fs::write(&thumbs, b"not a directory").unwrap();
+        assert!(restore().is_err());
+        assert_eq!(names(), original);
+        let path = to.join(&format!("album/{}", original[0]));
+        assert_eq!(get_file(&path, &to_key).unwrap(), b"sand");
+
+        fs::remove_file(&thumbs).unwrap();
+        let summary = restore().unwrap();
+        assert_eq!(summary.restored, 1);
+        assert_eq!(summary.conflicts.len(), 1);
+        let replaced = names();
+        assert_eq!(replaced.len(), 1);
+        assert_ne!(replaced, original);
+        assert!(!Path::new(&path).exists());
+    }
+}
@@ -1,19 +1,19 @@
 // file manipulation
 use std::{
     fs::{self, File},
-    io::{self, Cursor, Write},
+    io::{self, Cursor, Read, Write},
     path::Path,
 };
 
 // Caching
 use blurhash;
-use image::{imageops, EncodableLayout, GenericImageView};
+use image::{imageops, EncodableLayout, GenericImageView, ImageReader};
 use infer::is_video;
 
 // Custom error
 use crate::utils::error::VaultError;
 // Encrytion
-use crate::utils::encryption::encrypt_data;
+use crate::utils::encryption::{encrypt_data, CryptoParams};
 
 #[cfg(not(target_os = "android"))]
 use gstreamer as gst;
@@ -32,6 +32,10 @@ use jni::JNIEnv;
 #[cfg(target_os = "android")]
 use std::os::unix::io::RawFd;
 
+// Most containers put what a decoder needs for the first frame near the start, so
+// previews of data too big to hold in memory are made from this much of it
+pub const PREVIEW_PREFIX_LEN: u64 = 32 * 1024 * 1024;
+
 #[cfg(not(target_os = "android"))]
 pub fn get_thumbnail_from_memory_gst(video_data: &Vec<u8>) -> Result<Vec<u8>, Box<dyn Error>> {
     // 1. Initialize GStreamer
@@ -47,14 +51,14 @@ pub fn get_thumbnail_from_memory_gst(video_data: &Vec<u8>) -> Result<Vec<u8>, Bo
 
     let src = pipeline
         .by_name("src")
-        .unwrap()
+        .ok_or("Pipeline has no appsrc")?
         .dynamic_cast::<AppSrc>()
-        .unwrap();
+        .map_err(|_| "Failed to cast to AppSrc")?;
     let sink = pipeline
         .by_name("sink")
-        .unwrap()
+        .ok_or("Pipeline has no appsink")?
         .dynamic_cast::<AppSink>()
-        .unwrap();
+        .map_err(|_| "Failed to cast to AppSink")?;
 
     // 3. Set to Playing state
     pipeline.set_state(gst::State::Playing)?;
@@ -87,17 +91,55 @@ fn create_fallback_image() -> image::DynamicImage {
 }
 
 // Image caching functions ----------------------------------------
+// Writes the thumbnail and returns the blurhash and aspect ratio
 pub fn cache_image(
     image_data: &Vec<u8>,
     file_path: String,
     components_x: u32,
     components_y: u32,
-) -> Result<(), VaultError> {
-    let img: image::DynamicImage = if is_video(image_data) {
+    key: &CryptoParams,
+) -> Result<(String, f32), VaultError> {
+    let img = if is_video(image_data) {
+        video_preview(image_data)?
+    } else {
+        image::load_from_memory(image_data).unwrap_or_else(|_| create_fallback_image())
+    };
+    cache_preview(img, file_path, components_x, components_y, key)
+}
+
+// Same as cache_image for a file on disk. Images are decoded straight from the file and
+// videos only get their first PREVIEW_PREFIX_LEN bytes, so a video with its index at the
+// end gets the fallback thumbnail.
+pub fn cache_file(
+    src: &Path,
+    video: bool,
+    file_path: String,
+    components_x: u32,
+    components_y: u32,
+    key: &CryptoParams,
+) -> Result<(String, f32), VaultError> {
+    let img = if video {
+        let mut prefix = Vec::new();
+        File::open(src)
+            .and_then(|file| file.take(PREVIEW_PREFIX_LEN).read_to_end(&mut prefix))
+            .map_err(|e| VaultError::io(e, src))?;
+        video_preview(&prefix)?
+    } else {
+        ImageReader::open(src)
+            .ok()
+            .and_then(|reader| reader.with_guessed_format().ok())
+            .and_then(|reader| reader.decode().ok())
+            .unwrap_or_else(create_fallback_image)
+    };
+    cache_preview(img, file_path, components_x, components_y, key)
+}
+
+fn video_preview(video_data: &Vec<u8>) -> Result<image::DynamicImage, VaultError> {
+    let img = {
         // Video
         #[cfg(not(target_os = "android"))]
         {
-            match get_thumbnail_from_memory_gst(image_data) {
+            match get_thumbnail_from_memory_gst(video_data) {
                 Ok(raw_rgb_data) => image::load_from_memory(&raw_rgb_data)
                     .unwrap_or_else(|_| create_fallback_image()),
                 Err(_) => create_fallback_image(),
@@ -110,70 +152,52 @@ pub fn cache_image(
             match get_jni_env() {
                 Ok(mut env_guard) => {
                     // 2. Pass the guard (which acts as &mut JNIEnv) to your function
-                    match get_thumbnail_no_disk(&mut env_guard, image_data) {
+                    match get_thumbnail_no_disk(&mut env_guard, video_data) {
                         // Ok(raw_rgb_data) => image::load_from_memory(&raw_rgb_data)
                         //     .unwrap_or_else(|_| create_fallback_image()),
-                        Ok(raw_rgb_data) => image::load_from_memory(&raw_rgb_data)
-                            .map_err(|e| VaultError::Error(e.to_string()))?,
+                        Ok(raw_rgb_data) => {
+                            image::load_from_memory(&raw_rgb_data).map_err(|e| {
+                                VaultError::Internal {
+                                    message: e.to_string(),
+                                }
+                            })?
+                        }
                         Err(_) => create_fallback_image(),
                     }
                 }
                 Err(_) => create_fallback_image(),
             }
         }
-    } else {
-        image::load_from_memory(image_data).unwrap_or_else(|_| create_fallback_image())
     };
+    Ok(img)
+}
+
+// Writes the thumbnail of a decoded preview and returns the blurhash and aspect ratio
+fn cache_preview(
+    img: image::DynamicImage,
+    file_path: String,
+    components_x: u32,
+    components_y: u32,
+    key: &CryptoParams,
+) -> Result<(String, f32), VaultError> {
     let (width, height) = img.dimensions();
 
-    // creating a blur hash
-    match blurhash::encode(
+    // creating a blur hash, stored in the album index by the caller
+    let blurhash = match blurhash::encode(
         components_x,
         components_y,
         width,
         height,
         img.to_rgba8().as_bytes(),
     ) {
-        Ok(hash) => {
-            let path_temp = Path::new(file_path.as_str());
-            // let parent_path = path_temp.parent().unwrap();
-            match path_temp.parent() {
-                Some(parent_path) => {
-                    // let hash_path = parent_path.join("hash").join(path_temp.file_name().unwrap());
-                    match path_temp.file_name() {
-                        Some(filename) => {
-                            let hash_path = parent_path.join(".hash").join(filename);
-                            let path = hash_path.as_os_str();
-
-                            let content = format!("{} {:.2}", hash, width as f32 / height as f32);
-
-                            match File::create(&path) {
-                                Ok(mut file) => {
-                                    file.write_all(&encrypt_data(&content.as_bytes())?)
-                                        .map_err(|e| VaultError::Error(e.to_string()))?;
-                                }
-                                Err(e) => {
-                                    if e.kind() == io::ErrorKind::NotFound {
-                                        fs::create_dir_all(parent_path.join(".hash"))
-                                            .map_err(|e| VaultError::Error(e.to_string()))?;
-                                        let mut file = File::create(&path)
-                                            .map_err(|e| VaultError::Error(e.to_string()))?;
-                                        file.write_all(&encrypt_data(&content.as_bytes())?)
-                                            .map_err(|e| VaultError::Error(e.to_string()))?;
-                                    } else {
-                                        return Err(VaultError::Error(e.to_string()));
-                                    }
-                                }
-                            }
-                        }
-                        None => return Err(VaultError::Error("Invalid path".to_string())),
-                    }
-                }
-                None => return Err(VaultError::Error("Invalid path".to_string())),
-            }
+        Ok(hash) => hash,
+        Err(e) => {
+            return Err(VaultError::Internal {
+                message: e.to_string(),
+            })
         }
-        Err(e) => return Err(VaultError::Error(e.to_string())),
-    }
+    };
+    let aspect_ratio = width as f32 / height as f32;
 
     // resizing the image
     let thumbnail = img
@@ -192,7 +216,9 @@ pub fn cache_image(
         image::ColorType::Rgb8,
         image::ImageFormat::Jpeg,
     )
-    .map_err(|e| VaultError::Error(e.to_string()))?;
+    .map_err(|e| VaultError::Internal {
+        message: e.to_string(),
+    })?;
 
     // getting filepath
     // let working_dir = Path::new(&file_path)
@@ -201,7 +227,11 @@ pub fn cache_image(
     let working_dir;
     match Path::new(&file_path).parent() {
         Some(parent) => working_dir = parent,
-        None => return Err(VaultError::Error("Invalid path".to_string())),
+        None => {
+            return Err(VaultError::InvalidInput {
+                message: format!("Invalid path: {}", file_path),
+            })
+        }
     }
     // let filename = working_dir
     //     .join(format!("thumbs/{}", Path::new(&file_path).file_name().unwrap().to_str().unwrap()));
@@ -211,32 +241,40 @@ pub fn cache_image(
             Some(filepath_str) => {
                 filename = working_dir.join(format!(".thumbs/{}", filepath_str));
             }
-            None => return Err(VaultError::Error("Invalid path".to_string())),
+            None => {
+                return Err(VaultError::InvalidInput {
+                    message: format!("Invalid path: {}", file_path),
+                })
+            }
         },
-        None => return Err(VaultError::Error("Invalid path".to_string())),
+        None => {
+            return Err(VaultError::InvalidInput {
+                message: format!("Invalid path: {}", file_path),
+            })
+        }
     }
 
     // saving file
-    let encrypted_data = encrypt_data(&buffer)?;
+    let encrypted_data = encrypt_data(key, &buffer)?;
     match File::create(&filename) {
         Ok(mut file) => match file.write_all(&encrypted_data) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(VaultError::Error(e.to_string())),
+            Ok(_) => Ok((blurhash, aspect_ratio)),
+            Err(e) => Err(VaultError::io(e, &filename)),
         },
         Err(e) => {
             if e.kind() == io::ErrorKind::NotFound {
                 // create_dir(working_dir.to_string_lossy().to_string(), "thumbs".to_string())?;
                 fs::create_dir_all(working_dir.join(".thumbs"))
-                    .map_err(|e| VaultError::Error(e.to_string()))?;
+                    .map_err(|e| VaultError::io(e, working_dir))?;
                 match File::create(&filename) {
                     Ok(mut file) => match file.write_all(&encrypted_data) {
-                        Ok(_) => Ok(()),
-                        Err(e) => Err(VaultError::Error(e.to_string())),
+                        Ok(_) => Ok((blurhash, aspect_ratio)),
+                        Err(e) => Err(VaultError::io(e, &filename)),
                     },
-                    Err(_) => Err(VaultError::Error(e.to_string())),
+                    Err(_) => Err(VaultError::io(e, &filename)),
                 }
             } else {
-                Err(VaultError::Error(e.to_string()))
+                Err(VaultError::io(e, &filename))
             }
         }
     }
@@ -1,107 +1,645 @@
 // encryption
 use aes::Aes256;
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
 use block_modes::block_padding::Pkcs7;
 use block_modes::{BlockMode, Cbc};
 
-// key generation
-use pbkdf2::pbkdf2_hmac;
-use sha2::Sha256;
+// random nonces
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+// vault header
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
 
 // key memory safety
-use lazy_static::lazy_static;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::RwLock;
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 // Custom error
 use crate::utils::error::VaultError;
+// Key derivation
+use crate::utils::kdf::{KdfConfig, KdfParams};
+// Key slots
+use crate::utils::keyslot::{generate_recovery_key, KeySlot, KeySlotInfo, KeySlotKind};
+// Utils
+use crate::utils::utils::write_atomic;
 
 // alias for the AES-256-CBC encryption mode
 type Aes256Cbc = Cbc<Aes256, Pkcs7>;
 
-const SALT_LEN: usize = 32;
 const KEY_LEN: usize = 32;
 const IV_LEN: usize = 16;
+const GCM_NONCE_LEN: usize = 12;
 
-const SALT: [u8; SALT_LEN] = [
-    17, 128, 16, 104, 193, 198, 63, 155, 239, 14, 180, 237, 137, 144, 175, 49, 118, 108, 13, 147,
-    174, 122, 195, 174, 176, 103, 104, 156, 151, 114, 101, 106,
-];
 pub const VAULT_FILE: &str = ".vault-key";
+
+// Every blob written by this version starts with a small header:
+// magic (4) | format version (1) | algorithm id (1) | nonce (algorithm specific)
+// Blobs without the magic are legacy ones encrypted with the password derived IV.
+const BLOB_MAGIC: &[u8; 4] = b"VLTB";
+const BLOB_VERSION: u8 = 1;
+const BLOB_HEADER_LEN: usize = BLOB_MAGIC.len() + 2;
+
+// Files written in chunks use the chunked algorithm, its nonce field is a random nonce
+// prefix (7) and the chunk size (4, little endian). Every chunk holds chunk size plain
+// bytes plus the tag, only the last one may be shorter. The nonce of a chunk is the
+// prefix, the chunk index (4, big endian) and a byte that is 1 for the last chunk only,
+// so chunks can't be reordered or dropped and the file can't be cut at a chunk boundary.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+const CHUNK_NONCE_PREFIX_LEN: usize = 7;
+const GCM_TAG_LEN: usize = 16;
+pub const CHUNK_HEADER_LEN: usize = BLOB_HEADER_LEN + CHUNK_NONCE_PREFIX_LEN + 4;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum CipherAlgorithm {
+    Aes256Cbc = 1,
+    Aes256Gcm = 2,
+    ChunkedAes256Gcm = 3,
+}
+impl CipherAlgorithm {
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(CipherAlgorithm::Aes256Cbc),
+            2 => Some(CipherAlgorithm::Aes256Gcm),
+            3 => Some(CipherAlgorithm::ChunkedAes256Gcm),
+            _ => None,
+        }
+    }
+    fn nonce_len(&self) -> usize {
+        match self {
+            CipherAlgorithm::Aes256Cbc => IV_LEN,
+            CipherAlgorithm::Aes256Gcm => GCM_NONCE_LEN,
+            CipherAlgorithm::ChunkedAes256Gcm => CHUNK_NONCE_PREFIX_LEN + 4,
+        }
+    }
+}
 pub const VERIFICATION_DATA: &[u8] = b"vault_password_is_correct";
+const DEFAULT_SLOT_LABEL: &str = "Password";
+pub const VAULT_HEADER_VERSION: u32 = 3;
+// AES block size, the length of a legacy header is always a multiple of it
+const LEGACY_BLOCK_LEN: usize = 16;
+
+// Contents of the .vault-key file.
+// Version 3 keeps a list of key slots that each wrap the same random master key.
+// Version 2 had a single password slot stored inline and is read as a one slot header,
+// older versions only hold the encrypted verification data and use the derived key directly.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VaultHeader {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub slots: Vec<KeySlot>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kdf: Option<KdfParams>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "hex")]
+    verification: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "hex")]
+    wrapped_key: Vec<u8>,
+}
+
+// Master key opened by a secret, along with the slot that matched (None for legacy vaults)
+struct UnlockedKey {
+    slot: Option<usize>,
+    master: CryptoParams,
+}
+
+impl VaultHeader {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VaultError> {
+        if VaultHeader::is_legacy(bytes) {
+            // Legacy .vault-key: just the encrypted verification data, keyed with the shared salt
+            return Ok(VaultHeader {
+                version: 0,
+                slots: Vec::new(),
+                kdf: Some(KdfParams::legacy()),
+                verification: bytes.to_vec(),
+                wrapped_key: Vec::new(),
+            });
+        }
+        let version = VaultHeader::version_of(bytes)?;
+        if version > VAULT_HEADER_VERSION {
+            return Err(VaultError::UnsupportedFormat {
+                path: None,
+                details: format!("vault file version {}", version),
+            });
+        }
+        let mut header: VaultHeader =
+            serde_json::from_slice(bytes).map_err(|_| VaultError::Corrupted { path: None })?;
 
-#[derive(Zeroize)]
-struct CryptoParams {
+        if !header.wrapped_key.is_empty() {
+            if let Some(kdf) = header.kdf.take() {
+                header.slots.push(KeySlot {
+                    id: 0,
+                    kind: KeySlotKind::Password,
+                    label: DEFAULT_SLOT_LABEL.to_string(),
+                    created_at: 0,
+                    kdf,
+                    wrapped_key: std::mem::take(&mut header.wrapped_key),
+                });
+            }
+        }
+        if header.slots.is_empty() && (header.kdf.is_none() || header.verification.is_empty()) {
+            return Err(VaultError::Corrupted { path: None });
+        }
+        for kdf in header.kdf_params() {
+            kdf.check_limits()?;
+        }
+        Ok(header)
+    }
+    // Format version of a serialized header, legacy headers are version 0
+    pub fn version_of(bytes: &[u8]) -> Result<u32, VaultError> {
+        #[derive(Deserialize)]
+        struct Version {
+            #[serde(default)]
+            version: u32,
+        }
+
+        if VaultHeader::is_legacy(bytes) {
+            return Ok(0);
+        }
+        serde_json::from_slice::<Version>(bytes)
+            .map(|header| header.version)
+            .map_err(|_| VaultError::Corrupted { path: None })
+    }
+    // Legacy headers are raw AES-CBC output, so about one in 256 starts with `{` too.
+    // Those don't parse as JSON; the block size check keeps most damaged JSON headers
+    // reported as corrupted rather than read as legacy ones.
+    fn is_legacy(bytes: &[u8]) -> bool {
+        !bytes.starts_with(b"{")
+            || (bytes.len().is_multiple_of(LEGACY_BLOCK_LEN)
+                && serde_json::from_slice::<serde::de::IgnoredAny>(bytes).is_err())
+    }
+    pub fn to_bytes(&self) -> Result<Vec<u8>, VaultError> {
+        serde_json::to_vec_pretty(self).map_err(|e| VaultError::Internal {
+            message: format!("Failed to serialize vault file: {}", e),
+        })
+    }
+
+    fn new(password: &str, kdf: KdfParams, master: &CryptoParams) -> Result<Self, VaultError> {
+        let mut header = VaultHeader {
+            version: VAULT_HEADER_VERSION,
+            slots: Vec::new(),
+            kdf: None,
+            verification: Vec::new(),
+            wrapped_key: Vec::new(),
+        };
+        header.add_slot(
+            KeySlotKind::Password,
+            DEFAULT_SLOT_LABEL,
+            password,
+            kdf,
+            master,
+        )?;
+        Ok(header)
+    }
+
+    // Parameters of every slot, legacy vaults have a single one
+    pub fn kdf_params(&self) -> Vec<KdfParams> {
+        match &self.kdf {
+            Some(kdf) if self.slots.is_empty() => vec![kdf.clone()],
+            _ => self.slots.iter().map(|slot| slot.kdf.clone()).collect(),
+        }
+    }
+
+    pub fn slot_infos(&self) -> Vec<KeySlotInfo> {
+        if self.slots.is_empty() {
+            // The password of a legacy vault behaves like a single password slot
+            return vec![KeySlotInfo {
+                id: 0,
+                kind: KeySlotKind::Password,
+                label: DEFAULT_SLOT_LABEL.to_string(),
+                created_at: 0,
+            }];
+        }
+        self.slots.iter().map(KeySlotInfo::from).collect()
+    }
+
+    // Wraps the master key with a key derived from the secret, returns the new slot id
+    fn add_slot(
+        &mut self,
+        kind: KeySlotKind,
+        label: &str,
+        secret: &str,
+        kdf: KdfParams,
+        master: &CryptoParams,
+    ) -> Result<u32, VaultError> {
+        let kek = CryptoParams::derive(&kind.canonical_secret(secret), &kdf)?;
+        let id = self.slots.iter().map(|slot| slot.id + 1).max().unwrap_or(0);
+        self.slots.push(KeySlot {
+            id,
+            kind,
+            label: label.to_string(),
+            created_at: Utc::now().timestamp(),
+            kdf,
+            wrapped_key: encrypt_with_key(&kek.key, &master.to_bytes())?,
+        });
+        Ok(id)
+    }
+
+    fn rewrap_slot(
+        &mut self,
+        index: usize,
+        secret: &str,
+        master: &CryptoParams,
+    ) -> Result<(), VaultError> {
+        let slot = &mut self.slots[index];
+        slot.kind.validate(secret)?;
+        let kdf = slot.kdf.resalted();
+        let kek = CryptoParams::derive(&slot.kind.canonical_secret(secret), &kdf)?;
+        slot.wrapped_key = encrypt_with_key(&kek.key, &master.to_bytes())?;
+        slot.kdf = kdf;
+        Ok(())
+    }
+
+    // Moves a legacy vault over to key slots, keeping the password derived key as master key
+    fn upgrade(&mut self, password: &str, master: &CryptoParams) -> Result<(), VaultError> {
+        if !self.slots.is_empty() {
+            return Ok(());
+        }
+        self.version = VAULT_HEADER_VERSION;
+        self.kdf = None;
+        self.verification.clear();
+        let kdf = KdfParams::new(&KdfConfig::default())?;
+        self.add_slot(
+            KeySlotKind::Password,
+            DEFAULT_SLOT_LABEL,
+            password,
+            kdf,
+            master,
+        )?;
+        Ok(())
+    }
+
+    fn remove_slot(&mut self, id: u32) -> Result<(), VaultError> {
+        let index = self
+            .slots
+            .iter()
+            .position(|slot| slot.id == id)
+            .ok_or_else(|| VaultError::InvalidInput {
+                message: format!("Key slot {} not found", id),
+            })?;
+        if self.slots.len() == 1 {
+            return Err(VaultError::InvalidInput {
+                message: "Can't remove the last key slot of a vault".to_string(),
+            });
+        }
+        self.slots.remove(index);
+        Ok(())
+    }
+
+    // Returns the vault master key, or None when the secret opens no slot
+    fn unlock(&self, secret: &str) -> Result<Option<UnlockedKey>, VaultError> {
+        if let Some(kdf) = self.kdf.as_ref().filter(|_| self.slots.is_empty()) {
+            let kek = CryptoParams::derive(secret, kdf)?;
+            // With a wrong key the verification blob fails to authenticate
+            return match decrypt_with_key(&kek.key, &kek.iv, &self.verification) {
+                Ok(data) if data == VERIFICATION_DATA => Ok(Some(UnlockedKey {
+                    slot: None,
+                    master: kek,
+                })),
+                Ok(_) | Err(VaultError::Corrupted { .. }) => Ok(None),
+                Err(e) => Err(e),
+            };
+        }
+
+        for (index, slot) in self.slots.iter().enumerate() {
+            if !slot.kind.accepts(secret) {
+                continue;
+            }
+            let kek = CryptoParams::derive(&slot.kind.canonical_secret(secret), &slot.kdf)?;
+            match decrypt_with_key(&kek.key, &kek.iv, &slot.wrapped_key) {
+                Ok(bytes) => {
+                    return Ok(Some(UnlockedKey {
+                        slot: Some(index),
+                        master: CryptoParams::from_bytes(&Zeroizing::new(bytes))?,
+                    }))
+                }
+                Err(VaultError::Corrupted { .. }) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(None)
+    }
+}
+
+// The IV is only used for legacy headerless blobs. Vaults created with a random master
+// key never have any, but vaults moved over from password derived keys still need it.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct CryptoParams {
     key: [u8; KEY_LEN],
     iv: [u8; IV_LEN],
 }
-impl ZeroizeOnDrop for CryptoParams {}
-lazy_static! {
-    static ref CRYPTO_PARAMS: RwLock<Option<CryptoParams>> = RwLock::new(None);
+impl CryptoParams {
+    pub(crate) fn random() -> Self {
+        let mut params = CryptoParams {
+            key: [0u8; KEY_LEN],
+            iv: [0u8; IV_LEN],
+        };
+        OsRng.fill_bytes(&mut params.key);
+        OsRng.fill_bytes(&mut params.iv);
+        params
+    }
+
+    fn derive(password: &str, kdf: &KdfParams) -> Result<Self, VaultError> {
+        let mut derived_key = Zeroizing::new([0u8; KEY_LEN + IV_LEN]);
+        kdf.derive(password, &mut derived_key[..])?;
+        CryptoParams::from_bytes(&derived_key[..])
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, VaultError> {
+        if bytes.len() != KEY_LEN + IV_LEN {
+            return Err(VaultError::Corrupted { path: None });
+        }
+        let mut params = CryptoParams {
+            key: [0u8; KEY_LEN],
+            iv: [0u8; IV_LEN],
+        };
+        params.key.copy_from_slice(&bytes[..KEY_LEN]);
+        params.iv.copy_from_slice(&bytes[KEY_LEN..]);
+        Ok(params)
+    }
+
+    fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(KEY_LEN + IV_LEN));
+        bytes.extend_from_slice(&self.key);
+        bytes.extend_from_slice(&self.iv);
+        bytes
+    }
 }
 
 // ------ cryptography functions -------
-pub fn encrypt_data(data: &[u8]) -> Result<Vec<u8>, VaultError> {
-    match get_crypto_params()? {
-        Some((key, iv)) => match Aes256Cbc::new_from_slices(&key, &iv) {
-            Ok(cipher) => Ok(cipher.encrypt_vec(data)),
-            Err(e) => Err(VaultError::Error(e.to_string())),
-        },
-        None => Err(VaultError::Error(
-            "Could not get key (empty or none)".to_string(),
-        )),
-    }
-}
-
-pub fn decrypt_data(encrypted_data: &[u8]) -> Result<Vec<u8>, VaultError> {
-    match get_crypto_params()? {
-        Some((key, iv)) => match Aes256Cbc::new_from_slices(&key, &iv) {
-            Ok(cipher) => match cipher.decrypt_vec(encrypted_data) {
-                Ok(decrypted_data) => Ok(decrypted_data),
-                Err(e) => Err(VaultError::Error(e.to_string())),
+pub fn encrypt_data(key: &CryptoParams, data: &[u8]) -> Result<Vec<u8>, VaultError> {
+    encrypt_with_key(&key.key, data)
+}
+
+pub fn decrypt_data(key: &CryptoParams, encrypted_data: &[u8]) -> Result<Vec<u8>, VaultError> {
+    decrypt_with_key(&key.key, &key.iv, encrypted_data)
+}
+
+fn encrypt_with_key(key: &[u8; KEY_LEN], data: &[u8]) -> Result<Vec<u8>, VaultError> {
+    let algorithm = CipherAlgorithm::Aes256Gcm;
+    let mut nonce = [0u8; GCM_NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let mut blob = Vec::with_capacity(BLOB_HEADER_LEN + nonce.len() + data.len() + 16);
+    blob.extend_from_slice(BLOB_MAGIC);
+    blob.push(BLOB_VERSION);
+    blob.push(algorithm as u8);
+
+    // The header is authenticated too, so it can't be swapped to downgrade the algorithm
+    let cipher = Aes256Gcm::new_from_slice(key).map_err(|e| VaultError::Internal {
+        message: e.to_string(),
+    })?;
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: data,
+                aad: &blob,
             },
-            Err(e) => Err(VaultError::Error(e.to_string())),
-        },
-        None => Err(VaultError::Error(
-            "Could not get key (empty or none)".to_string(),
-        )),
+        )
+        .map_err(|e| VaultError::Internal {
+            message: e.to_string(),
+        })?;
+
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(&ciphertext);
+    Ok(blob)
+}
+
+fn decrypt_with_key(
+    key: &[u8; KEY_LEN],
+    legacy_iv: &[u8; IV_LEN],
+    encrypted_data: &[u8],
+) -> Result<Vec<u8>, VaultError> {
+    match split_header(encrypted_data)? {
+        Some(BlobParts {
+            algorithm: CipherAlgorithm::Aes256Gcm,
+            nonce,
+            ciphertext,
+        }) => {
+            let cipher = Aes256Gcm::new_from_slice(key).map_err(|e| VaultError::Internal {
+                message: e.to_string(),
+            })?;
+            cipher
+                .decrypt(
+                    Nonce::from_slice(nonce),
+                    Payload {
+                        msg: ciphertext,
+                        aad: &encrypted_data[..BLOB_HEADER_LEN],
+                    },
+                )
+                .map_err(|_| VaultError::Corrupted { path: None })
+        }
+        Some(BlobParts {
+            algorithm: CipherAlgorithm::Aes256Cbc,
+            nonce,
+            ciphertext,
+        }) => decrypt_cbc(key, nonce, ciphertext),
+        Some(BlobParts {
+            algorithm: CipherAlgorithm::ChunkedAes256Gcm,
+            ..
+        }) => {
+            let cipher = ChunkCipher::with_header(key, encrypted_data)?;
+            let mut plain = Vec::new();
+            let mut chunks = encrypted_data[cipher.header.len()..]
+                .chunks(cipher.sealed_chunk_len())
+                .peekable();
+            let mut index = 0;
+            // An empty file still has its last chunk
+            if chunks.peek().is_none() {
+                return Err(VaultError::Corrupted { path: None });
+            }
+            while let Some(chunk) = chunks.next() {
+                plain.extend(cipher.open(index, chunks.peek().is_none(), chunk)?);
+                index += 1;
+            }
+            Ok(plain)
+        }
+        // Legacy headerless blob, encrypted with the IV derived from the password
+        None => decrypt_cbc(key, legacy_iv, encrypted_data),
     }
 }
 
-fn derive_key_and_iv(password: &str, salt: &[u8]) -> ([u8; KEY_LEN], [u8; IV_LEN]) {
-    let mut derived_key = [0u8; KEY_LEN + IV_LEN];
-    pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, 100_000, &mut derived_key);
+fn decrypt_cbc(key: &[u8; KEY_LEN], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, VaultError> {
+    let cipher = Aes256Cbc::new_from_slices(key, iv).map_err(|e| VaultError::Internal {
+        message: e.to_string(),
+    })?;
+    // A bad padding is the only integrity signal CBC gives us
+    cipher
+        .decrypt_vec(ciphertext)
+        .map_err(|_| VaultError::Corrupted { path: None })
+}
 
-    let mut key = [0u8; KEY_LEN];
-    let mut iv = [0u8; IV_LEN];
-    key.copy_from_slice(&derived_key[..KEY_LEN]);
-    iv.copy_from_slice(&derived_key[KEY_LEN..]);
+struct BlobParts<'a> {
+    algorithm: CipherAlgorithm,
+    nonce: &'a [u8],
+    ciphertext: &'a [u8],
+}
 
-    (key, iv)
+// Returns None for legacy headerless blobs
+fn split_header(data: &[u8]) -> Result<Option<BlobParts<'_>>, VaultError> {
+    if data.len() < BLOB_HEADER_LEN || !data.starts_with(BLOB_MAGIC) {
+        return Ok(None);
+    }
+    let version = data[BLOB_MAGIC.len()];
+    if version != BLOB_VERSION {
+        return Err(VaultError::UnsupportedFormat {
+            path: None,
+            details: format!("encrypted data version {}", version),
+        });
+    }
+    let algorithm_id = data[BLOB_MAGIC.len() + 1];
+    let algorithm =
+        CipherAlgorithm::from_id(algorithm_id).ok_or_else(|| VaultError::UnsupportedFormat {
+            path: None,
+            details: format!("encryption algorithm {}", algorithm_id),
+        })?;
+
+    let body = &data[BLOB_HEADER_LEN..];
+    if body.len() < algorithm.nonce_len() {
+        return Err(VaultError::Corrupted { path: None });
+    }
+    let (nonce, ciphertext) = body.split_at(algorithm.nonce_len());
+    Ok(Some(BlobParts {
+        algorithm,
+        nonce,
+        ciphertext,
+    }))
 }
 
-fn set_crypto_params(password: &str) -> Result<bool, VaultError> {
-    match CRYPTO_PARAMS.write() {
-        Ok(mut params) => {
-            let (derived_key, derived_iv) = derive_key_and_iv(password, &SALT);
-            *params = Some(CryptoParams {
-                key: derived_key,
-                iv: derived_iv,
-            });
-            Ok(true)
+// Seals and opens the chunks of one chunked file, see CHUNK_SIZE
+pub struct ChunkCipher {
+    cipher: Aes256Gcm,
+    header: Vec<u8>,
+    chunk_size: usize,
+}
+impl ChunkCipher {
+    // Cipher for a new file with a random nonce prefix
+    pub fn new(key: &CryptoParams) -> Result<Self, VaultError> {
+        let mut prefix = [0u8; CHUNK_NONCE_PREFIX_LEN];
+        OsRng.fill_bytes(&mut prefix);
+
+        let mut header = Vec::with_capacity(BLOB_HEADER_LEN + prefix.len() + 4);
+        header.extend_from_slice(BLOB_MAGIC);
+        header.push(BLOB_VERSION);
+        header.push(CipherAlgorithm::ChunkedAes256Gcm as u8);
+        header.extend_from_slice(&prefix);
+        header.extend_from_slice(&(CHUNK_SIZE as u32).to_le_bytes());
+        ChunkCipher::from_parts(&key.key, header, CHUNK_SIZE)
+    }
+
+    // Whether `data`, the start of a file, is in the chunked format
+    pub fn is_chunked(data: &[u8]) -> bool {
+        matches!(
+            split_header(data),
+            Ok(Some(BlobParts {
+                algorithm: CipherAlgorithm::ChunkedAes256Gcm,
+                ..
+            }))
+        )
+    }
+
+    // Cipher of an existing file, `data` starts with its header
+    pub fn open_header(key: &CryptoParams, data: &[u8]) -> Result<Self, VaultError> {
+        ChunkCipher::with_header(&key.key, data)
+    }
+
+    fn with_header(key: &[u8; KEY_LEN], data: &[u8]) -> Result<Self, VaultError> {
+        let nonce = match split_header(data)? {
+            Some(BlobParts {
+                algorithm: CipherAlgorithm::ChunkedAes256Gcm,
+                nonce,
+                ..
+            }) => nonce,
+            _ => {
+                return Err(VaultError::UnsupportedFormat {
+                    path: None,
+                    details: "not a chunked file".to_string(),
+                })
+            }
+        };
+        let mut chunk_size = [0u8; 4];
+        chunk_size.copy_from_slice(&nonce[CHUNK_NONCE_PREFIX_LEN..]);
+        let chunk_size = u32::from_le_bytes(chunk_size) as usize;
+        // Bounds what a tampered header can make a reader allocate
+        if chunk_size == 0 || chunk_size > 16 * CHUNK_SIZE {
+            return Err(VaultError::Corrupted { path: None });
+        }
+
+        let header = data[..BLOB_HEADER_LEN + nonce.len()].to_vec();
+        ChunkCipher::from_parts(key, header, chunk_size)
+    }
+
+    fn from_parts(
+        key: &[u8; KEY_LEN],
+        header: Vec<u8>,
+        chunk_size: usize,
+    ) -> Result<Self, VaultError> {
+        let cipher = Aes256Gcm::new_from_slice(key).map_err(|e| VaultError::Internal {
+            message: e.to_string(),
+        })?;
+        Ok(ChunkCipher {
+            cipher,
+            header,
+            chunk_size,
+        })
+    }
+
+    pub fn header(&self) -> &[u8] {
+        &self.header
+    }
+
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    // Size of a full chunk once sealed
+    pub fn sealed_chunk_len(&self) -> usize {
+        self.chunk_size + GCM_TAG_LEN
+    }
+
+    pub fn seal(&self, index: u32, last: bool, chunk: &[u8]) -> Result<Vec<u8>, VaultError> {
+        self.cipher
+            .encrypt(
+                Nonce::from_slice(&self.nonce(index, last)),
+                Payload {
+                    msg: chunk,
+                    aad: &self.header,
+                },
+            )
+            .map_err(|e| VaultError::Internal {
+                message: e.to_string(),
+            })
+    }
+
+    pub fn open(&self, index: u32, last: bool, chunk: &[u8]) -> Result<Vec<u8>, VaultError> {
+        // Only the last chunk may be short, a short chunk anywhere else was cut
+        if chunk.len() > self.sealed_chunk_len()
+            || (!last && chunk.len() != self.sealed_chunk_len())
+        {
+            return Err(VaultError::Corrupted { path: None });
         }
-        Err(e) => Err(VaultError::Error(e.to_string())),
+        self.cipher
+            .decrypt(
+                Nonce::from_slice(&self.nonce(index, last)),
+                Payload {
+                    msg: chunk,
+                    aad: &self.header,
+                },
+            )
+            .map_err(|_| VaultError::Corrupted { path: None })
     }
-}
 
-fn get_crypto_params() -> Result<Option<([u8; KEY_LEN], [u8; IV_LEN])>, VaultError> {
-    match CRYPTO_PARAMS.read() {
-        Ok(cryptoparams_option) => match cryptoparams_option.as_ref() {
-            Some(cryptoparams) => Ok(Some((cryptoparams.key.clone(), cryptoparams.iv.clone()))),
-            None => Ok(None),
-        },
-        Err(e) => Err(VaultError::Error(e.to_string())),
+    fn nonce(&self, index: u32, last: bool) -> [u8; GCM_NONCE_LEN] {
+        let mut nonce = [0u8; GCM_NONCE_LEN];
+        nonce[..CHUNK_NONCE_PREFIX_LEN].copy_from_slice(
+            &self.header[BLOB_HEADER_LEN..BLOB_HEADER_LEN + CHUNK_NONCE_PREFIX_LEN],
+        );
+        nonce[CHUNK_NONCE_PREFIX_LEN..GCM_NONCE_LEN - 1].copy_from_slice(&index.to_be_bytes());
+        nonce[GCM_NONCE_LEN - 1] = last as u8;
+        nonce
     }
 }
 
@@ -109,26 +647,100 @@ pub fn check_validation_data_exists(dir: &str) -> bool {
     let full_path = Path::new(dir).join(VAULT_FILE);
     Path::new(&full_path).exists()
 }
-fn load_validation_data(path: PathBuf) -> Result<Vec<u8>, VaultError> {
-    fs::read(&path).map_err(|e| VaultError::Error(format!("Failed to read vault file: {}", e)))
+fn load_validation_data(path: PathBuf) -> Result<VaultHeader, VaultError> {
+    let bytes = fs::read(&path).map_err(|e| VaultError::io(e, &path))?;
+    VaultHeader::from_bytes(&bytes).map_err(|e| e.at(&path))
+}
+fn store_validation_data(path: PathBuf, header: &VaultHeader) -> Result<(), VaultError> {
+    // Losing the key file halfway through a write would lock the user out for good
+    write_atomic(&path, &header.to_bytes()?)
 }
-pub fn save_validation_data(dir: &str, password: &str) -> Result<(), VaultError> {
-    fs::create_dir_all(dir)
-        .map_err(|e| VaultError::Error(format!("Failed to create directory '{}': {}", dir, e)))?;
+// Creates the .vault-key of a new vault and returns its master key
+pub fn create_vault_key(
+    dir: &str,
+    password: &str,
+    kdf: &KdfConfig,
+) -> Result<CryptoParams, VaultError> {
+    fs::create_dir_all(dir).map_err(|e| VaultError::io(e, dir))?;
     let full_path = Path::new(dir).join(VAULT_FILE);
-    set_crypto_params(password)?;
-    let verification_data_encrypted = encrypt_data(VERIFICATION_DATA)?;
-    fs::write(&full_path, verification_data_encrypted)
-        .map_err(|e| VaultError::Error(format!("Failed to save vault file: {}", e)))
+    let master = CryptoParams::random();
+    let header = VaultHeader::new(password, KdfParams::new(kdf)?, &master)?;
+    store_validation_data(full_path, &header)?;
+    Ok(master)
 }
-pub fn check_password(password: &str, dir: &str) -> Result<bool, VaultError> {
+// Returns the master key of the vault, or None when the password is wrong
+pub fn open_vault_key(dir: &str, password: &str) -> Result<Option<CryptoParams>, VaultError> {
     let full_path = Path::new(dir).join(VAULT_FILE);
-    let verification_data_encrypted = load_validation_data(full_path)?;
-    set_crypto_params(password)?;
-    if decrypt_data(&verification_data_encrypted)? == VERIFICATION_DATA {
-        return Ok(true);
+    let header = load_validation_data(full_path)?;
+    Ok(header.unlock(password)?.map(|unlocked| unlocked.master))
+}
+// Only the master key gets rewrapped, files stay encrypted with the same key.
+// Vaults that still use the password derived key are moved to key slots here.
+pub fn change_validation_password(
+    dir: &str,
+    old_password: &str,
+    new_password: &str,
+) -> Result<(), VaultError> {
+    let full_path = Path::new(dir).join(VAULT_FILE);
+    let mut header = load_validation_data(full_path.clone())?;
+    let unlocked = header
+        .unlock(old_password)?
+        .ok_or(VaultError::IncorrectPassword)?;
+    match unlocked.slot {
+        Some(index) => header.rewrap_slot(index, new_password, &unlocked.master)?,
+        None => header.upgrade(new_password, &unlocked.master)?,
     }
-    Ok(false)
+    store_validation_data(full_path, &header)
+}
+
+// ------ key slots -------
+pub fn add_validation_slot(
+    dir: &str,
+    password: &str,
+    kind: KeySlotKind,
+    secret: &str,
+    label: &str,
+) -> Result<u32, VaultError> {
+    kind.validate(secret)?;
+    let full_path = Path::new(dir).join(VAULT_FILE);
+    let mut header = load_validation_data(full_path.clone())?;
+    let unlocked = header
+        .unlock(password)?
+        .ok_or(VaultError::IncorrectPassword)?;
+    header.upgrade(password, &unlocked.master)?;
+    let kdf = KdfParams::new(&KdfConfig::default())?;
+    let id = header.add_slot(kind, label, secret, kdf, &unlocked.master)?;
+    store_validation_data(full_path, &header)?;
+    Ok(id)
+}
+pub fn add_recovery_slot(dir: &str, password: &str) -> Result<String, VaultError> {
+    let recovery_key = generate_recovery_key();
+    add_validation_slot(
+        dir,
+        password,
+        KeySlotKind::RecoveryKey,
+        &recovery_key,
+        "Recovery key",
+    )?;
+    Ok(recovery_key)
+}
+pub fn list_validation_slots(dir: &str) -> Result<Vec<KeySlotInfo>, VaultError> {
+    let full_path = Path::new(dir).join(VAULT_FILE);
+    Ok(load_validation_data(full_path)?.slot_infos())
+}
+pub fn validation_kdf_params(dir: &str) -> Result<Vec<KdfParams>, VaultError> {
+    let full_path = Path::new(dir).join(VAULT_FILE);
+    Ok(load_validation_data(full_path)?.kdf_params())
+}
+pub fn remove_validation_slot(dir: &str, password: &str, slot_id: u32) -> Result<(), VaultError> {
+    let full_path = Path::new(dir).join(VAULT_FILE);
+    let mut header = load_validation_data(full_path.clone())?;
+    let unlocked = header
+        .unlock(password)?
+        .ok_or(VaultError::IncorrectPassword)?;
+    header.upgrade(password, &unlocked.master)?;
+    header.remove_slot(slot_id)?;
+    store_validation_data(full_path, &header)
 }
 
 // OOP for temporary password based decryption
@@ -138,18 +750,118 @@ pub struct PasswordDecrypter {
     iv: [u8; IV_LEN],
 }
 impl PasswordDecrypter {
-    pub fn new(password: &str) -> Self {
-        let (key, iv) = derive_key_and_iv(password, &SALT);
-        Self { key: key, iv: iv }
+    pub fn new(password: &str, header: &VaultHeader) -> Result<Self, VaultError> {
+        let unlocked = header
+            .unlock(password)?
+            .ok_or(VaultError::IncorrectPassword)?;
+        Ok(Self {
+            key: unlocked.master.key,
+            iv: unlocked.master.iv,
+        })
     }
     pub fn decrypt(&self, encrypted_data: &[u8]) -> Result<Vec<u8>, VaultError> {
-        let cipher = Aes256Cbc::new_from_slices(&self.key, &self.iv)
-            .map_err(|e| VaultError::Error(format!("Failed to create cipher: {}", e)))?;
-        cipher.decrypt_vec(encrypted_data).map_err(|_| {
-            VaultError::Error(
-                "Decryption failed. The password may be incorrect or the data is corrupt."
-                    .to_string(),
-            )
-        })
+        decrypt_with_key(&self.key, &self.iv, encrypted_data)
+    }
+    // For entries that are decrypted a chunk at a time, see stream::decrypt_reader
+    pub fn crypto_params(&self) -> CryptoParams {
+        CryptoParams {
+            key: self.key,
+            iv: self.iv,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header_with(kdf: KdfParams) -> Vec<u8> {
+        let header = VaultHeader {
+            version: VAULT_HEADER_VERSION,
+            slots: vec![KeySlot {
+                id: 0,
+                kind: KeySlotKind::Password,
+                label: DEFAULT_SLOT_LABEL.to_string(),
+                created_at: 0,
+                kdf,
+                wrapped_key: vec![0; 60],
+            }],
+            kdf: None,
+            verification: Vec::new(),
+            wrapped_key: Vec::new(),
+        };
+        header.to_bytes().unwrap()
+    }
+
+    // Legacy key of the password "password77", which happens to start with `{`
+    const LEGACY_BRACE_KEY: &str =
+        "7ba9f3e3e7e96e9171e535a8d3d152301a774020572ead11673a351d6c96533f";
+
+    #[test]
+    fn legacy_key_starting_with_a_brace() {
+        let bytes = hex::decode(LEGACY_BRACE_KEY).unwrap();
+        assert_eq!(bytes[0], b'{');
+        assert_eq!(VaultHeader::version_of(&bytes).unwrap(), 0);
+
+        let header = VaultHeader::from_bytes(&bytes).unwrap();
+        assert_eq!(header.version, 0);
+        assert_eq!(header.kdf_params(), vec![KdfParams::legacy()]);
+        assert!(header.unlock("password77").unwrap().is_some());
+        assert!(header.unlock("password78").unwrap().is_none());
+    }
+
+    #[test]
+    fn damaged_json_header_is_corrupted() {
+        let mut bytes = header_with(KdfParams::legacy());
+        bytes.truncate(bytes.len() / 2);
+        if bytes.len().is_multiple_of(LEGACY_BLOCK_LEN) {
+            bytes.pop();
+        }
+        assert!(matches!(
+            VaultHeader::from_bytes(&bytes),
+            Err(VaultError::Corrupted { .. })
+        ));
+    }
+
+    #[test]
+    fn oversized_kdf_params_are_rejected() {
+        let oversized = [
+            KdfParams::Argon2id {
+                salt: vec![0; 32],
+                memory_kib: u32::MAX,
+                iterations: 2,
+                parallelism: 1,
+            },
+            KdfParams::Argon2id {
+                salt: vec![0; 32],
+                memory_kib: 19 * 1024,
+                iterations: u32::MAX,
+                parallelism: 1,
+            },
+            KdfParams::Argon2id {
+                salt: vec![0; 32],
+                memory_kib: 19 * 1024,
+                iterations: 2,
+                parallelism: 255,
+            },
+            KdfParams::Pbkdf2Sha256 {
+                salt: vec![0; 32],
+                iterations: u32::MAX,
+            },
+        ];
+        for kdf in oversized {
+            assert!(matches!(
+                VaultHeader::from_bytes(&header_with(kdf)),
+                Err(VaultError::UnsupportedFormat { .. })
+            ));
+        }
+
+        let usable = KdfParams::new(&KdfConfig::default()).unwrap();
+        assert_eq!(
+            VaultHeader::from_bytes(&header_with(usable.clone()))
+                .unwrap()
+                .kdf_params(),
+            vec![usable]
+        );
     }
 }
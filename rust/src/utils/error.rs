@@ -1,15 +1,195 @@
 use core::fmt;
+use std::{
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
 
+use zip::result::ZipError;
+
+#[derive(Debug)]
 pub enum VaultError {
-    Error(String),
+    // Bugs and failures the user can't do anything about, including caught panics
+    Internal {
+        message: String,
+    },
     IncorrectPassword,
+    Locked,
+    Corrupted {
+        path: Option<String>,
+    },
+    UnsupportedFormat {
+        path: Option<String>,
+        details: String,
+    },
+    NotFound {
+        path: String,
+    },
+    AlreadyExists {
+        path: String,
+    },
+    PermissionDenied {
+        path: String,
+    },
+    InsufficientSpace {
+        path: String,
+    },
+    Io {
+        kind: IoErrorKind,
+        path: Option<String>,
+        message: String,
+    },
+    PathOutsideVault {
+        path: String,
+    },
+    InvalidInput {
+        message: String,
+    },
+    InvalidBackup {
+        path: String,
+        reason: String,
+    },
+    BackupVersionMismatch {
+        found: u32,
+        supported: u32,
+    },
+    // Stopped through a CancelToken
+    Cancelled,
+}
+
+// The io errors that don't already have a variant of their own
+#[derive(Debug)]
+pub enum IoErrorKind {
+    Interrupted,
+    TimedOut,
+    UnexpectedEof,
+    InvalidData,
+    ReadOnly,
+    Busy,
+    Other,
+}
+
+impl VaultError {
+    // Maps an io error on `path` to the matching variant
+    pub fn io(error: io::Error, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_string_lossy().to_string();
+        let kind = match error.kind() {
+            io::ErrorKind::NotFound => return VaultError::NotFound { path },
+            io::ErrorKind::AlreadyExists => return VaultError::AlreadyExists { path },
+            io::ErrorKind::PermissionDenied => return VaultError::PermissionDenied { path },
+            io::ErrorKind::StorageFull => return VaultError::InsufficientSpace { path },
+            io::ErrorKind::Interrupted => IoErrorKind::Interrupted,
+            io::ErrorKind::TimedOut => IoErrorKind::TimedOut,
+            io::ErrorKind::UnexpectedEof => IoErrorKind::UnexpectedEof,
+            io::ErrorKind::InvalidData => IoErrorKind::InvalidData,
+            io::ErrorKind::ReadOnlyFilesystem => IoErrorKind::ReadOnly,
+            io::ErrorKind::ResourceBusy => IoErrorKind::Busy,
+            _ => IoErrorKind::Other,
+        };
+        VaultError::Io {
+            kind,
+            path: Some(path),
+            message: error.to_string(),
+        }
+    }
+
+    pub fn zip(error: ZipError, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        match error {
+            ZipError::Io(e) => VaultError::io(e, path),
+            ZipError::FileNotFound => VaultError::NotFound {
+                path: path.to_string_lossy().to_string(),
+            },
+            ZipError::UnsupportedArchive(details) => VaultError::UnsupportedFormat {
+                path: Some(path.to_string_lossy().to_string()),
+                details: details.to_string(),
+            },
+            ZipError::InvalidArchive(reason) => VaultError::InvalidBackup {
+                path: path.to_string_lossy().to_string(),
+                reason: reason.to_string(),
+            },
+        }
+    }
+
+    // Fills in the path of errors raised by code that doesn't know which file it's working on
+    pub fn at(self, path: impl AsRef<Path>) -> Self {
+        let path = Some(path.as_ref().to_string_lossy().to_string());
+        match self {
+            VaultError::Corrupted { path: None } => VaultError::Corrupted { path },
+            VaultError::UnsupportedFormat {
+                path: None,
+                details,
+            } => VaultError::UnsupportedFormat { path, details },
+            VaultError::Io {
+                kind,
+                path: None,
+                message,
+            } => VaultError::Io {
+                kind,
+                path,
+                message,
+            },
+            error => error,
+        }
+    }
 }
 
 impl fmt::Display for VaultError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             VaultError::IncorrectPassword => write!(f, "Incorrect password provided."),
-            VaultError::Error(s) => write!(f, "{}", s),
+            VaultError::Locked => write!(f, "Vault is locked."),
+            VaultError::Corrupted { path: Some(path) } => {
+                write!(f, "'{}' is corrupted or has been tampered with.", path)
+            }
+            VaultError::Corrupted { path: None } => {
+                write!(f, "Encrypted data is corrupted or has been tampered with.")
+            }
+            VaultError::UnsupportedFormat { path, details } => match path {
+                Some(path) => write!(f, "Unsupported format in '{}': {}", path, details),
+                None => write!(f, "Unsupported format: {}", details),
+            },
+            VaultError::NotFound { path } => write!(f, "'{}' does not exist.", path),
+            VaultError::AlreadyExists { path } => write!(f, "'{}' already exists.", path),
+            VaultError::PermissionDenied { path } => {
+                write!(f, "Permission denied for '{}'.", path)
+            }
+            VaultError::InsufficientSpace { path } => {
+                write!(f, "Not enough space left to write '{}'.", path)
+            }
+            VaultError::Io { path, message, .. } => match path {
+                Some(path) => write!(f, "I/O error on '{}': {}", path, message),
+                None => write!(f, "I/O error: {}", message),
+            },
+            VaultError::PathOutsideVault { path } => {
+                write!(f, "Path '{}' is outside the vault.", path)
+            }
+            VaultError::InvalidInput { message } => write!(f, "{}", message),
+            VaultError::InvalidBackup { path, reason } => {
+                write!(f, "'{}' is not a valid backup: {}", path, reason)
+            }
+            VaultError::BackupVersionMismatch { found, supported } => write!(
+                f,
+                "Backup format version {} is newer than the supported version {}.",
+                found, supported
+            ),
+            VaultError::Cancelled => write!(f, "The operation was cancelled."),
+            VaultError::Internal { message } => write!(f, "Internal error: {}", message),
         }
     }
 }
+
+// Every API entry point runs inside this so a panic comes back to Dart as
+// VaultError::Internal instead of unwinding across the FFI boundary
+pub fn catch_panic<T>(f: impl FnOnce() -> Result<T, VaultError>) -> Result<T, VaultError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => "unknown panic".to_string(),
+            },
+        };
+        Err(VaultError::Internal { message })
+    })
+}
@@ -0,0 +1,512 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+// Custom error
+use crate::utils::error::VaultError;
+// Encrytion
+use crate::utils::encryption::{decrypt_data, encrypt_data, CryptoParams};
+// Utils
+use crate::utils::utils::write_atomic;
+
+pub const INDEX_FILE: &str = ".index";
+// Per-file sidecars used before the index, imported once and then removed
+const LEGACY_HASH_DIR: &str = ".hash";
+
+// Length prefix of every record in the index file
+const RECORD_LEN_BYTES: usize = 4;
+// Records written on top of the live entries before the file gets rewritten
+const COMPACT_SLACK: usize = 64;
+
+lazy_static! {
+    // Appends to the same index from two threads must not interleave
+    static ref INDEX_LOCK: Mutex<Indexes> = Mutex::new(Indexes::default());
+}
+
+// Everything the album view needs about one media file, without decrypting it
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MediaEntry {
+    pub blurhash: Option<String>,
+    pub aspect_ratio: Option<f32>,
+    // Size of the plain data, unknown for entries imported from .hash sidecars
+    pub size: Option<u64>,
+    pub mime_type: Option<String>,
+    pub original_name: Option<String>,
+    pub created_at: i64,
+    pub modified_at: Option<i64>,
+    // SHA-256 of the plain data, filled in when a restore first needs it for older entries
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "kebab-case")]
+enum IndexRecord {
+    Put { name: String, entry: MediaEntry },
+    Remove { name: String },
+}
+
+// Encrypted log of changes to the media of one album. Every change is appended as its
+// own record so saving one photo doesn't rewrite the whole index, and the log is
+// rewritten as a snapshot once it's mostly stale records.
+pub struct AlbumIndex {
+    path: PathBuf,
+    entries: HashMap<String, MediaEntry>,
+    records: usize,
+    // Length and modification time of the index file as last read or written, None while
+    // there is none
+    stamp: Option<(u64, SystemTime)>,
+}
+
+// Indexes opened so far by album folder, so a bulk import doesn't read and decrypt the
+// whole index again for every file it adds
+#[derive(Default)]
+pub struct Indexes {
+    albums: HashMap<PathBuf, AlbumIndex>,
+}
+
+// Held while an index is opened and changed
+pub fn lock_indexes() -> MutexGuard<'static, Indexes> {
+    // A panic while holding it at worst leaves an index that's reloaded on the next open
+    INDEX_LOCK.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// Drops every cached index, their entries are only readable with the key
+pub fn forget_indexes() {
+    lock_indexes().albums.clear();
+}
+
+impl Indexes {
+    // The index of the album in `dir`. A cached one is reloaded when the file no longer
+    // matches it, like when the album was deleted or a write failed halfway.
+    pub fn open(&mut self, dir: &Path, key: &CryptoParams) -> Result<&mut AlbumIndex, VaultError> {
+        match self.albums.entry(dir.to_path_buf()) {
+            Entry::Occupied(mut cached) => {
+                if !cached.get().is_current() {
+                    cached.insert(AlbumIndex::load(dir, key)?);
+                }
+                Ok(cached.into_mut())
+            }
+            Entry::Vacant(vacant) => Ok(vacant.insert(AlbumIndex::load(dir, key)?)),
+        }
+    }
+}
+
+impl AlbumIndex {
+    // Loads the index of the album in `dir`, importing .hash sidecars the first time
+    fn load(dir: &Path, key: &CryptoParams) -> Result<Self, VaultError> {
+        let mut index = AlbumIndex {
+            path: dir.join(INDEX_FILE),
+            entries: HashMap::new(),
+            records: 0,
+            stamp: None,
+        };
+
+        let bytes = match fs::read(&index.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                index.migrate_sidecars(dir, key)?;
+                return Ok(index);
+            }
+            Err(e) => return Err(VaultError::io(e, &index.path)),
+        };
+        index.stamp = file_stamp(&index.path);
+
+        let mut offset = 0;
+        let mut skipped = 0;
+        while bytes.len() - offset >= RECORD_LEN_BYTES {
+            let mut len = [0u8; RECORD_LEN_BYTES];
+            len.copy_from_slice(&bytes[offset..offset + RECORD_LEN_BYTES]);
+            let start = offset + RECORD_LEN_BYTES;
+            let end = start + u32::from_le_bytes(len) as usize;
+            if end > bytes.len() {
+                break;
+            }
+            offset = end;
+
+            // A damaged record only loses that change, not the whole album
+            let record = decrypt_data(key, &bytes[start..end])
+                .ok()
+                .and_then(|plain| serde_json::from_slice::<IndexRecord>(&plain).ok());
+            match record {
+                Some(record) => index.apply(record),
+                None => skipped += 1,
+            }
+        }
+
+        // Not a single readable record is more likely the wrong key than damage, and
+        // compacting would throw the whole index away
+        if skipped > 0 && index.records == 0 {
+            return Err(VaultError::Corrupted {
+                path: Some(index.path.to_string_lossy().to_string()),
+            });
+        }
+        // A record cut short by a crash is dropped, everything before it is intact
+        if skipped > 0 || offset < bytes.len() {
+            index.compact(key)?;
+        }
+        Ok(index)
+    }
+
+    fn is_current(&self) -> bool {
+        file_stamp(&self.path) == self.stamp
+    }
+
+    pub fn entries(&self) -> &HashMap<String, MediaEntry> {
+        &self.entries
+    }
+
+    pub fn get(&self, name: &str) -> Option<&MediaEntry> {
+        self.entries.get(name)
+    }
+
+    pub fn put(
+        &mut self,
+        name: &str,
+        entry: MediaEntry,
+        key: &CryptoParams,
+    ) -> Result<(), VaultError> {
+        self.append(
+            IndexRecord::Put {
+                name: name.to_string(),
+                entry,
+            },
+            key,
+        )
+    }
+
+    pub fn remove(
+        &mut self,
+        name: &str,
+        key: &CryptoParams,
+    ) -> Result<Option<MediaEntry>, VaultError> {
+        let entry = self.entries.get(name).cloned();
+        if entry.is_some() {
+            self.append(
+                IndexRecord::Remove {
+                    name: name.to_string(),
+                },
+                key,
+            )?;
+        }
+        Ok(entry)
+    }
+
+    fn apply(&mut self, record: IndexRecord) {
+        match record {
+            IndexRecord::Put { name, entry } => {
+                self.entries.insert(name, entry);
+            }
+            IndexRecord::Remove { name } => {
+                self.entries.remove(&name);
+            }
+        }
+        self.records += 1;
+    }
+
+    fn append(&mut self, record: IndexRecord, key: &CryptoParams) -> Result<(), VaultError> {
+        let bytes = encode_record(&record, key)?;
+
+        // One write per record, a crash leaves at most a truncated last record
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| VaultError::io(e, &self.path))?;
+        file.write_all(&bytes)
+            .and_then(|_| file.sync_data())
+            .map_err(|e| VaultError::io(e, &self.path))?;
+        self.stamp = file_stamp(&self.path);
+        self.apply(record);
+
+        if self.records > self.entries.len() * 2 + COMPACT_SLACK {
+            self.compact(key)?;
+        }
+        Ok(())
+    }
+
+    // Replaces the log with one record per live entry
+    fn compact(&mut self, key: &CryptoParams) -> Result<(), VaultError> {
+        let mut bytes = Vec::new();
+        for (name, entry) in &self.entries {
+            bytes.extend(encode_record(
+                &IndexRecord::Put {
+                    name: name.clone(),
+                    entry: entry.clone(),
+                },
+                key,
+            )?);
+        }
+        write_atomic(&self.path, &bytes)?;
+        self.stamp = file_stamp(&self.path);
+        self.records = self.entries.len();
+        Ok(())
+    }
+
+    // Albums from before the index kept "<blurhash> <aspect ratio>" in .hash/<name>
+    fn migrate_sidecars(&mut self, dir: &Path, key: &CryptoParams) -> Result<(), VaultError> {
+        let hash_dir = dir.join(LEGACY_HASH_DIR);
+        let sidecars = match fs::read_dir(&hash_dir) {
+            Ok(sidecars) => sidecars,
+            Err(_) => return Ok(()),
+        };
+
+        for sidecar in sidecars {
+            let sidecar = sidecar.map_err(|e| VaultError::io(e, &hash_dir))?;
+            let name = sidecar.file_name().to_string_lossy().to_string();
+            let media_path = dir.join(&name);
+            let metadata = match fs::metadata(&media_path) {
+                Ok(metadata) if metadata.is_file() => metadata,
+                _ => continue,
+            };
+
+            // A sidecar that can't be read only costs its placeholder, not the album
+            let hash = fs::read(sidecar.path())
+                .ok()
+                .and_then(|buffer| decrypt_data(key, &buffer).ok())
+                .and_then(|plain| String::from_utf8(plain).ok());
+            let (blurhash, aspect_ratio) = match hash.as_deref().and_then(|h| h.split_once(' ')) {
+                Some((blurhash, aspect_ratio)) => {
+                    (Some(blurhash.to_string()), aspect_ratio.parse::<f32>().ok())
+                }
+                None => (None, None),
+            };
+
+            let modified_at = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs() as i64);
+            self.entries.insert(
+                name,
+                MediaEntry {
+                    blurhash,
+                    aspect_ratio,
+                    size: None,
+                    mime_type: None,
+                    original_name: None,
+                    created_at: modified_at.unwrap_or(0),
+                    modified_at,
+                    sha256: None,
+                },
+            );
+        }
+
+        self.compact(key)?;
+        fs::remove_dir_all(&hash_dir).map_err(|e| VaultError::io(e, &hash_dir))
+    }
+}
+
+fn file_stamp(path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.len(), metadata.modified().unwrap_or(UNIX_EPOCH)))
+}
+
+fn encode_record(record: &IndexRecord, key: &CryptoParams) -> Result<Vec<u8>, VaultError> {
+    let plain = serde_json::to_vec(record).map_err(|e| VaultError::Internal {
+        message: e.to_string(),
+    })?;
+    let encrypted = encrypt_data(key, &plain)?;
+
+    let mut bytes = Vec::with_capacity(RECORD_LEN_BYTES + encrypted.len());
+    bytes.extend((encrypted.len() as u32).to_le_bytes());
+    bytes.extend(encrypted);
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::TempDir;
+
+    fn entry(created_at: i64) -> MediaEntry {
+        MediaEntry {
+            blurhash: Some("LKO2".to_string()),
+            aspect_ratio: Some(1.5),
+            size: Some(3),
+            mime_type: Some("image/jpeg".to_string()),
+            original_name: Some("photo.jpg".to_string()),
+            created_at,
+            modified_at: None,
+            sha256: None,
+        }
+    }
+
+    fn names(index: &AlbumIndex) -> Vec<String> {
+        let mut names: Vec<String> = index.entries().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    // Start and end of every record in the index file
+    fn record_bounds(bytes: &[u8]) -> Vec<(usize, usize)> {
+        let mut bounds = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let mut len = [0u8; RECORD_LEN_BYTES];
+            len.copy_from_slice(&bytes[offset..offset + RECORD_LEN_BYTES]);
+            let end = offset + RECORD_LEN_BYTES + u32::from_le_bytes(len) as usize;
+            bounds.push((offset, end));
+            offset = end;
+        }
+        bounds
+    }
+
+    #[test]
+    fn put_remove_and_reopen() {
+        let dir = TempDir::new("index_reopen");
+        let key = CryptoParams::random();
+
+        let mut index = AlbumIndex::load(dir.path(), &key).unwrap();
+        index.put("a.image", entry(1), &key).unwrap();
+        index.put("b.image", entry(2), &key).unwrap();
+        index.put("a.image", entry(3), &key).unwrap();
+        assert_eq!(
+            index.remove("b.image", &key).unwrap().map(|e| e.created_at),
+            Some(2)
+        );
+        assert!(index.remove("b.image", &key).unwrap().is_none());
+
+        let reopened = AlbumIndex::load(dir.path(), &key).unwrap();
+        assert_eq!(names(&reopened), vec!["a.image"]);
+        assert_eq!(reopened.get("a.image").unwrap().created_at, 3);
+        assert_eq!(reopened.records, 4);
+    }
+
+    #[test]
+    fn compacts_stale_records() {
+        let dir = TempDir::new("index_compact");
+        let key = CryptoParams::random();
+
+        let mut index = AlbumIndex::load(dir.path(), &key).unwrap();
+        for i in 0..COMPACT_SLACK as i64 * 3 {
+            index.put("a.image", entry(i), &key).unwrap();
+        }
+        assert!(index.records <= COMPACT_SLACK + 2);
+
+        let reopened = AlbumIndex::load(dir.path(), &key).unwrap();
+        assert_eq!(reopened.records, index.records);
+        assert_eq!(
+            reopened.get("a.image").unwrap().created_at,
+            COMPACT_SLACK as i64 * 3 - 1
+        );
+    }
+
+    #[test]
+    fn drops_a_truncated_last_record() {
+        let dir = TempDir::new("index_truncated");
+        let key = CryptoParams::random();
+        let path = dir.path().join(INDEX_FILE);
+
+        let mut index = AlbumIndex::load(dir.path(), &key).unwrap();
+        index.put("a.image", entry(1), &key).unwrap();
+        index.put("b.image", entry(2), &key).unwrap();
+        let mut bytes = fs::read(&path).unwrap();
+        bytes.truncate(bytes.len() - 5);
+        fs::write(&path, &bytes).unwrap();
+
+        let recovered = AlbumIndex::load(dir.path(), &key).unwrap();
+        assert_eq!(names(&recovered), vec!["a.image"]);
+        // Rewritten without the broken tail
+        assert_eq!(record_bounds(&fs::read(&path).unwrap()).len(), 1);
+    }
+
+    #[test]
+    fn skips_a_corrupt_record() {
+        let dir = TempDir::new("index_corrupt");
+        let key = CryptoParams::random();
+        let path = dir.path().join(INDEX_FILE);
+
+        let mut index = AlbumIndex::load(dir.path(), &key).unwrap();
+        for (i, name) in ["a.image", "b.image", "c.image"].iter().enumerate() {
+            index.put(name, entry(i as i64), &key).unwrap();
+        }
+        let mut bytes = fs::read(&path).unwrap();
+        let (_, end) = record_bounds(&bytes)[1];
+        bytes[end - 1] ^= 1;
+        fs::write(&path, &bytes).unwrap();
+
+        let recovered = AlbumIndex::load(dir.path(), &key).unwrap();
+        assert_eq!(names(&recovered), vec!["a.image", "c.image"]);
+        assert_eq!(record_bounds(&fs::read(&path).unwrap()).len(), 2);
+    }
+
+    #[test]
+    fn wrong_key_leaves_the_index_alone() {
+        let dir = TempDir::new("index_wrong_key");
+        let key = CryptoParams::random();
+        let path = dir.path().join(INDEX_FILE);
+
+        let mut index = AlbumIndex::load(dir.path(), &key).unwrap();
+        index.put("a.image", entry(1), &key).unwrap();
+        let before = fs::read(&path).unwrap();
+
+        assert!(matches!(
+            AlbumIndex::load(dir.path(), &CryptoParams::random()),
+            Err(VaultError::Corrupted { path: Some(_) })
+        ));
+        assert_eq!(fs::read(&path).unwrap(), before);
+    }
+
+    #[test]
+    fn migrates_hash_sidecars() {
+        let dir = TempDir::new("index_sidecars");
+        let key = CryptoParams::random();
+        let hash_dir = dir.path().join(LEGACY_HASH_DIR);
+        fs::create_dir(&hash_dir).unwrap();
+        fs::write(dir.path().join("a.image"), b"media").unwrap();
+        fs::write(
+            hash_dir.join("a.image"),
+            encrypt_data(&key, b"LKO2 1.50").unwrap(),
+        )
+        .unwrap();
+        // No media file next to it, so nothing to import
+        fs::write(
+            hash_dir.join("gone.image"),
+            encrypt_data(&key, b"LKO2 1.50").unwrap(),
+        )
+        .unwrap();
+
+        let index = AlbumIndex::load(dir.path(), &key).unwrap();
+        assert_eq!(names(&index), vec!["a.image"]);
+        let migrated = index.get("a.image").unwrap();
+        assert_eq!(migrated.blurhash.as_deref(), Some("LKO2"));
+        assert_eq!(migrated.aspect_ratio, Some(1.5));
+        assert!(!hash_dir.exists());
+
+        let reopened = AlbumIndex::load(dir.path(), &key).unwrap();
+        assert_eq!(names(&reopened), vec!["a.image"]);
+    }
+
+    #[test]
+    fn cached_index_follows_the_file() {
+        let dir = TempDir::new("index_cache");
+        let key = CryptoParams::random();
+
+        lock_indexes()
+            .open(dir.path(), &key)
+            .unwrap()
+            .put("a.image", entry(1), &key)
+            .unwrap();
+        assert_eq!(
+            names(lock_indexes().open(dir.path(), &key).unwrap()),
+            vec!["a.image"]
+        );
+
+        // Another index in its place, like an album deleted and created again
+        fs::remove_file(dir.path().join(INDEX_FILE)).unwrap();
+        assert!(lock_indexes()
+            .open(dir.path(), &key)
+            .unwrap()
+            .entries()
+            .is_empty());
+    }
+}
@@ -0,0 +1,314 @@
+// key generation
+use argon2::{Algorithm, Argon2, Params, Version};
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha256;
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+// Custom error
+use crate::utils::error::VaultError;
+
+const SALT_LEN: usize = 32;
+
+const PBKDF2_ITERATIONS: u32 = 100_000;
+const PBKDF2_MIN_ITERATIONS: u32 = 10_000;
+
+// Defaults follow the OWASP recommendation for Argon2id
+const ARGON2_MEMORY_KIB: u32 = 19 * 1024;
+const ARGON2_ITERATIONS: u32 = 2;
+const ARGON2_PARALLELISM: u32 = 1;
+
+// Highest costs accepted from anywhere. Headers also come out of backups, so without a cap
+// a crafted .vault-key could make unlocking allocate or spin for as long as it likes.
+const PBKDF2_MAX_ITERATIONS: u32 = 10_000_000;
+const ARGON2_MAX_MEMORY_KIB: u32 = 1024 * 1024;
+const ARGON2_MAX_ITERATIONS: u32 = 64;
+const ARGON2_MAX_PARALLELISM: u32 = 16;
+
+// Bounds used when benchmarking, kept low enough for phones
+const BENCH_MIN_MEMORY_KIB: u32 = 8 * 1024;
+const BENCH_MAX_MEMORY_KIB: u32 = 256 * 1024;
+const BENCH_MAX_ITERATIONS: u32 = 10;
+
+// Salt shared by every vault created before salts were stored in the vault header
+const LEGACY_SALT: [u8; SALT_LEN] = [
+    17, 128, 16, 104, 193, 198, 63, 155, 239, 14, 180, 237, 137, 144, 175, 49, 118, 108, 13, 147,
+    174, 122, 195, 174, 176, 103, 104, 156, 151, 114, 101, 106,
+];
+
+// KDF selection passed in from Dart, the salt is always generated here
+pub enum KdfConfig {
+    Pbkdf2Sha256 {
+        iterations: u32,
+    },
+    Argon2id {
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+}
+impl Default for KdfConfig {
+    fn default() -> Self {
+        KdfConfig::Argon2id {
+            memory_kib: ARGON2_MEMORY_KIB,
+            iterations: ARGON2_ITERATIONS,
+            parallelism: ARGON2_PARALLELISM,
+        }
+    }
+}
+
+// Key derivation function and its parameters, as stored in the vault header
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "algorithm", rename_all = "kebab-case")]
+pub enum KdfParams {
+    Pbkdf2Sha256 {
+        #[serde(with = "hex")]
+        salt: Vec<u8>,
+        iterations: u32,
+    },
+    Argon2id {
+        #[serde(with = "hex")]
+        salt: Vec<u8>,
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+}
+impl KdfParams {
+    pub fn new(config: &KdfConfig) -> Result<Self, VaultError> {
+        let mut salt = vec![0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let params = match *config {
+            KdfConfig::Pbkdf2Sha256 { iterations } => {
+                if iterations < PBKDF2_MIN_ITERATIONS {
+                    return Err(VaultError::InvalidInput {
+                        message: format!(
+                            "PBKDF2 needs at least {} iterations",
+                            PBKDF2_MIN_ITERATIONS
+                        ),
+                    });
+                }
+                check_pbkdf2(iterations).map_err(|message| VaultError::InvalidInput { message })?;
+                KdfParams::Pbkdf2Sha256 { salt, iterations }
+            }
+            KdfConfig::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => {
+                // Reject parameters argon2 can't work with before they end up in a header
+                check_argon2(memory_kib, iterations, parallelism)
+                    .map_err(|message| VaultError::InvalidInput { message })?;
+                argon2(memory_kib, iterations, parallelism)?;
+                KdfParams::Argon2id {
+                    salt,
+                    memory_kib,
+                    iterations,
+                    parallelism,
+                }
+            }
+        };
+        Ok(params)
+    }
+
+    pub fn legacy() -> Self {
+        KdfParams::Pbkdf2Sha256 {
+            salt: LEGACY_SALT.to_vec(),
+            iterations: PBKDF2_ITERATIONS,
+        }
+    }
+
+    // Same function and cost, with a fresh salt
+    pub fn resalted(&self) -> Self {
+        let mut salt = vec![0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let mut params = self.clone();
+        match &mut params {
+            KdfParams::Pbkdf2Sha256 { salt: old, .. } | KdfParams::Argon2id { salt: old, .. } => {
+                *old = salt
+            }
+        }
+        params
+    }
+
+    // For parameters read from a header or manifest, which may not come from this app
+    pub fn check_limits(&self) -> Result<(), VaultError> {
+        let checked = match *self {
+            KdfParams::Pbkdf2Sha256 { iterations, .. } => check_pbkdf2(iterations),
+            KdfParams::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+                ..
+            } => check_argon2(memory_kib, iterations, parallelism),
+        };
+        checked.map_err(|details| VaultError::UnsupportedFormat {
+            path: None,
+            details,
+        })
+    }
+
+    // Fills `output` with key material derived from the password
+    pub fn derive(&self, password: &str, output: &mut [u8]) -> Result<(), VaultError> {
+        match self {
+            KdfParams::Pbkdf2Sha256 { salt, iterations } => {
+                pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, *iterations, output);
+                Ok(())
+            }
+            KdfParams::Argon2id {
+                salt,
+                memory_kib,
+                iterations,
+                parallelism,
+            } => argon2(*memory_kib, *iterations, *parallelism)?
+                .hash_password_into(password.as_bytes(), salt, output)
+                .map_err(|e| VaultError::Internal {
+                    message: format!("Key derivation failed: {}", e),
+                }),
+        }
+    }
+}
+
+fn check_pbkdf2(iterations: u32) -> Result<(), String> {
+    if iterations > PBKDF2_MAX_ITERATIONS {
+        return Err(format!("PBKDF2 iterations above {}", PBKDF2_MAX_ITERATIONS));
+    }
+    Ok(())
+}
+
+fn check_argon2(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<(), String> {
+    if memory_kib > ARGON2_MAX_MEMORY_KIB {
+        return Err(format!("Argon2 memory above {} KiB", ARGON2_MAX_MEMORY_KIB));
+    }
+    if iterations > ARGON2_MAX_ITERATIONS {
+        return Err(format!("Argon2 iterations above {}", ARGON2_MAX_ITERATIONS));
+    }
+    if parallelism > ARGON2_MAX_PARALLELISM {
+        return Err(format!(
+            "Argon2 parallelism above {}",
+            ARGON2_MAX_PARALLELISM
+        ));
+    }
+    Ok(())
+}
+
+fn argon2(
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+) -> Result<Argon2<'static>, VaultError> {
+    let params = Params::new(memory_kib, iterations, parallelism, None).map_err(|e| {
+        VaultError::InvalidInput {
+            message: format!("Invalid Argon2 parameters: {}", e),
+        }
+    })?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
+
+// Picks Argon2id parameters that take roughly `target_millis` to derive a key on this device.
+// Memory is raised first since it's what makes GPU cracking expensive, then iterations.
+pub fn benchmark_argon2(target_millis: u32) -> Result<KdfConfig, VaultError> {
+    let target = target_millis.max(1) as f64;
+    let mut memory_kib = BENCH_MIN_MEMORY_KIB;
+    let mut iterations = ARGON2_ITERATIONS;
+
+    let mut elapsed = time_argon2(memory_kib, iterations)?;
+    while elapsed * 2.0 <= target && memory_kib * 2 <= BENCH_MAX_MEMORY_KIB {
+        memory_kib *= 2;
+        elapsed = time_argon2(memory_kib, iterations)?;
+    }
+
+    // Cost grows linearly with iterations, so no need to measure every step
+    if elapsed < target {
+        let scaled = (iterations as f64 * target / elapsed.max(1.0)).floor() as u32;
+        iterations = scaled.clamp(ARGON2_ITERATIONS, BENCH_MAX_ITERATIONS);
+    }
+
+    Ok(KdfConfig::Argon2id {
+        memory_kib,
+        iterations,
+        parallelism: ARGON2_PARALLELISM,
+    })
+}
+
+fn time_argon2(memory_kib: u32, iterations: u32) -> Result<f64, VaultError> {
+    let argon2 = argon2(memory_kib, iterations, ARGON2_PARALLELISM)?;
+    let salt = [0u8; SALT_LEN];
+    let mut output = [0u8; 48];
+
+    let start = Instant::now();
+    argon2
+        .hash_password_into(b"benchmark", &salt, &mut output)
+        .map_err(|e| VaultError::Internal {
+            message: format!("Key derivation failed: {}", e),
+        })?;
+    Ok(start.elapsed().as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_costs_above_the_limits() {
+        let configs = [
+            KdfConfig::Pbkdf2Sha256 {
+                iterations: PBKDF2_MAX_ITERATIONS + 1,
+            },
+            KdfConfig::Argon2id {
+                memory_kib: ARGON2_MAX_MEMORY_KIB + 1,
+                iterations: ARGON2_ITERATIONS,
+                parallelism: ARGON2_PARALLELISM,
+            },
+            KdfConfig::Argon2id {
+                memory_kib: ARGON2_MEMORY_KIB,
+                iterations: ARGON2_MAX_ITERATIONS + 1,
+                parallelism: ARGON2_PARALLELISM,
+            },
+            KdfConfig::Argon2id {
+                memory_kib: ARGON2_MEMORY_KIB,
+                iterations: ARGON2_ITERATIONS,
+                parallelism: ARGON2_MAX_PARALLELISM + 1,
+            },
+        ];
+        for config in &configs {
+            assert!(matches!(
+                KdfParams::new(config),
+                Err(VaultError::InvalidInput { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn new_accepts_the_limits() {
+        let params = KdfParams::new(&KdfConfig::Pbkdf2Sha256 {
+            iterations: PBKDF2_MAX_ITERATIONS,
+        })
+        .unwrap();
+        params.check_limits().unwrap();
+        KdfParams::new(&KdfConfig::default())
+            .unwrap()
+            .check_limits()
+            .unwrap();
+    }
+
+    #[test]
+    fn check_limits_rejects_parsed_params() {
+        let json = format!(
+            r#"{{"algorithm":"argon2id","salt":"{}","memory_kib":{},"iterations":2,"parallelism":1}}"#,
+            "00".repeat(SALT_LEN),
+            u32::MAX
+        );
+        let params: KdfParams = serde_json::from_str(&json).unwrap();
+        assert!(matches!(
+            params.check_limits(),
+            Err(VaultError::UnsupportedFormat { .. })
+        ));
+        KdfParams::legacy().check_limits().unwrap();
+    }
+}
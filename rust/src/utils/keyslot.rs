@@ -0,0 +1,140 @@
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+// Custom error
+use crate::utils::error::VaultError;
+// Key derivation
+use crate::utils::kdf::KdfParams;
+
+const MIN_PIN_LEN: usize = 4;
+
+// Crockford base32, no I, L, O or U so codes survive being written down and typed back
+const RECOVERY_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const RECOVERY_GROUPS: usize = 6;
+const RECOVERY_GROUP_LEN: usize = 4;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeySlotKind {
+    Password,
+    Pin,
+    RecoveryKey,
+}
+impl KeySlotKind {
+    // Whether a secret typed by the user could belong to a slot of this kind,
+    // so unlocking doesn't run the KDF of every slot for every attempt
+    pub fn accepts(&self, secret: &str) -> bool {
+        match self {
+            KeySlotKind::Password => !secret.is_empty(),
+            KeySlotKind::Pin => is_pin(secret),
+            KeySlotKind::RecoveryKey => normalize_recovery_key(secret).is_some(),
+        }
+    }
+
+    // The exact bytes fed to the KDF
+    pub fn canonical_secret(&self, secret: &str) -> String {
+        match self {
+            KeySlotKind::RecoveryKey => {
+                normalize_recovery_key(secret).unwrap_or_else(|| secret.to_string())
+            }
+            _ => secret.to_string(),
+        }
+    }
+
+    pub fn validate(&self, secret: &str) -> Result<(), VaultError> {
+        match self {
+            KeySlotKind::Password if secret.is_empty() => Err(VaultError::InvalidInput {
+                message: "Password can't be empty".to_string(),
+            }),
+            KeySlotKind::Pin if !is_pin(secret) => Err(VaultError::InvalidInput {
+                message: format!("PIN must be at least {} digits", MIN_PIN_LEN),
+            }),
+            KeySlotKind::RecoveryKey if normalize_recovery_key(secret).is_none() => {
+                Err(VaultError::InvalidInput {
+                    message: "Invalid recovery key".to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+// One way of unlocking the vault: the master key wrapped with a key derived from one secret
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KeySlot {
+    pub id: u32,
+    pub kind: KeySlotKind,
+    pub label: String,
+    pub created_at: i64,
+    pub kdf: KdfParams,
+    #[serde(with = "hex")]
+    pub wrapped_key: Vec<u8>,
+}
+
+// What Dart gets to see about a slot, never any key material
+pub struct KeySlotInfo {
+    pub id: u32,
+    pub kind: KeySlotKind,
+    pub label: String,
+    pub created_at: i64,
+}
+impl From<&KeySlot> for KeySlotInfo {
+    fn from(slot: &KeySlot) -> Self {
+        KeySlotInfo {
+            id: slot.id,
+            kind: slot.kind,
+            label: slot.label.clone(),
+            created_at: slot.created_at,
+        }
+    }
+}
+
+fn is_pin(secret: &str) -> bool {
+    secret.len() >= MIN_PIN_LEN && secret.chars().all(|c| c.is_ascii_digit())
+}
+
+// Random code in groups like `7KQ2-M9XD-...`, 120 bits of entropy
+pub fn generate_recovery_key() -> String {
+    let mut random = [0u8; RECOVERY_GROUPS * RECOVERY_GROUP_LEN];
+    OsRng.fill_bytes(&mut random);
+
+    random
+        .chunks(RECOVERY_GROUP_LEN)
+        .map(|group| {
+            group
+                .iter()
+                .map(|byte| RECOVERY_ALPHABET[(byte & 0x1f) as usize] as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+// Accepts lowercase, missing dashes and the usual look-alike characters
+fn normalize_recovery_key(input: &str) -> Option<String> {
+    let mut chars = Vec::with_capacity(RECOVERY_GROUPS * RECOVERY_GROUP_LEN);
+    for c in input.chars() {
+        let c = match c.to_ascii_uppercase() {
+            '-' | ' ' => continue,
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        if !c.is_ascii() || !RECOVERY_ALPHABET.contains(&(c as u8)) {
+            return None;
+        }
+        chars.push(c);
+    }
+    if chars.len() != RECOVERY_GROUPS * RECOVERY_GROUP_LEN {
+        return None;
+    }
+
+    Some(
+        chars
+            .chunks(RECOVERY_GROUP_LEN)
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("-"),
+    )
+}
@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufReader, Read},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zip::ZipArchive;
+
+// Custom error
+use crate::utils::error::VaultError;
+// Encrytion
+use crate::utils::encryption::{encrypt_data, CryptoParams, PasswordDecrypter};
+// Key derivation
+use crate::utils::kdf::KdfParams;
+
+pub const MANIFEST_FILE: &str = ".manifest";
+pub const MANIFEST_VERSION: u32 = 1;
+// A manifest is a few hundred bytes per entry, anything past this isn't one of ours
+const MAX_MANIFEST_LEN: u64 = 64 * 1024 * 1024;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BackupEncryption {
+    // Entries were decrypted before they went into the archive
+    Plain,
+    // Entries are copied as they are and need the vault password to restore
+    VaultKey,
+}
+
+// Written as the last entry of every archive. Kept readable without the password so a
+// backup can be inspected before restoring it, names are encrypted in VaultKey archives.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BackupManifest {
+    pub version: u32,
+    pub created_at: i64,
+    // Version of the library that wrote the archive, None for archives from before it was kept
+    #[serde(default)]
+    pub app_version: Option<String>,
+    pub encryption: BackupEncryption,
+    // Of every key slot in the archived vault key, empty for plain archives
+    pub kdf: Vec<KdfParams>,
+    // Every album with its parents, paths use '/' whatever the platform
+    pub albums: Vec<String>,
+    pub entries: Vec<ManifestEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ManifestEntry {
+    // Path of the entry in the archive
+    pub name: String,
+    pub original_name: Option<String>,
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    // Seconds since the epoch
+    #[serde(default)]
+    pub modified_at: Option<i64>,
+    // Size and hash of the entry as stored, so a backup can be checked without the password
+    pub size: u64,
+    pub sha256: String,
+}
+
+impl BackupManifest {
+    pub fn new(encryption: BackupEncryption, kdf: Vec<KdfParams>) -> Self {
+        BackupManifest {
+            version: MANIFEST_VERSION,
+            created_at: chrono::Utc::now().timestamp(),
+            app_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+            encryption,
+            kdf,
+            albums: Vec::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    // None for archives written before manifests
+    pub fn read(
+        archive: &mut ZipArchive<BufReader<File>>,
+        zip_path: &str,
+    ) -> Result<Option<Self>, VaultError> {
+        let file = match archive.by_name(MANIFEST_FILE) {
+            Ok(file) => file,
+            Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+            Err(e) => return Err(VaultError::zip(e, zip_path)),
+        };
+        let invalid = |reason: String| VaultError::InvalidBackup {
+            path: zip_path.to_string(),
+            reason,
+        };
+
+        let mut bytes = Vec::new();
+        file.take(MAX_MANIFEST_LEN + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|e| VaultError::io(e, zip_path))?;
+        if bytes.len() as u64 > MAX_MANIFEST_LEN {
+            return Err(invalid("manifest is too large".to_string()));
+        }
+
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+        let version = serde_json::from_slice::<Version>(&bytes)
+            .map_err(|e| invalid(format!("unreadable manifest: {}", e)))?
+            .version;
+        if version > MANIFEST_VERSION {
+            return Err(VaultError::BackupVersionMismatch {
+                found: version,
+                supported: MANIFEST_VERSION,
+            });
+        }
+        let manifest: BackupManifest = serde_json::from_slice(&bytes)
+            .map_err(|e| invalid(format!("unreadable manifest: {}", e)))?;
+        for kdf in &manifest.kdf {
+            kdf.check_limits().map_err(|e| invalid(e.to_string()))?;
+        }
+        Ok(Some(manifest))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, VaultError> {
+        serde_json::to_vec_pretty(self).map_err(|e| VaultError::Internal {
+            message: format!("Failed to serialize backup manifest: {}", e),
+        })
+    }
+
+    // Entries by their name in the archive, built once for a pass over the archive
+    pub fn entries_by_name(&self) -> HashMap<&str, &ManifestEntry> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry))
+            .collect()
+    }
+
+    // What inspect_backup hands out, encrypted names mean nothing without the password
+    pub fn without_sealed_names(mut self) -> Self {
+        if self.encryption == BackupEncryption::VaultKey {
+            for entry in &mut self.entries {
+                entry.original_name = None;
+            }
+        }
+        self
+    }
+}
+
+// Original names in VaultKey archives are encrypted with the vault key, hex encoded
+pub fn seal_name(name: &str, key: &CryptoParams) -> Result<String, VaultError> {
+    Ok(hex::encode(encrypt_data(key, name.as_bytes())?))
+}
+
+pub fn open_name(sealed: &str, decrypter: &PasswordDecrypter) -> Option<String> {
+    let bytes = hex::decode(sealed).ok()?;
+    String::from_utf8(decrypter.decrypt(&bytes).ok()?).ok()
+}
+
+// Hashes data as it goes through a backup or restore
+#[derive(Clone)]
+pub struct EntryHasher {
+    hasher: Sha256,
+    size: u64,
+}
+
+impl EntryHasher {
+    pub fn new() -> Self {
+        EntryHasher {
+            hasher: Sha256::new(),
+            size: 0,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+        self.size += data.len() as u64;
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn finish(self) -> String {
+        hex::encode(self.hasher.finalize())
+    }
+}
+
+impl Default for EntryHasher {
+    fn default() -> Self {
+        EntryHasher::new()
+    }
+}
+
+// Hashes everything read through it
+pub struct HashReader<R: Read> {
+    inner: R,
+    hasher: EntryHasher,
+}
+
+impl<R: Read> HashReader<R> {
+    pub fn new(inner: R) -> Self {
+        HashReader {
+            inner,
+            hasher: EntryHasher::new(),
+        }
+    }
+
+    pub fn into_hasher(self) -> EntryHasher {
+        self.hasher
+    }
+}
+
+impl<R: Read> Read for HashReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
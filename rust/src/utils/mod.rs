@@ -1,5 +1,17 @@
+pub mod backup;
 pub mod cache;
 pub mod error;
 pub mod utils;
 pub mod encryption;
+pub mod index;
+pub mod kdf;
+pub mod keyslot;
+pub mod manifest;
+pub mod progress;
+pub mod server;
+pub mod session;
+pub mod storage;
+pub mod stream;
 
+#[cfg(test)]
+pub mod testing;
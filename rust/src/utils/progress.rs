@@ -0,0 +1,128 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use flutter_rust_bridge::frb;
+
+use crate::frb_generated::StreamSink;
+// Custom error
+use crate::utils::error::VaultError;
+
+// Events in between files are sent at most this often
+const EMIT_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone)]
+pub struct ProgressEvent {
+    pub files_done: u32,
+    pub files_total: u32,
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    // Path of the file being worked on, relative to the vault or the archive
+    pub current_item: Option<String>,
+}
+
+// Handed to a long operation and cancelled from another call, the operation stops at
+// the next file or chunk and undoes whatever it had half written
+#[frb(opaque)]
+pub struct CancelToken {
+    cancelled: AtomicBool,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken {
+            cancelled: AtomicBool::new(false),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for CancelToken {
+    fn default() -> Self {
+        CancelToken::new()
+    }
+}
+
+// What a long operation reports to, both the sink and the token are optional
+pub struct Progress<'a> {
+    sink: Option<StreamSink<ProgressEvent>>,
+    cancel: Option<&'a CancelToken>,
+    event: ProgressEvent,
+    last_emit: Option<Instant>,
+}
+
+impl<'a> Progress<'a> {
+    pub fn new(sink: Option<StreamSink<ProgressEvent>>, cancel: Option<&'a CancelToken>) -> Self {
+        Progress {
+            sink,
+            cancel,
+            event: ProgressEvent {
+                files_done: 0,
+                files_total: 0,
+                bytes_done: 0,
+                bytes_total: 0,
+                current_item: None,
+            },
+            last_emit: None,
+        }
+    }
+
+    // For callers that don't report anywhere
+    pub fn none() -> Self {
+        Progress::new(None, None)
+    }
+
+    pub fn start(&mut self, files_total: u32, bytes_total: u64) {
+        self.event.files_total = files_total;
+        self.event.bytes_total = bytes_total;
+        self.emit();
+    }
+
+    pub fn begin_item(&mut self, item: &str) -> Result<(), VaultError> {
+        self.check()?;
+        self.event.current_item = Some(item.to_string());
+        self.emit();
+        Ok(())
+    }
+
+    pub fn add_bytes(&mut self, bytes: u64) -> Result<(), VaultError> {
+        self.check()?;
+        self.event.bytes_done += bytes;
+        if self
+            .last_emit
+            .is_none_or(|last_emit| last_emit.elapsed() >= EMIT_INTERVAL)
+        {
+            self.emit();
+        }
+        Ok(())
+    }
+
+    pub fn finish_item(&mut self) {
+        self.event.files_done += 1;
+        self.emit();
+    }
+
+    // Fails with VaultError::Cancelled once the token is cancelled
+    pub fn check(&self) -> Result<(), VaultError> {
+        match self.cancel {
+            Some(cancel) if cancel.is_cancelled() => Err(VaultError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    fn emit(&mut self) {
+        if let Some(sink) = &self.sink {
+            // A closed Dart stream only stops the events, not the operation
+            let _ = sink.add(self.event.clone());
+        }
+        self.last_emit = Some(Instant::now());
+    }
+}
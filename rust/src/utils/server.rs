@@ -0,0 +1,686 @@
+// Serves decrypted media to players that only take a URL
+use std::{
+    collections::HashMap,
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{Ipv4Addr, Shutdown, SocketAddr, TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use lazy_static::lazy_static;
+use rand::{rngs::OsRng, RngCore};
+use zeroize::Zeroizing;
+
+// Custom error
+use crate::utils::error::{IoErrorKind, VaultError};
+// Encrytion
+use crate::utils::encryption::{decrypt_data, CryptoParams};
+// Streaming encryption
+use crate::utils::stream::ChunkReader;
+// Vault operations
+use crate::utils::storage::get_media_info;
+
+const TOKEN_BYTES: usize = 32;
+// A request line and its headers, anything longer isn't from a media player
+const MAX_REQUEST_LEN: usize = 16 * 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+// Most decrypted bytes held in memory per connection
+const SEND_LEN: u64 = 256 * 1024;
+// Connections served at once, players rarely keep more than a couple open.
+// Anything past this gets a 503 instead of another thread.
+const MAX_CONNECTIONS: usize = 8;
+
+// A file handed out through a URL, usable for as long as its key is
+struct MediaToken {
+    path: PathBuf,
+    key: Arc<RwLock<Option<CryptoParams>>>,
+    // Files from before chunked encryption can only be decrypted whole, which is done
+    // once per token rather than for every range a player asks for
+    plain: Option<Arc<Zeroizing<Vec<u8>>>>,
+}
+
+struct RunningServer {
+    port: u16,
+    stop: Arc<AtomicBool>,
+}
+
+lazy_static! {
+    static ref SERVER: Mutex<Option<RunningServer>> = Mutex::new(None);
+    static ref TOKENS: Mutex<HashMap<String, MediaToken>> = Mutex::new(HashMap::new());
+}
+static CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
+
+// Counts a connection for as long as it's being served
+struct ConnectionSlot;
+
+impl ConnectionSlot {
+    fn take() -> Option<Self> {
+        CONNECTIONS
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| {
+                (active < MAX_CONNECTIONS).then_some(active + 1)
+            })
+            .ok()
+            .map(|_| ConnectionSlot)
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        CONNECTIONS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// Starts the server on a random loopback port, or returns the port it already runs on
+pub fn start_server() -> Result<u16, VaultError> {
+    let mut server = SERVER.lock().map_err(|e| VaultError::Internal {
+        message: e.to_string(),
+    })?;
+    if let Some(running) = server.as_ref() {
+        return Ok(running.port);
+    }
+
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, 0));
+    let listener = TcpListener::bind(address).map_err(|e| VaultError::Io {
+        kind: IoErrorKind::Other,
+        path: None,
+        message: e.to_string(),
+    })?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| VaultError::Internal {
+            message: e.to_string(),
+        })?
+        .port();
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let stopped = stop.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if stopped.load(Ordering::SeqCst) {
+                break;
+            }
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            match ConnectionSlot::take() {
+                Some(slot) => {
+                    thread::spawn(move || {
+                        handle_connection(stream);
+                        drop(slot);
+                    });
+                }
+                None => {
+                    let _ = write_head(
+                        &mut stream,
+                        "503 Service Unavailable",
+                        &[("Retry-After", "1"), ("Content-Length", "0")],
+                    );
+                    let _ = stream.shutdown(Shutdown::Both);
+                }
+            }
+        }
+    });
+
+    *server = Some(RunningServer { port, stop });
+    Ok(port)
+}
+
+// Stops accepting connections and forgets every token
+pub fn stop_server() -> Result<(), VaultError> {
+    let running = SERVER
+        .lock()
+        .map_err(|e| VaultError::Internal {
+            message: e.to_string(),
+        })?
+        .take();
+    if let Some(running) = running {
+        running.stop.store(true, Ordering::SeqCst);
+        // Wakes the accept loop up so it sees the flag
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, running.port));
+    }
+    if let Ok(mut tokens) = TOKENS.lock() {
+        tokens.clear();
+    }
+    Ok(())
+}
+
+// URL serving the decrypted file at `path`. It stops working once `key` is cleared,
+// which happens when the vault or the handle it came from is locked.
+pub fn media_url(
+    path: &Path,
+    key: Arc<RwLock<Option<CryptoParams>>>,
+) -> Result<String, VaultError> {
+    if !path.is_file() {
+        return Err(VaultError::NotFound {
+            path: path.to_string_lossy().to_string(),
+        });
+    }
+    let port = start_server()?;
+
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    let token = hex::encode(bytes);
+
+    let mut tokens = TOKENS.lock().map_err(|e| VaultError::Internal {
+        message: e.to_string(),
+    })?;
+    tokens.retain(|_, token| token_key(token).is_some());
+    tokens.insert(
+        token.clone(),
+        MediaToken {
+            path: path.to_path_buf(),
+            key,
+            plain: None,
+        },
+    );
+    Ok(format!("http://127.0.0.1:{}/{}", port, token))
+}
+
+// Drops the tokens whose key was cleared together with the plaintext cached for them,
+// locking calls it so nothing decrypted outlives the key
+pub fn forget_expired_tokens() {
+    if let Ok(mut tokens) = TOKENS.lock() {
+        tokens.retain(|_, token| token_key(token).is_some());
+    }
+}
+
+fn token_key(token: &MediaToken) -> Option<CryptoParams> {
+    token.key.read().ok()?.clone()
+}
+
+// What a token serves right now
+struct Served {
+    path: PathBuf,
+    key: CryptoParams,
+    plain: Option<Arc<Zeroizing<Vec<u8>>>>,
+}
+
+// Expired tokens are dropped on the way
+fn lookup(token: &str) -> Option<Served> {
+    let mut tokens = TOKENS.lock().ok()?;
+    let entry = tokens.get(token)?;
+    match token_key(entry) {
+        Some(key) => Some(Served {
+            path: entry.path.clone(),
+            key,
+            plain: entry.plain.clone(),
+        }),
+        None => {
+            tokens.remove(token);
+            None
+        }
+    }
+}
+
+fn cache_plain(token: &str, plain: Arc<Zeroizing<Vec<u8>>>) {
+    if let Ok(mut tokens) = TOKENS.lock() {
+        if let Some(entry) = tokens.get_mut(token) {
+            entry.plain = Some(plain);
+        }
+    }
+}
+
+struct Request {
+    method: String,
+    target: String,
+    range: Option<String>,
+}
+
+// One request per connection, players open a new one for every seek anyway
+fn handle_connection(mut stream: TcpStream) {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let response = match read_request(&stream) {
+        Some(request) => respond(&mut stream, request),
+        None => write_head(&mut stream, "400 Bad Request", &[("Content-Length", "0")]),
+    };
+    let _ = response.and_then(|_| stream.flush());
+    let _ = stream.shutdown(Shutdown::Both);
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut read = 0;
+    let mut line = String::new();
+
+    reader.read_line(&mut line).ok()?;
+    read += line.len();
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?.to_string();
+
+    let mut range = None;
+    loop {
+        line.clear();
+        let len = reader.read_line(&mut line).ok()?;
+        read += len;
+        if len == 0 || read > MAX_REQUEST_LEN {
+            return None;
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("range") {
+                range = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    Some(Request {
+        method,
+        target,
+        range,
+    })
+}
+
+fn respond(stream: &mut TcpStream, request: Request) -> std::io::Result<()> {
+    if request.method != "GET" && request.method != "HEAD" {
+        return write_head(
+            stream,
+            "405 Method Not Allowed",
+            &[("Allow", "GET, HEAD"), ("Content-Length", "0")],
+        );
+    }
+
+    let token = request.target.trim_start_matches('/');
+    let token = token.split('?').next().unwrap_or_default();
+    let Served { path, key, plain } = match lookup(token) {
+        Some(served) => served,
+        // Unknown or locked, either way the token doesn't grant access
+        None => return write_head(stream, "403 Forbidden", &[("Content-Length", "0")]),
+    };
+
+    let body = match plain {
+        Some(plain) => Ok(Body::Whole(plain)),
+        None => Body::open(&path, &key),
+    };
+    let mut body = match body {
+        Ok(body) => {
+            if let Body::Whole(plain) = &body {
+                cache_plain(token, plain.clone());
+            }
+            body
+        }
+        Err(_) => {
+            return write_head(
+                stream,
+                "500 Internal Server Error",
+                &[("Content-Length", "0")],
+            )
+        }
+    };
+    let len = body.len();
+    let content_type = get_media_info(&path.to_string_lossy(), &key)
+        .ok()
+        .and_then(|info| info.mime_type)
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+
+    let (status, start, end) = match request
+        .range
+        .as_deref()
+        .map(|range| parse_range(range, len))
+    {
+        None | Some(RangeRequest::Ignored) => ("200 OK", 0, len),
+        Some(RangeRequest::Bytes(start, end)) => ("206 Partial Content", start, end),
+        Some(RangeRequest::Unsatisfiable) => {
+            return write_head(
+                stream,
+                "416 Range Not Satisfiable",
+                &[
+                    ("Content-Range", &format!("bytes */{}", len)),
+                    ("Content-Length", "0"),
+                ],
+            )
+        }
+    };
+
+    let content_length = (end - start).to_string();
+    let content_range = format!("bytes {}-{}/{}", start, end.saturating_sub(1), len);
+    let mut headers = vec![
+        ("Content-Type", content_type.as_str()),
+        ("Content-Length", content_length.as_str()),
+        ("Accept-Ranges", "bytes"),
+    ];
+    if status.starts_with("206") {
+        headers.push(("Content-Range", content_range.as_str()));
+    }
+    write_head(stream, status, &headers)?;
+    if request.method == "HEAD" {
+        return Ok(());
+    }
+
+    // Sent a chunk at a time so a seek near the end of a large video stays cheap
+    let mut offset = start;
+    while offset < end {
+        if lookup(token).is_none() {
+            // Locked while sending, the player sees a cut off response
+            break;
+        }
+        let data = body
+            .read(offset, (end - offset).min(SEND_LEN))
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        if data.is_empty() {
+            break;
+        }
+        stream.write_all(&data)?;
+        offset += data.len() as u64;
+    }
+    Ok(())
+}
+
+// Chunked files are read a range at a time, older ones are decrypted whole
+enum Body {
+    Chunked(Box<ChunkReader>),
+    Whole(Arc<Zeroizing<Vec<u8>>>),
+}
+
+impl Body {
+    fn open(path: &Path, key: &CryptoParams) -> Result<Self, VaultError> {
+        match ChunkReader::open(path, key)? {
+            Some(reader) => Ok(Body::Chunked(Box::new(reader))),
+            None => {
+                let buffer = fs::read(path).map_err(|e| VaultError::io(e, path))?;
+                let plain = decrypt_data(key, &buffer).map_err(|e| e.at(path))?;
+                Ok(Body::Whole(Arc::new(Zeroizing::new(plain))))
+            }
+        }
+    }
+
+    fn len(&self) -> u64 {
+        match self {
+            Body::Chunked(reader) => reader.len(),
+            Body::Whole(data) => data.len() as u64,
+        }
+    }
+
+    fn read(&mut self, offset: u64, len: u64) -> Result<Vec<u8>, VaultError> {
+        match self {
+            Body::Chunked(reader) => reader.read_range(offset, len),
+            Body::Whole(data) => {
+                let start = offset.min(data.len() as u64) as usize;
+                let end = offset.saturating_add(len).min(data.len() as u64) as usize;
+                Ok(data[start..end].to_vec())
+            }
+        }
+    }
+}
+
+enum RangeRequest {
+    // Start and exclusive end
+    Bytes(u64, u64),
+    Unsatisfiable,
+    // Not something we serve partially, the whole file is sent instead
+    Ignored,
+}
+
+// Only single byte ranges, which is all players ask for
+fn parse_range(range: &str, len: u64) -> RangeRequest {
+    let spec = match range.strip_prefix("bytes=") {
+        Some(spec) if !spec.contains(',') => spec.trim(),
+        _ => return RangeRequest::Ignored,
+    };
+    let (first, last) = match spec.split_once('-') {
+        Some(parts) => parts,
+        None => return RangeRequest::Ignored,
+    };
+
+    let (start, end) = if first.is_empty() {
+        // "-n" is the last n bytes
+        match last.parse::<u64>() {
+            Ok(0) => return RangeRequest::Unsatisfiable,
+            Ok(suffix) => (len.saturating_sub(suffix), len),
+            Err(_) => return RangeRequest::Ignored,
+        }
+    } else {
+        let start = match first.parse::<u64>() {
+            Ok(start) => start,
+            Err(_) => return RangeRequest::Ignored,
+        };
+        let end = if last.is_empty() {
+            len
+        } else {
+            match last.parse::<u64>() {
+                Ok(last) if last >= start => last.saturating_add(1).min(len),
+                _ => return RangeRequest::Ignored,
+            }
+        };
+        (start, end)
+    };
+
+    if start >= len {
+        return RangeRequest::Unsatisfiable;
+    }
+    RangeRequest::Bytes(start, end)
+}
+
+fn write_head(
+    stream: &mut TcpStream,
+    status: &str,
+    headers: &[(&str, &str)],
+) -> std::io::Result<()> {
+    let mut head = format!("HTTP/1.1 {}\r\nConnection: close\r\n", status);
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::encryption::{encrypt_data, CHUNK_SIZE};
+    use crate::utils::session::{lock, register_handle_key, LockReason};
+    use crate::utils::stream::ChunkWriter;
+    use crate::utils::testing::{TempDir, SESSION_KEYS};
+    use std::{fs::File, io::Read, thread::sleep, time::Instant};
+
+    // The server and its tokens are global, so these tests take turns
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    struct Response {
+        status: String,
+        headers: Vec<(String, String)>,
+        body: Vec<u8>,
+    }
+
+    impl Response {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    fn get(url: &str, range: Option<&str>) -> Response {
+        let address = url.trim_start_matches("http://");
+        let (host, target) = address.split_once('/').unwrap();
+        let mut stream = TcpStream::connect(host).unwrap();
+        let mut request = format!("GET /{} HTTP/1.1\r\nHost: {}\r\n", target, host);
+        if let Some(range) = range {
+            request.push_str(&format!("Range: {}\r\n", range));
+        }
+        request.push_str("\r\n");
+        stream.write_all(request.as_bytes()).unwrap();
+
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).unwrap();
+        let split = raw.windows(4).position(|w| w == b"\r\n\r\n").unwrap();
+        let head = String::from_utf8(raw[..split].to_vec()).unwrap();
+        let mut lines = head.split("\r\n");
+        let (_, status) = lines.next().unwrap().split_once(' ').unwrap();
+        Response {
+            status: status.to_string(),
+            headers: lines
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, value)| (name.to_string(), value.trim().to_string()))
+                .collect(),
+            body: raw[split + 4..].to_vec(),
+        }
+    }
+
+    fn sample(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn chunked_file(dir: &TempDir, data: &[u8], key: &CryptoParams) -> PathBuf {
+        let path = dir.path().join("video.video");
+        let mut writer = ChunkWriter::new(File::create(&path).unwrap(), &path, key).unwrap();
+        writer.write(data).unwrap();
+        writer.finish().unwrap();
+        path
+    }
+
+    fn shared(key: &CryptoParams) -> Arc<RwLock<Option<CryptoParams>>> {
+        Arc::new(RwLock::new(Some(key.clone())))
+    }
+
+    fn bad_token(url: &str) -> String {
+        let (base, _) = url.rsplit_once('/').unwrap();
+        format!("{}/{}", base, "0".repeat(TOKEN_BYTES * 2))
+    }
+
+    #[test]
+    fn serves_whole_files_and_ranges() {
+        let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new("server_ranges");
+        let key = CryptoParams::random();
+        let data = sample(3 * CHUNK_SIZE + 123);
+        let url = media_url(&chunked_file(&dir, &data, &key), shared(&key)).unwrap();
+
+        let whole = get(&url, None);
+        assert_eq!(whole.status, "200 OK");
+        assert_eq!(whole.header("Accept-Ranges"), Some("bytes"));
+        assert_eq!(whole.body, data);
+
+        // Crosses the first chunk boundary
+        let start = CHUNK_SIZE - 10;
+        let range = get(&url, Some(&format!("bytes={}-{}", start, start + 19)));
+        assert_eq!(range.status, "206 Partial Content");
+        assert_eq!(
+            range.header("Content-Range"),
+            Some(format!("bytes {}-{}/{}", start, start + 19, data.len()).as_str())
+        );
+        assert_eq!(range.body, &data[start..start + 20]);
+
+        let suffix = get(&url, Some("bytes=-5"));
+        assert_eq!(suffix.status, "206 Partial Content");
+        assert_eq!(suffix.body, &data[data.len() - 5..]);
+
+        let open_ended = get(&url, Some(&format!("bytes={}-", 2 * CHUNK_SIZE)));
+        assert_eq!(open_ended.body, &data[2 * CHUNK_SIZE..]);
+    }
+
+    #[test]
+    fn unsatisfiable_range_is_416() {
+        let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new("server_416");
+        let key = CryptoParams::random();
+        let data = sample(1000);
+        let url = media_url(&chunked_file(&dir, &data, &key), shared(&key)).unwrap();
+
+        let response = get(&url, Some("bytes=1000-"));
+        assert_eq!(response.status, "416 Range Not Satisfiable");
+        assert_eq!(response.header("Content-Range"), Some("bytes */1000"));
+        assert!(response.body.is_empty());
+    }
+
+    #[test]
+    fn bad_or_locked_tokens_are_403() {
+        let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new("server_403");
+        let key = CryptoParams::random();
+        let shared_key = shared(&key);
+        let url = media_url(&chunked_file(&dir, &sample(100), &key), shared_key.clone()).unwrap();
+
+        assert_eq!(get(&bad_token(&url), None).status, "403 Forbidden");
+        assert_eq!(get(&url, None).status, "200 OK");
+
+        *shared_key.write().unwrap() = None;
+        assert_eq!(get(&url, None).status, "403 Forbidden");
+    }
+
+    #[test]
+    fn legacy_files_are_decrypted_once_per_token() {
+        let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new("server_legacy");
+        let key = CryptoParams::random();
+        let data = sample(5000);
+        let path = dir.path().join("old.image");
+        fs::write(&path, encrypt_data(&key, &data).unwrap()).unwrap();
+        let url = media_url(&path, shared(&key)).unwrap();
+
+        assert_eq!(get(&url, Some("bytes=0-99")).body, &data[..100]);
+        // Later ranges come out of the cache, the file isn't read again
+        fs::remove_file(&path).unwrap();
+        let range = get(&url, Some("bytes=4000-"));
+        assert_eq!(range.status, "206 Partial Content");
+        assert_eq!(range.body, &data[4000..]);
+    }
+
+    #[test]
+    fn lock_drops_cached_plaintext() {
+        let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+        let _keys = SESSION_KEYS.write().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new("server_lock");
+        let key = CryptoParams::random();
+        let path = dir.path().join("old.image");
+        fs::write(&path, encrypt_data(&key, &sample(5000)).unwrap()).unwrap();
+        let shared_key = shared(&key);
+        register_handle_key(&shared_key);
+        let url = media_url(&path, shared_key).unwrap();
+        let (_, token) = url.rsplit_once('/').unwrap();
+
+        assert_eq!(get(&url, Some("bytes=0-99")).status, "206 Partial Content");
+        let cached = TOKENS.lock().unwrap()[token].plain.is_some();
+        assert!(cached);
+
+        lock(LockReason::Manual).unwrap();
+        // Tokens of other tests have keys nothing locked
+        let (kept, expired_plain) = {
+            let tokens = TOKENS.lock().unwrap();
+            let expired_plain = tokens
+                .values()
+                .any(|token| token.plain.is_some() && token_key(token).is_none());
+            (tokens.contains_key(token), expired_plain)
+        };
+        assert!(!kept);
+        assert!(!expired_plain);
+    }
+
+    #[test]
+    fn connections_past_the_limit_are_turned_away() {
+        let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = TempDir::new("server_limit");
+        let key = CryptoParams::random();
+        let url = media_url(&chunked_file(&dir, &sample(100), &key), shared(&key)).unwrap();
+        let host = url.trim_start_matches("http://").split('/').next().unwrap();
+
+        let wait_for = |active: usize| {
+            let start = Instant::now();
+            while CONNECTIONS.load(Ordering::SeqCst) != active {
+                assert!(start.elapsed() < Duration::from_secs(5));
+                sleep(Duration::from_millis(10));
+            }
+        };
+
+        // Connections that never send a request hold on to their slot
+        let idle: Vec<TcpStream> = (0..MAX_CONNECTIONS)
+            .map(|_| TcpStream::connect(host).unwrap())
+            .collect();
+        wait_for(MAX_CONNECTIONS);
+        assert_eq!(get(&url, None).status, "503 Service Unavailable");
+
+        drop(idle);
+        wait_for(0);
+        assert_eq!(get(&url, None).status, "200 OK");
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Once, RwLock, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+use crate::frb_generated::StreamSink;
+// Custom error
+use crate::utils::error::VaultError;
+// Encryption
+use crate::utils::encryption::CryptoParams;
+// Album index
+use crate::utils::index::forget_indexes;
+// Media server
+use crate::utils::server::forget_expired_tokens;
+
+// How often the watcher looks at the idle time
+const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone)]
+pub enum LockReason {
+    Manual,
+    Inactivity,
+}
+
+#[derive(Clone)]
+pub enum VaultEvent {
+    Locked { reason: LockReason },
+}
+
+// 0 means auto-lock is off
+static AUTO_LOCK_SECONDS: AtomicU64 = AtomicU64::new(0);
+static START_WATCHER: Once = Once::new();
+
+lazy_static! {
+    static ref LAST_ACTIVITY: Mutex<Instant> = Mutex::new(Instant::now());
+    static ref EVENT_SINKS: Mutex<Vec<StreamSink<VaultEvent>>> = Mutex::new(Vec::new());
+    // Keys held by open Vault handles, the session vault of api::file among them
+    static ref HANDLE_KEYS: Mutex<Vec<Weak<RwLock<Option<CryptoParams>>>>> = Mutex::new(Vec::new());
+}
+
+pub fn register_handle_key(key: &Arc<RwLock<Option<CryptoParams>>>) {
+    if let Ok(mut keys) = HANDLE_KEYS.lock() {
+        keys.retain(|key| key.strong_count() > 0);
+        keys.push(Arc::downgrade(key));
+    }
+}
+
+pub fn record_activity() {
+    if let Ok(mut last_activity) = LAST_ACTIVITY.lock() {
+        *last_activity = Instant::now();
+    }
+}
+
+pub fn lock(reason: LockReason) -> Result<(), VaultError> {
+    let mut was_unlocked = false;
+    forget_indexes();
+    if let Ok(mut keys) = HANDLE_KEYS.lock() {
+        keys.retain(|key| match key.upgrade() {
+            Some(key) => {
+                if let Ok(mut key) = key.write() {
+                    was_unlocked |= key.take().is_some();
+                }
+                true
+            }
+            None => false,
+        });
+    }
+    forget_expired_tokens();
+    if was_unlocked {
+        emit(VaultEvent::Locked { reason });
+    }
+    Ok(())
+}
+
+pub fn set_auto_lock(seconds: u64) {
+    AUTO_LOCK_SECONDS.store(seconds, Ordering::SeqCst);
+    record_activity();
+    if seconds > 0 {
+        START_WATCHER.call_once(|| {
+            thread::spawn(watch_inactivity);
+        });
+    }
+}
+
+pub fn add_event_sink(sink: StreamSink<VaultEvent>) -> Result<(), VaultError> {
+    EVENT_SINKS
+        .lock()
+        .map_err(|e| VaultError::Internal {
+            message: e.to_string(),
+        })?
+        .push(sink);
+    Ok(())
+}
+
+fn emit(event: VaultEvent) {
+    if let Ok(mut sinks) = EVENT_SINKS.lock() {
+        // Sinks whose Dart stream was closed fail to send and are dropped
+        sinks.retain(|sink| sink.add(event.clone()).is_ok());
+    }
+}
+
+fn watch_inactivity() {
+    loop {
+        thread::sleep(WATCH_INTERVAL);
+
+        let seconds = AUTO_LOCK_SECONDS.load(Ordering::SeqCst);
+        if seconds == 0 {
+            continue;
+        }
+        let idle = match LAST_ACTIVITY.lock() {
+            Ok(last_activity) => last_activity.elapsed(),
+            Err(_) => continue,
+        };
+        if idle >= Duration::from_secs(seconds) {
+            let _ = lock(LockReason::Inactivity);
+        }
+    }
+}
@@ -0,0 +1,839 @@
+// file manipulation
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs::{self, remove_dir_all, remove_file, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use chrono::Utc;
+use zeroize::Zeroizing;
+
+// Custom error
+use crate::utils::{encryption::VAULT_FILE, error::VaultError};
+// Encrytion
+use crate::utils::encryption::{decrypt_data, CryptoParams, CHUNK_SIZE};
+// Caching
+use crate::utils::cache::{cache_file, cache_image, PREVIEW_PREFIX_LEN};
+// Progress
+use crate::utils::progress::Progress;
+// Content hashes
+use crate::utils::manifest::EntryHasher;
+// Streaming encryption
+use crate::utils::stream::{ChunkReader, ChunkWriter};
+// Album index
+use crate::utils::index::{lock_indexes, MediaEntry};
+// Utils
+use crate::utils::utils::generate_unique_filename;
+use crate::utils::utils::is_internal_name;
+use crate::utils::utils::rename_with_parent;
+
+use infer;
+
+pub fn create_dir(dir: String, album_name: String) -> Result<(), VaultError> {
+    match fs::create_dir(dir.clone() + "/" + &album_name) {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            if e.kind() == io::ErrorKind::NotFound {
+                fs::create_dir_all(dir.clone()).map_err(|e| VaultError::io(e, &dir))?;
+                fs::create_dir(dir.clone() + "/" + &album_name)
+                    .map_err(|e| VaultError::io(e, Path::new(&dir).join(&album_name)))?;
+                Ok(())
+            } else {
+                Err(VaultError::io(e, Path::new(&dir).join(&album_name)))
+            }
+        }
+    }
+}
+
+pub fn delete_dir(dir: &str) -> Result<(), VaultError> {
+    if Path::new(dir).join(VAULT_FILE).exists() {
+        return Err(VaultError::InvalidInput {
+            message: "Refusing to delete the vault root".to_string(),
+        });
+    }
+    match remove_dir_all(&dir) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(VaultError::io(e, dir)),
+    }
+}
+
+pub fn get_dirs(dir: String) -> Result<Vec<String>, VaultError> {
+    match fs::read_dir(dir.clone()) {
+        Ok(entries) => {
+            let mut directories: Vec<String> = vec![];
+            for entry in entries {
+                match entry {
+                    Ok(entry) => {
+                        if entry.path().is_dir() {
+                            directories.push(entry.file_name().to_string_lossy().to_string());
+                        };
+                    }
+                    Err(e) => return Err(VaultError::io(e, &dir)),
+                }
+            }
+            Ok(directories)
+        }
+        Err(_) => match fs::create_dir(&dir) {
+            Ok(_) => Ok(vec![]),
+            Err(e) => Err(VaultError::io(e, &dir)),
+        },
+    }
+}
+
+pub fn get_images(
+    dir: String,
+    key: &CryptoParams,
+) -> Result<HashMap<String, (String, f32)>, VaultError> {
+    let mut indexes = lock_indexes();
+    let index = indexes.open(Path::new(&dir), key)?;
+
+    let mut files: HashMap<String, (String, f32)> = HashMap::new();
+    for (name, entry) in index.entries() {
+        // Plain files saved with save_file have no preview to show
+        if let (Some(hash), Some(aspect_ratio)) = (&entry.blurhash, entry.aspect_ratio) {
+            files.insert(name.clone(), (hash.clone(), aspect_ratio));
+        }
+    }
+    Ok(files)
+}
+
+pub fn get_album_thumb(
+    dir: &str,
+    key: &CryptoParams,
+) -> Result<Option<HashMap<String, (String, f32)>>, VaultError> {
+    let images = get_images(dir.to_owned(), key)?;
+    let smallest_entry = images.iter().min_by_key(|entry| entry.0.clone());
+
+    match smallest_entry {
+        Some((key, value)) => {
+            let mut map = HashMap::new();
+            map.insert(key.clone(), value.clone());
+            Ok(Some(map))
+        }
+        None => Ok(None),
+    }
+}
+
+pub fn get_file_thumb(path: &str, key: &CryptoParams) -> Result<Vec<u8>, VaultError> {
+    let mut files_list = path
+        .to_string()
+        .split("/")
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    // let filename = files_list.pop().unwrap();
+    match files_list.pop() {
+        Some(filename) => {
+            let path = files_list.join("/") + "/.thumbs/" + &filename;
+
+            match File::open(&path) {
+                Ok(file) => {
+                    let mut reader = BufReader::new(file);
+                    let mut buffer = Vec::new();
+                    match reader.read_to_end(&mut buffer) {
+                        Ok(_) => Ok(decrypt_data(key, &buffer).map_err(|e| e.at(&path))?),
+                        Err(e) => Err(VaultError::io(e, &path)),
+                    }
+                }
+                Err(e) => Err(VaultError::io(e, &path)),
+            }
+        }
+        None => Err(VaultError::InvalidInput {
+            message: format!("Invalid path: {}", path),
+        }),
+    }
+}
+
+pub fn get_file(path: &str, key: &CryptoParams) -> Result<Vec<u8>, VaultError> {
+    let mut data = Vec::new();
+    stream_file(path, key, |chunk| {
+        data.extend(chunk);
+        Ok(())
+    })?;
+    Ok(data)
+}
+
+// Hands the decrypted file to `each` one chunk at a time. Files from before the chunked
+// format are decrypted whole and then handed over in chunks.
+pub fn stream_file(
+    path: &str,
+    key: &CryptoParams,
+    mut each: impl FnMut(Vec<u8>) -> Result<(), VaultError>,
+) -> Result<(), VaultError> {
+    let mut reader = match ChunkReader::open(Path::new(path), key)? {
+        Some(reader) => reader,
+        None => {
+            let buffer = fs::read(path).map_err(|e| VaultError::io(e, path))?;
+            let data = decrypt_data(key, &buffer).map_err(|e| e.at(path))?;
+            for chunk in data.chunks(CHUNK_SIZE) {
+                each(chunk.to_vec())?;
+            }
+            return Ok(());
+        }
+    };
+
+    for index in 0..reader.chunks() {
+        each(reader.read_chunk(index)?)?;
+    }
+    Ok(())
+}
+
+// `len` bytes of the decrypted file from `offset` on, for players that seek. Costs about
+// as much as the range itself for chunked files, older files are decrypted whole.
+pub fn read_range(
+    path: &str,
+    offset: u64,
+    len: u64,
+    key: &CryptoParams,
+) -> Result<Vec<u8>, VaultError> {
+    match ChunkReader::open(Path::new(path), key)? {
+        Some(mut reader) => reader.read_range(offset, len),
+        None => {
+            let buffer = fs::read(path).map_err(|e| VaultError::io(e, path))?;
+            let data = decrypt_data(key, &buffer).map_err(|e| e.at(path))?;
+            let start = offset.min(data.len() as u64) as usize;
+            let end = offset.saturating_add(len).min(data.len() as u64) as usize;
+            Ok(data[start..end].to_vec())
+        }
+    }
+}
+
+// What the caller knows about a file from before it was imported
+pub struct MediaSource {
+    pub original_name: Option<String>,
+    pub mime_type: Option<String>,
+    // Seconds since the epoch
+    pub modified_at: Option<i64>,
+}
+
+// Metadata of one file in the vault, see get_media_info
+pub struct MediaInfo {
+    pub name: String,
+    pub original_name: Option<String>,
+    pub mime_type: Option<String>,
+    pub size: Option<u64>,
+    pub aspect_ratio: Option<f32>,
+    pub created_at: i64,
+    pub modified_at: Option<i64>,
+}
+
+pub fn save_media(
+    image_data: Vec<u8>,
+    dir: String,
+    source: Option<MediaSource>,
+    key: &CryptoParams,
+) -> Result<(), VaultError> {
+    store_media(image_data, dir, source, key).map(|_| ())
+}
+
+// save_media for callers that need to know the name it picked
+fn store_media(
+    image_data: Vec<u8>,
+    dir: String,
+    source: Option<MediaSource>,
+    key: &CryptoParams,
+) -> Result<PathBuf, VaultError> {
+    let info = infer::get(&image_data);
+
+    let category = match info {
+        Some(t) if t.matcher_type() == infer::MatcherType::IMAGE => "image",
+        Some(t) if t.matcher_type() == infer::MatcherType::VIDEO => "video",
+        _ => "file",
+    };
+
+    let filename = generate_unique_filename(&dir, category)?;
+    let path = Path::new(&dir).join(&filename);
+
+    let (blurhash, aspect_ratio) = cache_image(
+        &image_data,
+        path.as_os_str().to_string_lossy().to_string(),
+        2,
+        2,
+        key,
+    )?;
+    let mut entry = new_entry(&image_data, source);
+    entry.blurhash = Some(blurhash);
+    entry.aspect_ratio = Some(aspect_ratio);
+    entry.sha256 = Some(content_hash(&image_data));
+    write_media(&path, key, || {
+        write_encrypted(&path, &image_data, key)?;
+        Ok(entry)
+    })?;
+    Ok(path)
+}
+
+pub fn save_file(
+    image_data: Vec<u8>,
+    dir: String,
+    source: Option<MediaSource>,
+    key: &CryptoParams,
+) -> Result<(), VaultError> {
+    let path = Path::new(&dir).join(generate_unique_filename(&dir, "file")?);
+    let mut entry = new_entry(&image_data, source);
+    entry.sha256 = Some(content_hash(&image_data));
+    write_media(&path, key, || {
+        write_encrypted(&path, &image_data, key)?;
+        Ok(entry)
+    })
+}
+
+// Bytes of the source used to tell images, videos and other files apart
+const SNIFF_LEN: u64 = 8 * 1024;
+
+// Imports a file without ever holding all of it in memory, it is encrypted one chunk at
+// a time as it's read. The source is overwritten and removed afterwards if asked to.
+pub fn import_path(
+    src_path: &str,
+    dir: String,
+    source: Option<MediaSource>,
+    secure_delete: bool,
+    key: &CryptoParams,
+    progress: &mut Progress,
+) -> Result<(), VaultError> {
+    let src = Path::new(src_path);
+    let metadata = fs::metadata(src).map_err(|e| VaultError::io(e, src))?;
+    if !metadata.is_file() {
+        return Err(VaultError::InvalidInput {
+            message: format!("'{}' is not a file", src_path),
+        });
+    }
+
+    let mut prefix = Vec::new();
+    File::open(src)
+        .and_then(|file| file.take(SNIFF_LEN).read_to_end(&mut prefix))
+        .map_err(|e| VaultError::io(e, src))?;
+    let category = match infer::get(&prefix) {
+        Some(t) if t.matcher_type() == infer::MatcherType::IMAGE => "image",
+        Some(t) if t.matcher_type() == infer::MatcherType::VIDEO => "video",
+        _ => "file",
+    };
+
+    // Whatever Dart didn't pass is taken from the source file itself
+    let source = source.unwrap_or(MediaSource {
+        original_name: None,
+        mime_type: None,
+        modified_at: None,
+    });
+    let source = MediaSource {
+        original_name: source.original_name.or_else(|| {
+            src.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        }),
+        mime_type: source.mime_type,
+        modified_at: source.modified_at.or_else(|| {
+            metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs() as i64)
+        }),
+    };
+
+    let path = Path::new(&dir).join(generate_unique_filename(&dir, category)?);
+    write_media(&path, key, || {
+        let (blurhash, aspect_ratio) = cache_file(
+            src,
+            category == "video",
+            path.to_string_lossy().to_string(),
+            2,
+            2,
+            key,
+        )?;
+
+        let mut reader = File::open(src).map_err(|e| VaultError::io(e, src))?;
+        let file = File::create(&path).map_err(|e| VaultError::io(e, &path))?;
+        let mut writer = ChunkWriter::new(BufWriter::new(file), &path, key)?;
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        let mut hasher = EntryHasher::new();
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(VaultError::io(e, src)),
+            };
+            writer.write(&buffer[..read])?;
+            hasher.update(&buffer[..read]);
+            // Cancelling removes the file again, see write_media
+            progress.add_bytes(read as u64)?;
+        }
+        writer
+            .finish()?
+            .into_inner()
+            .map_err(|e| VaultError::io(e.into_error(), &path))?
+            .sync_all()
+            .map_err(|e| VaultError::io(e, &path))?;
+
+        let mut entry = new_entry(&prefix, Some(source));
+        entry.blurhash = Some(blurhash);
+        entry.aspect_ratio = Some(aspect_ratio);
+        entry.size = Some(hasher.size());
+        entry.sha256 = Some(hasher.finish());
+        Ok(entry)
+    })?;
+
+    if secure_delete {
+        overwrite_and_remove(src)?;
+    }
+    Ok(())
+}
+
+// Part files of a PendingMedia that wasn't finished, not part of the vault yet
+pub(crate) fn is_part_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .is_some_and(|name| name.starts_with('.') && name.ends_with(".part"))
+}
+
+// A file handed over piece by piece. It's written to a hidden part file and only shows up
+// in the album once finished, a dropped PendingMedia removes the part file again.
+pub struct PendingMedia {
+    dir: String,
+    part_path: PathBuf,
+    writer: Option<ChunkWriter<BufWriter<File>>>,
+    // Start of the data to tell its type, the preview is read back from the finished file
+    prefix: Zeroizing<Vec<u8>>,
+    hasher: EntryHasher,
+    source: Option<MediaSource>,
+}
+
+pub fn begin_media(
+    dir: String,
+    source: Option<MediaSource>,
+    key: &CryptoParams,
+) -> Result<PendingMedia, VaultError> {
+    let part_path = Path::new(&dir).join(format!(".{}", generate_unique_filename(&dir, "part")?));
+    let file = File::create(&part_path).map_err(|e| VaultError::io(e, &part_path))?;
+    let writer = ChunkWriter::new(BufWriter::new(file), &part_path, key)?;
+
+    Ok(PendingMedia {
+        dir,
+        part_path,
+        writer: Some(writer),
+        prefix: Zeroizing::new(Vec::new()),
+        hasher: EntryHasher::new(),
+        source,
+    })
+}
+
+impl PendingMedia {
+    pub fn write(&mut self, data: &[u8]) -> Result<(), VaultError> {
+        let writer = self
+            .writer
+            .as_mut()
+            .ok_or_else(|| VaultError::InvalidInput {
+                message: "The file was already finished".to_string(),
+            })?;
+        writer.write(data)?;
+
+        let keep = (SNIFF_LEN as usize)
+            .saturating_sub(self.prefix.len())
+            .min(data.len());
+        self.prefix.extend_from_slice(&data[..keep]);
+        self.hasher.update(data);
+        Ok(())
+    }
+
+    // Hash of everything written so far
+    pub fn sha256(&self) -> String {
+        self.hasher.clone().finish()
+    }
+
+    // Moves the file into the album under its final name and indexes it
+    pub fn finish(mut self, key: &CryptoParams) -> Result<PathBuf, VaultError> {
+        let writer = self.writer.take().ok_or_else(|| VaultError::InvalidInput {
+            message: "The file was already finished".to_string(),
+        })?;
+        let category = match infer::get(&self.prefix) {
+            Some(t) if t.matcher_type() == infer::MatcherType::IMAGE => "image",
+            Some(t) if t.matcher_type() == infer::MatcherType::VIDEO => "video",
+            _ => "file",
+        };
+        let path = Path::new(&self.dir).join(generate_unique_filename(&self.dir, category)?);
+
+        let result = write_media(&path, key, || {
+            writer
+                .finish()?
+                .into_inner()
+                .map_err(|e| VaultError::io(e.into_error(), &self.part_path))?
+                .sync_all()
+                .map_err(|e| VaultError::io(e, &self.part_path))?;
+            // Decrypted again only now, so no plaintext is kept around while the data comes in
+            let preview = Zeroizing::new(read_range(
+                &self.part_path.to_string_lossy(),
+                0,
+                PREVIEW_PREFIX_LEN,
+                key,
+            )?);
+            let (blurhash, aspect_ratio) =
+                cache_image(&preview, path.to_string_lossy().to_string(), 2, 2, key)?;
+            fs::rename(&self.part_path, &path).map_err(|e| VaultError::io(e, &path))?;
+
+            let mut entry = new_entry(&self.prefix, self.source.take());
+            entry.blurhash = Some(blurhash);
+            entry.aspect_ratio = Some(aspect_ratio);
+            let hasher = std::mem::take(&mut self.hasher);
+            entry.size = Some(hasher.size());
+            entry.sha256 = Some(hasher.finish());
+            Ok(entry)
+        });
+
+        if result.is_err() {
+            let _ = remove_file(&self.part_path);
+        }
+        result.map(|_| path)
+    }
+}
+
+impl Drop for PendingMedia {
+    fn drop(&mut self) {
+        if self.writer.take().is_some() {
+            let _ = remove_file(&self.part_path);
+        }
+    }
+}
+
+// Imports several files one after the other. A cancelled import keeps the files that
+// were already done and removes the one it was working on.
+pub fn import_paths(
+    src_paths: &[String],
+    dir: String,
+    secure_delete: bool,
+    key: &CryptoParams,
+    progress: &mut Progress,
+) -> Result<(), VaultError> {
+    let bytes_total = src_paths
+        .iter()
+        .filter_map(|src_path| fs::metadata(src_path).ok())
+        .map(|metadata| metadata.len())
+        .sum();
+    progress.start(src_paths.len() as u32, bytes_total);
+
+    for src_path in src_paths {
+        progress.begin_item(src_path)?;
+        import_path(src_path, dir.clone(), None, secure_delete, key, progress)?;
+        progress.finish_item();
+    }
+    Ok(())
+}
+
+// Overwrites a file with zeros before removing it. Flash storage and copy on write file
+// systems can keep the old blocks around, so this only keeps the contents from being
+// read back through the file system.
+fn overwrite_and_remove(path: &Path) -> Result<(), VaultError> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .map_err(|e| VaultError::io(e, path))?;
+    let len = file.metadata().map_err(|e| VaultError::io(e, path))?.len();
+
+    let zeros = vec![0u8; CHUNK_SIZE];
+    let mut left = len;
+    while left > 0 {
+        let take = left.min(zeros.len() as u64) as usize;
+        file.write_all(&zeros[..take])
+            .map_err(|e| VaultError::io(e, path))?;
+        left -= take as u64;
+    }
+    file.sync_all().map_err(|e| VaultError::io(e, path))?;
+    drop(file);
+
+    remove_file(path).map_err(|e| VaultError::io(e, path))
+}
+
+// Index entry of newly imported data, without a preview
+fn new_entry(data: &[u8], source: Option<MediaSource>) -> MediaEntry {
+    let source = source.unwrap_or(MediaSource {
+        original_name: None,
+        mime_type: None,
+        modified_at: None,
+    });
+
+    MediaEntry {
+        blurhash: None,
+        aspect_ratio: None,
+        size: Some(data.len() as u64),
+        // The MIME type Dart got from the platform is more specific than sniffing the bytes
+        mime_type: source
+            .mime_type
+            .or_else(|| infer::get(data).map(|t| t.mime_type().to_string())),
+        // Only the name, a directory part would let an export escape its folder
+        original_name: source.original_name.and_then(|name| {
+            Path::new(&name)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        }),
+        created_at: Utc::now().timestamp(),
+        modified_at: source.modified_at,
+        // Only known once all of the data went through, see the callers
+        sha256: None,
+    }
+}
+
+pub(crate) fn content_hash(data: &[u8]) -> String {
+    let mut hasher = EntryHasher::new();
+    hasher.update(data);
+    hasher.finish()
+}
+
+pub fn get_media_info(path: &str, key: &CryptoParams) -> Result<MediaInfo, VaultError> {
+    let (dir, name) = split_file_path(Path::new(path))?;
+    let name = name.to_string_lossy().to_string();
+
+    let entry = lock_indexes()
+        .open(dir, key)?
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| VaultError::NotFound {
+            path: path.to_string(),
+        })?;
+
+    Ok(MediaInfo {
+        original_name: entry.original_name.clone(),
+        mime_type: entry.mime_type.clone(),
+        size: entry.size,
+        aspect_ratio: entry.aspect_ratio,
+        created_at: entry.created_at,
+        modified_at: entry.modified_at,
+        name,
+    })
+}
+
+// Runs `write` to create the file at `path` and then records the entry it returns. A file
+// that fails to write or whose entry can't be recorded is removed again, so the album
+// never shows something half saved.
+fn write_media(
+    path: &Path,
+    key: &CryptoParams,
+    write: impl FnOnce() -> Result<MediaEntry, VaultError>,
+) -> Result<(), VaultError> {
+    let (dir, name) = split_file_path(path)?;
+
+    let indexed = write().and_then(|entry| {
+        lock_indexes()
+            .open(dir, key)?
+            .put(&name.to_string_lossy(), entry, key)
+    });
+
+    if indexed.is_err() {
+        let _ = remove_file(path);
+        let _ = remove_file(dir.join(".thumbs").join(name));
+    }
+    indexed
+}
+
+fn write_encrypted(path: &Path, data: &[u8], key: &CryptoParams) -> Result<(), VaultError> {
+    let file = File::create(path).map_err(|e| VaultError::io(e, path))?;
+    let mut writer = ChunkWriter::new(BufWriter::new(file), path, key)?;
+    writer.write(data)?;
+    writer
+        .finish()?
+        .into_inner()
+        .map_err(|e| VaultError::io(e.into_error(), path))?;
+    Ok(())
+}
+
+pub fn delete_file(path: &str, key: &CryptoParams) -> Result<(), VaultError> {
+    let file_path = Path::new(path);
+    let (parent_folder, file_name) = split_file_path(file_path)?;
+    check_not_internal(file_path)?;
+
+    let mut thumbs_file = PathBuf::from(parent_folder);
+    thumbs_file.push(".thumbs");
+    thumbs_file.push(file_name);
+
+    remove_file(path).map_err(|e| VaultError::io(e, path))?;
+
+    if thumbs_file.exists() {
+        remove_file(&thumbs_file).map_err(|e| VaultError::io(e, &thumbs_file))?;
+    }
+
+    lock_indexes()
+        .open(parent_folder, key)?
+        .remove(&file_name.to_string_lossy(), key)?;
+
+    Ok(())
+}
+
+// Keeps delete and move away from the key, indexes, thumbnails and their contents
+fn check_not_internal(path: &Path) -> Result<(), VaultError> {
+    let mut last_two = path.components().rev().take(2);
+    if last_two.any(|component| is_internal_name(component.as_os_str())) {
+        return Err(VaultError::PathOutsideVault {
+            path: path.to_string_lossy().to_string(),
+        });
+    }
+    Ok(())
+}
+
+// Folder and name of a media file, the .thumbs entry lives next to it
+fn split_file_path(path: &Path) -> Result<(&Path, &OsStr), VaultError> {
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => Ok((parent, name)),
+        _ => Err(VaultError::InvalidInput {
+            message: format!("Invalid path: {}", path.display()),
+        }),
+    }
+}
+
+pub fn move_file(source_file: &str, dest_dir: &str, key: &CryptoParams) -> Result<(), VaultError> {
+    let source_path = Path::new(source_file);
+    let (parent_folder, source_name) = split_file_path(source_path)?;
+    let name = source_name.to_string_lossy();
+
+    let dest_dir_path = Path::new(dest_dir);
+    check_not_internal(source_path)?;
+    check_not_internal(dest_dir_path)?;
+
+    let mut dest_file = PathBuf::from(dest_dir_path);
+    dest_file.push(source_name);
+    if dest_file == source_path {
+        return Ok(());
+    }
+
+    let mut thumbs_file = PathBuf::from(parent_folder);
+    thumbs_file.push(".thumbs");
+    thumbs_file.push(source_name);
+
+    let mut dest_thumb_file = PathBuf::from(dest_dir_path);
+    dest_thumb_file.push(".thumbs");
+    dest_thumb_file.push(source_name);
+
+    // Both indexes are loaded before anything moves, so one that can't be read stops it
+    let mut indexes = lock_indexes();
+    let entry = indexes.open(parent_folder, key)?.get(&name).cloned();
+    indexes.open(dest_dir_path, key)?;
+
+    rename_with_parent(source_path, dest_file.as_path())?;
+    if thumbs_file.exists() {
+        rename_with_parent(thumbs_file.as_path(), dest_thumb_file.as_path())?;
+    }
+
+    // The entry is added to the destination first, a failure in between leaves it in
+    // both indexes which the next move or delete cleans up, never in neither
+    if let Some(entry) = entry {
+        indexes.open(dest_dir_path, key)?.put(&name, entry, key)?;
+        indexes.open(parent_folder, key)?.remove(&name, key)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::encryption::encrypt_data;
+    use crate::utils::testing::{vault, TempDir};
+
+    #[test]
+    fn read_range_crosses_chunks() {
+        let dir = TempDir::new("storage_range");
+        let key = CryptoParams::random();
+        let data: Vec<u8> = (0..3 * CHUNK_SIZE + 7).map(|i| (i % 251) as u8).collect();
+        let chunked = dir.path().join("chunked");
+        write_encrypted(&chunked, &data, &key).unwrap();
+        // Files from before the chunked format are decrypted whole but cut the same way
+        let legacy = dir.path().join("legacy");
+        fs::write(&legacy, encrypt_data(&key, &data).unwrap()).unwrap();
+
+        let len = data.len() as u64;
+        let chunk = CHUNK_SIZE as u64;
+        let ranges = [
+            (0, 10),
+            (chunk - 3, 6),
+            (chunk - 1, chunk + 2),
+            (0, len),
+            (2 * chunk, chunk),
+            (len - 5, 100),
+            (len, 10),
+            (len + 10, 10),
+            (5, 0),
+        ];
+        for path in [&chunked, &legacy] {
+            let path = path.to_string_lossy();
+            for (offset, range_len) in ranges {
+                let start = offset.min(len) as usize;
+                let end = (offset + range_len).min(len) as usize;
+                assert_eq!(
+                    read_range(&path, offset, range_len, &key).unwrap(),
+                    data[start..end],
+                    "{} bytes from {} of {}",
+                    range_len,
+                    offset,
+                    path
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pending_media_previews_the_finished_file() {
+        let (root, key) = vault("storage_preview");
+        // Noise doesn't compress, so the image is well past what's kept to tell its type
+        let pixels: Vec<u8> = (0..256 * 128 * 3u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+        let mut png = Vec::new();
+        image::write_buffer_with_format(
+            &mut io::Cursor::new(&mut png),
+            &pixels,
+            256,
+            128,
+            image::ColorType::Rgb8,
+            image::ImageFormat::Png,
+        )
+        .unwrap();
+        assert!(png.len() as u64 > SNIFF_LEN);
+
+        let mut pending = begin_media(root.join("album"), None, &key).unwrap();
+        for part in png.chunks(1000) {
+            pending.write(part).unwrap();
+        }
+        assert_eq!(pending.prefix.len() as u64, SNIFF_LEN);
+        let path = pending.finish(&key).unwrap();
+
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        let entry = lock_indexes()
+            .open(&root.path().join("album"), &key)
+            .unwrap()
+            .get(&name)
+            .cloned()
+            .unwrap();
+        // The fallback thumbnail is square
+        assert_eq!(entry.aspect_ratio, Some(2.0));
+        assert!(root.path().join("album/.thumbs").join(&name).is_file());
+    }
+
+    #[test]
+    fn delete_and_move_refuse_internal_files() {
+        let root = TempDir::new("storage_internal");
+        let key = CryptoParams::random();
+        fs::create_dir_all(root.path().join("album/.thumbs")).unwrap();
+        fs::create_dir(root.path().join("other")).unwrap();
+        for path in [".vault-key", "album/.index", "album/.thumbs/a.image"] {
+            fs::write(root.path().join(path), b"internal").unwrap();
+        }
+
+        for path in [".vault-key", "album/.index", "album/.thumbs/a.image"] {
+            assert!(matches!(
+                delete_file(&root.join(path), &key),
+                Err(VaultError::PathOutsideVault { .. })
+            ));
+            assert!(matches!(
+                move_file(&root.join(path), &root.join("other"), &key),
+                Err(VaultError::PathOutsideVault { .. })
+            ));
+            assert!(root.path().join(path).exists());
+        }
+        assert!(matches!(
+            move_file(
+                &root.join("album/a.image"),
+                &root.join("album/.thumbs"),
+                &key
+            ),
+            Err(VaultError::PathOutsideVault { .. })
+        ));
+    }
+}
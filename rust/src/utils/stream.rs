@@ -0,0 +1,387 @@
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+// Custom error
+use crate::utils::error::VaultError;
+// Encrytion
+use crate::utils::encryption::{decrypt_data, ChunkCipher, CryptoParams, CHUNK_HEADER_LEN};
+
+// Encrypts a file chunk by chunk as it is written, so only one chunk is ever in memory
+pub struct ChunkWriter<W: Write> {
+    inner: W,
+    // Only used in errors
+    path: PathBuf,
+    cipher: ChunkCipher,
+    buffer: Vec<u8>,
+    index: u32,
+}
+
+impl<W: Write> ChunkWriter<W> {
+    pub fn new(mut inner: W, path: &Path, key: &CryptoParams) -> Result<Self, VaultError> {
+        let cipher = ChunkCipher::new(key)?;
+        inner
+            .write_all(cipher.header())
+            .map_err(|e| VaultError::io(e, path))?;
+
+        Ok(ChunkWriter {
+            path: path.to_path_buf(),
+            buffer: Vec::with_capacity(cipher.chunk_size()),
+            inner,
+            cipher,
+            index: 0,
+        })
+    }
+
+    pub fn write(&mut self, mut data: &[u8]) -> Result<(), VaultError> {
+        while !data.is_empty() {
+            // A full chunk is only sealed once more data shows it isn't the last one
+            if self.buffer.len() == self.cipher.chunk_size() {
+                self.seal(false)?;
+            }
+            let take = (self.cipher.chunk_size() - self.buffer.len()).min(data.len());
+            self.buffer.extend_from_slice(&data[..take]);
+            data = &data[take..];
+        }
+        Ok(())
+    }
+
+    // Seals the last chunk, a file that isn't finished can't be decrypted
+    pub fn finish(mut self) -> Result<W, VaultError> {
+        self.seal(true)?;
+        self.inner
+            .flush()
+            .map_err(|e| VaultError::io(e, &self.path))?;
+        Ok(self.inner)
+    }
+
+    fn seal(&mut self, last: bool) -> Result<(), VaultError> {
+        let sealed = self.cipher.seal(self.index, last, &self.buffer)?;
+        self.inner
+            .write_all(&sealed)
+            .map_err(|e| VaultError::io(e, &self.path))?;
+        self.buffer.clear();
+        self.index = self
+            .index
+            .checked_add(1)
+            .ok_or_else(|| VaultError::InvalidInput {
+                message: "File is too large to encrypt".to_string(),
+            })?;
+        Ok(())
+    }
+}
+
+// Decrypts a chunked file one chunk at a time, every chunk can be read on its own
+pub struct ChunkReader {
+    file: File,
+    path: PathBuf,
+    cipher: ChunkCipher,
+    chunks: u64,
+    len: u64,
+}
+
+impl ChunkReader {
+    // None for files written before the chunked format, those can only be decrypted whole
+    pub fn open(path: &Path, key: &CryptoParams) -> Result<Option<Self>, VaultError> {
+        let mut file = File::open(path).map_err(|e| VaultError::io(e, path))?;
+        let file_len = file.metadata().map_err(|e| VaultError::io(e, path))?.len();
+
+        let mut header = Vec::with_capacity(CHUNK_HEADER_LEN);
+        (&mut file)
+            .take(CHUNK_HEADER_LEN as u64)
+            .read_to_end(&mut header)
+            .map_err(|e| VaultError::io(e, path))?;
+        if !ChunkCipher::is_chunked(&header) {
+            return Ok(None);
+        }
+        let cipher = ChunkCipher::open_header(key, &header).map_err(|e| e.at(path))?;
+
+        // Every chunk is full except the last one, which holds at least its tag
+        let sealed = cipher.sealed_chunk_len() as u64;
+        let tag = sealed - cipher.chunk_size() as u64;
+        let body = file_len.saturating_sub(cipher.header().len() as u64);
+        let chunks = body.div_ceil(sealed);
+        let last = body - chunks.saturating_sub(1) * sealed;
+        if chunks == 0 || last < tag {
+            return Err(VaultError::Corrupted {
+                path: Some(path.to_string_lossy().to_string()),
+            });
+        }
+
+        Ok(Some(ChunkReader {
+            len: (chunks - 1) * cipher.chunk_size() as u64 + last - tag,
+            path: path.to_path_buf(),
+            file,
+            cipher,
+            chunks,
+        }))
+    }
+
+    // Size of the decrypted file
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn chunk_size(&self) -> usize {
+        self.cipher.chunk_size()
+    }
+
+    pub fn chunks(&self) -> u64 {
+        self.chunks
+    }
+
+    // Decrypts only the chunks the range touches, past the end of the file it's cut short
+    pub fn read_range(&mut self, offset: u64, len: u64) -> Result<Vec<u8>, VaultError> {
+        let end = offset.saturating_add(len).min(self.len);
+        if offset >= end {
+            return Ok(Vec::new());
+        }
+
+        let chunk_size = self.chunk_size() as u64;
+        let mut data = Vec::with_capacity((end - offset) as usize);
+        for index in offset / chunk_size..end.div_ceil(chunk_size) {
+            let chunk = self.read_chunk(index)?;
+            let start = index * chunk_size;
+            let from = offset.saturating_sub(start) as usize;
+            let to = ((end - start) as usize).min(chunk.len());
+            data.extend_from_slice(&chunk[from..to]);
+        }
+        Ok(data)
+    }
+
+    pub fn read_chunk(&mut self, index: u64) -> Result<Vec<u8>, VaultError> {
+        let last = index + 1 == self.chunks;
+        let nonce_index = u32::try_from(index)
+            .ok()
+            .filter(|_| index < self.chunks)
+            .ok_or_else(|| VaultError::InvalidInput {
+                message: format!("Chunk {} is past the end of the file", index),
+            })?;
+
+        let sealed = self.cipher.sealed_chunk_len() as u64;
+        let offset = self.cipher.header().len() as u64 + index * sealed;
+        let mut chunk = Vec::with_capacity(sealed as usize);
+        self.file
+            .seek(SeekFrom::Start(offset))
+            .and_then(|_| (&mut self.file).take(sealed).read_to_end(&mut chunk))
+            .map_err(|e| VaultError::io(e, &self.path))?;
+
+        self.cipher
+            .open(nonce_index, last, &chunk)
+            .map_err(|e| e.at(&self.path))
+    }
+}
+
+// Decrypts a blob from a reader that can't seek, such as an archive entry. Chunked blobs are
+// handed to `each` a chunk at a time, older ones are read and decrypted whole.
+pub fn decrypt_reader(
+    mut reader: impl Read,
+    path: &Path,
+    key: &CryptoParams,
+    mut each: impl FnMut(Vec<u8>) -> Result<(), VaultError>,
+) -> Result<(), VaultError> {
+    let mut header = Vec::with_capacity(CHUNK_HEADER_LEN);
+    (&mut reader)
+        .take(CHUNK_HEADER_LEN as u64)
+        .read_to_end(&mut header)
+        .map_err(|e| VaultError::io(e, path))?;
+    if !ChunkCipher::is_chunked(&header) {
+        reader
+            .read_to_end(&mut header)
+            .map_err(|e| VaultError::io(e, path))?;
+        return each(decrypt_data(key, &header).map_err(|e| e.at(path))?);
+    }
+    let cipher = ChunkCipher::open_header(key, &header).map_err(|e| e.at(path))?;
+
+    let sealed = cipher.sealed_chunk_len() as u64;
+    let mut read_sealed = || {
+        let mut chunk = Vec::with_capacity(sealed as usize);
+        (&mut reader)
+            .take(sealed)
+            .read_to_end(&mut chunk)
+            .map_err(|e| VaultError::io(e, path))?;
+        Ok::<_, VaultError>(chunk)
+    };
+
+    // One chunk is read ahead, only the end of the stream tells which one is last
+    let mut chunk = read_sealed()?;
+    let mut index: u32 = 0;
+    loop {
+        let next = read_sealed()?;
+        let last = next.is_empty();
+        each(cipher.open(index, last, &chunk).map_err(|e| e.at(path))?)?;
+        if last {
+            return Ok(());
+        }
+        chunk = next;
+        index = index.checked_add(1).ok_or_else(|| VaultError::Corrupted {
+            path: Some(path.to_string_lossy().to_string()),
+        })?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::utils::encryption::CHUNK_SIZE;
+    use crate::utils::testing::TempDir;
+
+    // A full chunk with its GCM tag
+    const SEALED_CHUNK_LEN: usize = CHUNK_SIZE + 16;
+
+    fn data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn write_chunked(path: &Path, data: &[u8], key: &CryptoParams) {
+        let mut writer = ChunkWriter::new(File::create(path).unwrap(), path, key).unwrap();
+        // Written in odd pieces so chunks don't line up with the writes
+        for piece in data.chunks(1000) {
+            writer.write(piece).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn read_all(path: &Path, key: &CryptoParams) -> Result<Vec<u8>, VaultError> {
+        let mut reader = ChunkReader::open(path, key)?.unwrap();
+        let mut read = Vec::new();
+        for index in 0..reader.chunks() {
+            read.extend(reader.read_chunk(index)?);
+        }
+        Ok(read)
+    }
+
+    fn decrypt_all(path: &Path, key: &CryptoParams) -> Result<Vec<u8>, VaultError> {
+        let mut read = Vec::new();
+        decrypt_reader(File::open(path).unwrap(), path, key, |chunk| {
+            read.extend(chunk);
+            Ok(())
+        })?;
+        Ok(read)
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = TempDir::new("stream_round_trip");
+        let key = CryptoParams::random();
+        let path = dir.path().join("file");
+        for len in [
+            0,
+            1,
+            CHUNK_SIZE - 1,
+            CHUNK_SIZE,
+            CHUNK_SIZE + 1,
+            3 * CHUNK_SIZE + 7,
+        ] {
+            let data = data(len);
+            write_chunked(&path, &data, &key);
+
+            let reader = ChunkReader::open(&path, &key).unwrap().unwrap();
+            assert_eq!(reader.len(), len as u64);
+            assert_eq!(reader.chunks(), len.div_ceil(CHUNK_SIZE).max(1) as u64);
+            assert_eq!(read_all(&path, &key).unwrap(), data);
+            assert_eq!(decrypt_all(&path, &key).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn truncated_final_chunk_is_corrupted() {
+        let dir = TempDir::new("stream_truncated");
+        let key = CryptoParams::random();
+        let path = dir.path().join("file");
+        write_chunked(&path, &data(2 * CHUNK_SIZE + 100), &key);
+        let sealed = fs::read(&path).unwrap();
+
+        // Cut inside the last chunk, and right after a full chunk so the one before
+        // looks like the last
+        let full_chunks = CHUNK_HEADER_LEN + 2 * SEALED_CHUNK_LEN;
+        for len in [sealed.len() - 10, full_chunks] {
+            fs::write(&path, &sealed[..len]).unwrap();
+            assert!(matches!(
+                read_all(&path, &key),
+                Err(VaultError::Corrupted { .. })
+            ));
+            assert!(matches!(
+                decrypt_all(&path, &key),
+                Err(VaultError::Corrupted { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn reordered_chunks_are_corrupted() {
+        let dir = TempDir::new("stream_reordered");
+        let key = CryptoParams::random();
+        let path = dir.path().join("file");
+        write_chunked(&path, &data(3 * CHUNK_SIZE), &key);
+
+        let mut sealed = fs::read(&path).unwrap();
+        let first = CHUNK_HEADER_LEN;
+        let second = first + SEALED_CHUNK_LEN;
+        let (head, tail) = sealed.split_at_mut(second);
+        head[first..].swap_with_slice(&mut tail[..SEALED_CHUNK_LEN]);
+        fs::write(&path, &sealed).unwrap();
+
+        let mut reader = ChunkReader::open(&path, &key).unwrap().unwrap();
+        assert!(matches!(
+            reader.read_chunk(0),
+            Err(VaultError::Corrupted { .. })
+        ));
+        assert!(matches!(
+            reader.read_chunk(1),
+            Err(VaultError::Corrupted { .. })
+        ));
+        // The chunks that stayed in place still decrypt on their own
+        assert!(reader.read_chunk(2).is_ok());
+        assert!(matches!(
+            decrypt_all(&path, &key),
+            Err(VaultError::Corrupted { .. })
+        ));
+    }
+
+    #[test]
+    fn tampered_header_is_corrupted() {
+        let dir = TempDir::new("stream_header");
+        let key = CryptoParams::random();
+        let path = dir.path().join("file");
+        write_chunked(&path, &data(100), &key);
+        let sealed = fs::read(&path).unwrap();
+
+        // The chunk size only goes into the associated data, the one chunk still fits
+        // either size so nothing but the authentication notices. The nonce prefix also
+        // goes into the nonce.
+        let chunk_size = CHUNK_HEADER_LEN - 4;
+        for (at, flip) in [(chunk_size, 1), (chunk_size - 1, 0x80)] {
+            let mut tampered = sealed.clone();
+            tampered[at] ^= flip;
+            fs::write(&path, &tampered).unwrap();
+            assert!(matches!(
+                read_all(&path, &key),
+                Err(VaultError::Corrupted { .. })
+            ));
+            assert!(matches!(
+                decrypt_all(&path, &key),
+                Err(VaultError::Corrupted { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn wrong_key_is_corrupted() {
+        let dir = TempDir::new("stream_wrong_key");
+        let path = dir.path().join("file");
+        write_chunked(&path, &data(CHUNK_SIZE + 1), &CryptoParams::random());
+        assert!(matches!(
+            read_all(&path, &CryptoParams::random()),
+            Err(VaultError::Corrupted { .. })
+        ));
+    }
+}
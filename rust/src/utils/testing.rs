@@ -0,0 +1,60 @@
+// Helpers shared by the unit tests
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        RwLock,
+    },
+};
+
+use crate::utils::encryption::{create_vault_key, CryptoParams};
+use crate::utils::kdf::KdfConfig;
+
+// Locking the session clears every registered key. Tests holding a registered key take
+// this for reading, the ones that lock the session take it for writing.
+pub static SESSION_KEYS: RwLock<()> = RwLock::new(());
+
+// Fresh directory under the system temp dir, removed again when dropped
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "vault_test_{}_{}_{}",
+            name,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    pub fn join(&self, path: &str) -> String {
+        self.0.join(path).to_string_lossy().to_string()
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+pub const PASSWORD: &str = "password";
+
+// A vault with an empty `album`, its key derived cheaply since the tests aren't about
+// the cost of that
+pub fn vault(name: &str) -> (TempDir, CryptoParams) {
+    let root = TempDir::new(name);
+    let kdf = KdfConfig::Pbkdf2Sha256 { iterations: 10_000 };
+    let key = create_vault_key(&root.join(""), PASSWORD, &kdf).unwrap();
+    fs::create_dir(root.path().join("album")).unwrap();
+    (root, key)
+}
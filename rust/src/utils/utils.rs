@@ -1,7 +1,8 @@
 use std::{
+    ffi::OsStr,
     fs::{self, File},
-    io::{BufReader, Read},
-    path::Path,
+    io::{BufReader, Read, Write},
+    path::{Component, Path, PathBuf},
 };
 
 // Time
@@ -9,12 +10,17 @@ use chrono::Utc;
 use zip::ZipArchive;
 
 use crate::utils::{
-    encryption::{PasswordDecrypter, VAULT_FILE, VERIFICATION_DATA},
+    encryption::{PasswordDecrypter, VaultHeader, VAULT_FILE, VAULT_HEADER_VERSION},
     error::VaultError,
+    index::INDEX_FILE,
+    manifest::MANIFEST_FILE,
 };
 
+// Files and folders the vault keeps for itself, they're never valid paths from Dart
+const INTERNAL_NAMES: &[&str] = &[VAULT_FILE, INDEX_FILE, MANIFEST_FILE, ".thumbs", ".hash"];
+
 // filename
-pub fn generate_unique_filename(base_dir: &str, ext: &str) -> String {
+pub fn generate_unique_filename(base_dir: &str, ext: &str) -> Result<String, VaultError> {
     let now = Utc::now();
     let date_time = now.format("%Y%m%d%H%M%S%9f").to_string();
 
@@ -24,12 +30,14 @@ pub fn generate_unique_filename(base_dir: &str, ext: &str) -> String {
         let full_path = Path::new(base_dir).join(&filename);
 
         if !full_path.exists() {
-            return filename;
+            return Ok(filename);
         }
 
         counter += 1;
         if counter > 9999 {
-            panic!("Too many files with the same timestamp");
+            return Err(VaultError::AlreadyExists {
+                path: full_path.to_string_lossy().to_string(),
+            });
         }
     }
 }
@@ -45,22 +53,178 @@ pub fn verify_and_get_decrypter(
     let mut encrypted_content = Vec::new();
     vault_key_file
         .read_to_end(&mut encrypted_content)
-        .map_err(|e| VaultError::Error(format!("Failed to read .vault-key from ZIP: {}", e)))?;
+        .map_err(|e| VaultError::io(e, VAULT_FILE))?;
 
-    let decrypter = PasswordDecrypter::new(password);
+    let version = VaultHeader::version_of(&encrypted_content)?;
+    if version > VAULT_HEADER_VERSION {
+        return Err(VaultError::BackupVersionMismatch {
+            found: version,
+            supported: VAULT_HEADER_VERSION,
+        });
+    }
+    let header = VaultHeader::from_bytes(&encrypted_content)?;
+    PasswordDecrypter::new(password, &header)
+}
+
+// True for the vault's own files, including the temporary copies write_atomic leaves behind
+pub fn is_internal_name(name: &OsStr) -> bool {
+    let name = name.to_string_lossy();
+    INTERNAL_NAMES.iter().any(|internal| {
+        name.strip_prefix(internal)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
 
-    match decrypter.decrypt(&encrypted_content) {
-        Ok(decrypted_contet) if decrypted_contet == VERIFICATION_DATA => Ok(decrypter),
-        _ => Err(VaultError::IncorrectPassword),
+// Resolves `path` against the vault root. Relative paths start at the root and absolute
+// ones must already point into it, `..`, symlinks and the vault's own files are refused so
+// a bad path can never reach anything outside the vault or the key and indexes inside it.
+pub fn resolve_in_vault(root: &Path, path: &str) -> Result<PathBuf, VaultError> {
+    let outside = || VaultError::PathOutsideVault {
+        path: path.to_string(),
+    };
+
+    let full_path = Path::new(path);
+    let relative = if full_path.has_root() {
+        full_path.strip_prefix(root).map_err(|_| outside())?
+    } else {
+        full_path
+    };
+
+    let mut resolved = root.to_path_buf();
+    for component in relative.components() {
+        match component {
+            Component::Normal(name) if !is_internal_name(name) => resolved.push(name),
+            Component::CurDir => continue,
+            _ => return Err(outside()),
+        }
+        // Only what's below the root is checked, the root itself may be a symlink on Android
+        let is_symlink = fs::symlink_metadata(&resolved)
+            .map(|metadata| metadata.file_type().is_symlink())
+            .unwrap_or(false);
+        if is_symlink {
+            return Err(outside());
+        }
     }
+    Ok(resolved)
 }
 
 pub fn rename_with_parent(from: &Path, to: &Path) -> Result<(), VaultError> {
     if let Some(parent) = to.parent() {
-        fs::create_dir_all(parent).map_err(|e| VaultError::Error(e.to_string()))?;
+        fs::create_dir_all(parent).map_err(|e| VaultError::io(e, parent))?;
     }
 
-    fs::rename(from, to).map_err(|e| VaultError::Error(e.to_string()))?;
+    fs::rename(from, to).map_err(|e| VaultError::io(e, from))?;
 
     Ok(())
 }
+
+// Writes to a temporary file first so readers never see a half written file
+pub fn write_atomic(path: &Path, data: &[u8]) -> Result<(), VaultError> {
+    let mut tmp_name = path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = Path::new(&tmp_name);
+
+    let result = File::create(tmp_path)
+        .and_then(|mut file| {
+            file.write_all(data)?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(tmp_path, path));
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(tmp_path);
+        return Err(VaultError::io(e, path));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::TempDir;
+
+    fn is_outside(result: Result<PathBuf, VaultError>) -> bool {
+        matches!(result, Err(VaultError::PathOutsideVault { .. }))
+    }
+
+    #[test]
+    fn resolves_paths_below_the_root() {
+        let root = TempDir::new("resolve");
+        let expected = root.path().join("album").join("a.image");
+
+        assert_eq!(
+            resolve_in_vault(root.path(), "album/a.image").unwrap(),
+            expected
+        );
+        assert_eq!(
+            resolve_in_vault(root.path(), "./album/./a.image").unwrap(),
+            expected
+        );
+        assert_eq!(
+            resolve_in_vault(root.path(), &root.join("album/a.image")).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn refuses_parent_dirs() {
+        let root = TempDir::new("resolve_parent");
+        fs::create_dir(root.path().join("album")).unwrap();
+
+        assert!(is_outside(resolve_in_vault(root.path(), "..")));
+        assert!(is_outside(resolve_in_vault(root.path(), "../a.image")));
+        assert!(is_outside(resolve_in_vault(
+            root.path(),
+            "album/../../a.image"
+        )));
+        assert!(is_outside(resolve_in_vault(
+            root.path(),
+            &root.join("album/../../a.image")
+        )));
+    }
+
+    #[test]
+    fn refuses_absolute_paths_outside_the_root() {
+        let root = TempDir::new("resolve_absolute");
+        let other = TempDir::new("resolve_absolute_other");
+
+        assert!(is_outside(resolve_in_vault(root.path(), "/etc/passwd")));
+        assert!(is_outside(resolve_in_vault(
+            root.path(),
+            &other.join("a.image")
+        )));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn refuses_symlinks_out_of_the_vault() {
+        let root = TempDir::new("resolve_symlink");
+        let other = TempDir::new("resolve_symlink_other");
+        fs::write(other.path().join("a.image"), b"outside").unwrap();
+        std::os::unix::fs::symlink(other.path(), root.path().join("album")).unwrap();
+        std::os::unix::fs::symlink(other.path().join("a.image"), root.path().join("b.image"))
+            .unwrap();
+
+        assert!(is_outside(resolve_in_vault(root.path(), "album")));
+        assert!(is_outside(resolve_in_vault(root.path(), "album/a.image")));
+        assert!(is_outside(resolve_in_vault(root.path(), "b.image")));
+    }
+
+    #[test]
+    fn refuses_internal_names() {
+        let root = TempDir::new("resolve_internal");
+
+        for path in [
+            ".vault-key",
+            ".vault-key.tmp",
+            ".manifest",
+            "album/.index",
+            "album/.thumbs",
+            "album/.thumbs/a.image",
+            "album/.hash/a.image",
+        ] {
+            assert!(is_outside(resolve_in_vault(root.path(), path)), "{}", path);
+        }
+        assert!(resolve_in_vault(root.path(), "album/.indexes").is_ok());
+    }
+}